@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+declare_id!("7vbsnKtFsmLhBSmxjqGxXTuyDhEREUMRTmb34ZkTXjFP");
+
+// Local stand-in for a VRF-style oracle. A requester creates a randomness account,
+// the fulfiller later writes 32 bytes into it and flips the fulfilled flag.
+// Configure zai with:
+//   program = mock_oracle::ID
+//   discriminator = RandomnessAccount discriminator
+//   fulfilled_offset = RandomnessAccount::FULFILLED_OFFSET
+//   randomness_offset = RandomnessAccount::RANDOMNESS_OFFSET
+// and the trusted fulfiller with:
+//   key = the fulfiller's key
+//   offset = RandomnessAccount::FULFILLER_OFFSET
+// zai rejects accounts naming any other fulfiller, since the requester picks it here.
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    // func0 - request_randomness
+    pub fn request_randomness(ctx: Context<RequestRandomness>, fulfiller: Pubkey) -> Result<()> {
+        let randomness_account = &mut ctx.accounts.randomness_account;
+        randomness_account.requester = ctx.accounts.requester.key();
+        randomness_account.fulfiller = fulfiller;
+        randomness_account.fulfilled = false;
+        randomness_account.randomness = [0u8; 32];
+
+        msg!("Randomness requested from fulfiller {}.", fulfiller);
+        Ok(())
+    }
+
+    // func1 - fulfil_randomness
+    pub fn fulfil_randomness(ctx: Context<FulfilRandomness>, randomness: [u8; 32]) -> Result<()> {
+        let randomness_account = &mut ctx.accounts.randomness_account;
+        if randomness_account.fulfilled {
+            return Err(error!(MockOracleError::AlreadyFulfilled));
+        }
+        randomness_account.randomness = randomness;
+        randomness_account.fulfilled = true;
+
+        msg!("Randomness fulfilled for {}.", randomness_account.key());
+        Ok(())
+    }
+}
+
+// func0 - request_randomness - ACC.
+#[derive(Accounts)]
+pub struct RequestRandomness<'info> {
+    #[account(init, payer = requester, space = RandomnessAccount::LEN)]
+    pub randomness_account: Account<'info, RandomnessAccount>,
+    #[account(mut)]
+    pub requester: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// func1 - fulfil_randomness - ACC.
+#[derive(Accounts)]
+pub struct FulfilRandomness<'info> {
+    #[account(mut, has_one = fulfiller)]
+    pub randomness_account: Account<'info, RandomnessAccount>,
+    pub fulfiller: Signer<'info>,
+}
+
+#[account] // 8 bytes
+pub struct RandomnessAccount {
+    pub requester: Pubkey, // 32 bytes
+    pub fulfiller: Pubkey, // 32 bytes
+    pub fulfilled: bool, // 1 byte
+    pub randomness: [u8; 32], // 32 bytes
+}
+
+impl RandomnessAccount {
+    pub const LEN: usize = 105; // 105 byte total.
+    pub const FULFILLER_OFFSET: u16 = 40;
+    pub const FULFILLED_OFFSET: u16 = 72;
+    pub const RANDOMNESS_OFFSET: u16 = 73;
+}
+
+#[error_code]
+pub enum MockOracleError {
+    #[msg("Randomness has already been fulfilled.")]
+    AlreadyFulfilled,
+}
@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+use crate::{OracleConfig, OracleFulfiller, RandomnessSource, ZaiError};
+
+#[account] // 8 bytes
+#[derive(Default)]
+pub struct GameConfig {
+    pub authority: Pubkey, // 32 bytes - program upgrade authority at initialization
+    pub server_key: Pubkey, // 32 bytes - game server allowed to run admin instructions
+    pub level_cap: u8, // 1 byte
+    pub classes: [u8; GameConfig::MAX_CLASSES], // 8 bytes - default free classes, 0 = empty slot
+    pub bump: u8, // 1 byte
+    pub pending_server_key: Pubkey, // 32 bytes - proposed key waiting to be accepted
+    pub previous_server_key: Pubkey, // 32 bytes - rotated-out key, valid until expiry
+    pub previous_key_expiry_slot: u64, // 8 bytes
+    pub key_overlap_slots: u64, // 8 bytes
+    pub loot_table_version: u16, // 2 bytes - active loot table, 0 = none
+    pub randomness_source: RandomnessSource, // 1 byte
+    pub oracle: OracleConfig, // 44 bytes
+    pub auto_level: bool, // 1 byte - apply level-ups when the server grants XP
+    pub close_guards: u8, // 1 byte - CloseGuard bitmask checked by close_player
+    pub username_cooldown: i64, // 8 bytes - seconds between username changes, 0 = none
+    pub username_change_fee: u64, // 8 bytes - lamports paid to the authority per change
+    pub paused: u8, // 1 byte - PauseFlag bitmask of paused instruction groups
+    pub suspended_xp_penalties: bool, // 1 byte - let modify_player_xp apply negative changes to suspended players
+    pub sponsorship_daily_budget: u64, // 8 bytes - lamports of rent create_player_sponsored may pay per day, 0 = disabled
+    pub sponsorship_day: i64, // 8 bytes - unix day the spent amount applies to
+    pub sponsorship_spent: u64, // 8 bytes - lamports sponsored so far on sponsorship_day
+    pub guild_xp_share_percent: u8, // 1 byte - share of positive XP grants added to the player's guild
+    pub latest_loot_table_version: u16, // 2 bytes - newest loot table created, may be ahead of the active one
+    pub oracle_fulfiller: OracleFulfiller, // 34 bytes - fulfiller oracle randomness accounts must name
+}
+
+impl GameConfig {
+    pub const SEED: &'static [u8] = b"config";
+    pub const MAX_CLASSES: usize = 8;
+    pub const LEN: usize = 512; // 290 byte total + 222 byte buffer.
+    // Matches the ~150 slot lifetime of a recent blockhash
+    pub const DEFAULT_KEY_OVERLAP_SLOTS: u64 = 150;
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    // Returns true if the key is the current server key, or the previous one within its overlap window
+    pub fn is_server_key(&self, key: &Pubkey, slot: u64) -> bool {
+        *key == self.server_key || (*key == self.previous_server_key && slot <= self.previous_key_expiry_slot)
+    }
+
+    // Version and PDA seed of the next loot table, never below the active version
+    pub fn next_loot_table_version(&self) -> u16 {
+        self.latest_loot_table_version.max(self.loot_table_version) + 1
+    }
+
+    // Returns true if the class is one of the configured default classes
+    pub fn is_default_class(&self, class: u8) -> bool {
+        class != 0 && self.classes.contains(&class)
+    }
+
+    pub fn has_close_guard(&self, guard: CloseGuard) -> bool {
+        self.close_guards & guard.mask() != 0
+    }
+
+    pub fn is_paused(&self, flag: PauseFlag) -> bool {
+        self.paused & flag.mask() != 0
+    }
+
+    // Checked at the top of every handler in the paused instruction group
+    pub fn require_not_paused(&self, flag: PauseFlag) -> Result<()> {
+        if self.is_paused(flag) {
+            msg!("Instructions in group {:?} are paused.", flag);
+            return Err(error!(ZaiError::ProgramPaused));
+        }
+        Ok(())
+    }
+
+    // Records sponsored rent against the daily budget, resetting the spent amount on a new day
+    pub fn spend_sponsorship(&mut self, lamports: u64, now: i64) -> Result<()> {
+        let day = now / Self::SECONDS_PER_DAY;
+        if day != self.sponsorship_day {
+            self.sponsorship_day = day;
+            self.sponsorship_spent = 0;
+        }
+        let spent = self.sponsorship_spent.checked_add(lamports).ok_or(ZaiError::SponsorshipBudgetExceeded)?;
+        if spent > self.sponsorship_daily_budget {
+            msg!("Sponsoring {} lamports would exceed the daily budget of {}, {} already spent.", lamports, self.sponsorship_daily_budget, self.sponsorship_spent);
+            return Err(error!(ZaiError::SponsorshipBudgetExceeded));
+        }
+        self.sponsorship_spent = spent;
+        Ok(())
+    }
+
+    // Packs a class list into the fixed-size slots, rejecting empty or oversized lists
+    pub fn pack_classes(classes: &[u8]) -> Option<[u8; GameConfig::MAX_CLASSES]> {
+        if classes.is_empty() || classes.len() > Self::MAX_CLASSES || classes.contains(&0) {
+            return None;
+        }
+        let mut packed = [0u8; Self::MAX_CLASSES];
+        packed[..classes.len()].copy_from_slice(classes);
+        Some(packed)
+    }
+}
+
+// Optional changes applied by update_config, None leaves the setting unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct ConfigUpdate {
+    pub level_cap: Option<u8>,
+    pub classes: Option<Vec<u8>>,
+    pub key_overlap_slots: Option<u64>,
+    pub randomness_source: Option<RandomnessSource>,
+    pub oracle: Option<OracleConfig>,
+    pub auto_level: Option<bool>,
+    pub close_guards: Option<u8>,
+    pub username_cooldown: Option<i64>,
+    pub username_change_fee: Option<u64>,
+    pub suspended_xp_penalties: Option<bool>,
+    pub sponsorship_daily_budget: Option<u64>,
+    pub guild_xp_share_percent: Option<u8>,
+    pub oracle_fulfiller: Option<OracleFulfiller>,
+}
+
+// Conditions that block close_player when enabled in the config
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum CloseGuard {
+    UnopenedChests,
+    InMatch,
+}
+
+impl CloseGuard {
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+// Instruction groups that can be paused independently by an operator
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PauseFlag {
+    Create,
+    ClassChange,
+    Xp,
+    LevelUp,
+    Equip,
+}
+
+impl PauseFlag {
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+#[account] // 8 bytes
+pub struct AuthorityRegistry {
+    pub bump: u8, // 1 byte
+    pub authorities: Vec<ServerAuthority>, // 4 + 33 bytes per entry
+}
+
+impl AuthorityRegistry {
+    pub const SEED: &'static [u8] = b"authorities";
+    pub const MAX_AUTHORITIES: usize = 16;
+    pub const LEN: usize = 8 + 1 + 4 + Self::MAX_AUTHORITIES * ServerAuthority::LEN;
+
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        self.authorities.iter().any(|entry| entry.key == *key && entry.roles & role.mask() != 0)
+    }
+}
+
+// A server key and the bitmask of roles it holds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ServerAuthority {
+    pub key: Pubkey, // 32 bytes
+    pub roles: u8, // 1 byte
+}
+
+impl ServerAuthority {
+    pub const LEN: usize = 33;
+}
+
+// Roles that can be granted to server keys in the authority registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    XpGranter,
+    ItemGranter,
+    Operator,
+    Sponsor,
+}
+
+impl Role {
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+
+    // Roles the config server key holds without a registry entry, limited to what it could do before roles existed
+    pub fn held_by_server_key(self) -> bool {
+        matches!(self, Role::XpGranter | Role::ItemGranter)
+    }
+}
+
+// The config server key holds the XP and item granter roles, every other role needs a registry entry
+pub fn require_role(game_config: &GameConfig, registry: &AuthorityRegistry, key: &Pubkey, role: Role) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    if (role.held_by_server_key() && game_config.is_server_key(key, slot)) || registry.has_role(key, role) {
+        return Ok(());
+    }
+    msg!("Signer {} is missing role {:?}.", key, role);
+    Err(error!(ZaiError::MissingRole))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = GameConfig::SECONDS_PER_DAY;
+
+    fn sponsoring_config(budget: u64) -> GameConfig {
+        GameConfig { sponsorship_daily_budget: budget, ..Default::default() }
+    }
+
+    #[test]
+    fn spend_sponsorship_within_budget() {
+        let mut game_config = sponsoring_config(100);
+        game_config.spend_sponsorship(60, 10 * DAY).unwrap();
+        game_config.spend_sponsorship(40, 10 * DAY + 5).unwrap();
+        assert_eq!((game_config.sponsorship_day, game_config.sponsorship_spent), (10, 100));
+    }
+
+    #[test]
+    fn spend_sponsorship_rejects_over_budget() {
+        let mut game_config = sponsoring_config(100);
+        game_config.spend_sponsorship(60, 10 * DAY).unwrap();
+        assert_eq!(game_config.spend_sponsorship(41, 10 * DAY).unwrap_err(), error!(ZaiError::SponsorshipBudgetExceeded));
+        assert_eq!(game_config.sponsorship_spent, 60);
+        assert!(sponsoring_config(0).spend_sponsorship(1, 10 * DAY).is_err());
+    }
+
+    #[test]
+    fn spend_sponsorship_resets_on_a_new_day() {
+        let mut game_config = sponsoring_config(100);
+        game_config.spend_sponsorship(100, 11 * DAY - 1).unwrap();
+        game_config.spend_sponsorship(70, 11 * DAY).unwrap();
+        assert_eq!((game_config.sponsorship_day, game_config.sponsorship_spent), (11, 70));
+    }
+
+    #[test]
+    fn spend_sponsorship_rejects_overflow() {
+        let mut game_config = sponsoring_config(u64::MAX);
+        game_config.spend_sponsorship(u64::MAX, 0).unwrap();
+        assert!(game_config.spend_sponsorship(1, 0).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum ZaiError {
-    #[msg("Invalid class selected. Valid options are 101, 102, or 103.")]
+    #[msg("Invalid class selected. Class is not in the configured class list.")]
     InvalidClass,
 
     #[msg("Active weapon must match the active class.")]
@@ -28,4 +28,148 @@ pub enum ZaiError {
 
     #[msg("Attempted to switch to default weapon.")]
     InvalidPremiumWeapon,
+
+    #[msg("Invalid game config parameters.")]
+    InvalidConfig,
+
+    #[msg("Signer does not hold the required server role.")]
+    MissingRole,
+
+    #[msg("Authority registry is full.")]
+    AuthorityRegistryFull,
+
+    #[msg("Loot table entries are invalid.")]
+    InvalidLootTable,
+
+    #[msg("Player has no chests to open.")]
+    NoChestsToOpen,
+
+    #[msg("Revealed secret does not match the request commitment.")]
+    InvalidReveal,
+
+    #[msg("Randomness for this request is not available yet.")]
+    RevealTooEarly,
+
+    #[msg("Randomness account does not match the configured source.")]
+    InvalidRandomnessAccount,
+
+    #[msg("Player account is already on the latest version.")]
+    PlayerAlreadyMigrated,
+
+    #[msg("Player does not own this item.")]
+    ItemNotOwned,
+
+    #[msg("Inventory is full for this item type.")]
+    InventoryFull,
+
+    #[msg("Item is not in the item registry.")]
+    ItemNotRegistered,
+
+    #[msg("Player level is too low for this item.")]
+    ItemLevelTooLow,
+
+    #[msg("Item cannot be used by the active class.")]
+    ItemClassMismatch,
+
+    #[msg("Item registry is full.")]
+    ItemRegistryFull,
+
+    #[msg("Player still has unopened chests.")]
+    UnopenedChests,
+
+    #[msg("Player is currently in a match.")]
+    PlayerInMatch,
+
+    #[msg("Character slot is not owned by this wallet.")]
+    SlotNotOwned,
+
+    #[msg("Wallet already owns the maximum number of character slots.")]
+    TooManySlots,
+
+    #[msg("Username must be 3 to 16 characters of a-z, 0-9 or _.")]
+    InvalidUsername,
+
+    #[msg("Player already has a username, use change_username.")]
+    UsernameAlreadySet,
+
+    #[msg("Username was changed too recently.")]
+    UsernameCooldown,
+
+    #[msg("The player's username account must be passed to release it.")]
+    UsernameNotReleased,
+
+    #[msg("This instruction is paused.")]
+    ProgramPaused,
+
+    #[msg("Player account is suspended.")]
+    PlayerSuspended,
+
+    #[msg("Session expiry must be in the future and within the maximum duration, with a non-empty scope.")]
+    InvalidSession,
+
+    #[msg("Session key has expired.")]
+    SessionExpired,
+
+    #[msg("Session key is not allowed to sign this instruction.")]
+    SessionScopeMismatch,
+
+    #[msg("Sponsored rent would exceed today's sponsorship budget.")]
+    SponsorshipBudgetExceeded,
+
+    #[msg("Referrer must be another wallet's player and match the referee's referred_by.")]
+    InvalidReferrer,
+
+    #[msg("Guild name must be 3 to 16 characters of a-z, 0-9 or _.")]
+    InvalidGuildName,
+
+    #[msg("Player is already in a guild.")]
+    AlreadyInGuild,
+
+    #[msg("Player is not a member of this guild.")]
+    NotInGuild,
+
+    #[msg("Only guild officers and the leader can do this.")]
+    NotGuildOfficer,
+
+    #[msg("Guild has reached its member cap.")]
+    GuildFull,
+
+    #[msg("This guild requires an invite to join.")]
+    GuildInviteRequired,
+
+    #[msg("Guild leader must transfer leadership before leaving.")]
+    GuildLeaderCannotLeave,
+
+    #[msg("Guild already has the maximum number of officers.")]
+    TooManyOfficers,
+
+    #[msg("The player's guild account must be passed to share XP.")]
+    GuildAccountRequired,
+
+    #[msg("Match results must list one writable, unique player account per result, then unique guild accounts.")]
+    InvalidMatchResults,
+
+    #[msg("XP batch must list one writable, unique player account per delta, then unique guild accounts.")]
+    InvalidXpBatch,
+
+    #[msg("Voucher must be signed by an XP granter in the preceding Ed25519 instruction.")]
+    InvalidVoucher,
+
+    #[msg("XP voucher has expired.")]
+    VoucherExpired,
+
+    #[msg("XP voucher nonce was already redeemed or is too old.")]
+    VoucherAlreadyRedeemed,
+
+    #[msg("max_levels must be at least 1.")]
+    InvalidMaxLevels,
+
+    #[msg("Every open session of the player must be passed to close_player.")]
+    OpenSessions,
+
+    #[msg("Player must leave their guild before closing.")]
+    PlayerInGuild,
+
+    #[msg("Existing referral milestones can only change their reward, new milestones are appended.")]
+    ReferralMilestonesChanged,
 }
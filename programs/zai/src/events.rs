@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use crate::{LootReward, Player, PremiumItemType};
+
+// Every event carries the owning wallet and character slot of the player it describes
+
+#[event]
+pub struct PlayerCreated {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+}
+
+#[event]
+pub struct ClassChanged {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+}
+
+impl ClassChanged {
+    pub fn new(player: &Player) -> Self {
+        Self {
+            player: player.player_id,
+            slot: player.slot,
+            active_class: player.active_class,
+            active_weapon: player.active_weapon,
+        }
+    }
+}
+
+#[event]
+pub struct XpModified {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub xp_change: i64,
+    pub xp: i64,
+}
+
+#[event]
+pub struct LeveledUp {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub levels_gained: u8,
+    pub level: u8,
+    pub xp: i64,
+    pub pending_chest_rolls: u8,
+}
+
+impl LeveledUp {
+    pub fn new(player: &Player, levels_gained: u8) -> Self {
+        Self {
+            player: player.player_id,
+            slot: player.slot,
+            levels_gained,
+            level: player.level,
+            xp: player.xp,
+            pending_chest_rolls: player.pending_chest_rolls,
+        }
+    }
+}
+
+#[event]
+pub struct ChestAwarded {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub rolls: u8,
+    pub chests_awarded: u8,
+    pub chests: u16,
+}
+
+#[event]
+pub struct PremiumEquipped {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+    pub active_cosmetic: u8,
+}
+
+impl PremiumEquipped {
+    pub fn new(player: &Player, item_type: PremiumItemType, item_id: u8) -> Self {
+        Self {
+            player: player.player_id,
+            slot: player.slot,
+            item_type,
+            item_id,
+            active_class: player.active_class,
+            active_weapon: player.active_weapon,
+            active_cosmetic: player.active_cosmetic,
+        }
+    }
+}
+
+#[event]
+pub struct PlayerSuspended {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub suspended_until: i64, // 0 when the suspension is lifted
+    pub reason: u8,
+}
+
+// Emitted when a player joins or leaves a guild, including kicks
+#[event]
+pub struct GuildMembershipChanged {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub guild: Pubkey,
+    pub joined: bool,
+}
+
+impl GuildMembershipChanged {
+    pub fn new(player: &Player, guild: Pubkey, joined: bool) -> Self {
+        Self {
+            player: player.player_id,
+            slot: player.slot,
+            guild,
+            joined,
+        }
+    }
+}
+
+// Emitted for the referrer, referee is the wallet of the player that reached the milestones
+#[event]
+pub struct ReferralRewarded {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub referee: Pubkey,
+    pub referee_level: u8,
+    pub xp: u64,
+    pub chests: u16,
+}
+
+#[event]
+pub struct ChestOpened {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub loot_table_version: u16,
+    pub reward: LootReward,
+    pub chests_remaining: u16,
+}
+
+// Emitted for every revoked item, the loadout fields show any fallback that replaced it
+#[event]
+pub struct ItemRevoked {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+    pub active_cosmetic: u8,
+}
+
+impl ItemRevoked {
+    pub fn new(player: &Player, item_type: PremiumItemType, item_id: u8) -> Self {
+        Self {
+            player: player.player_id,
+            slot: player.slot,
+            item_type,
+            item_id,
+            active_class: player.active_class,
+            active_weapon: player.active_weapon,
+            active_cosmetic: player.active_cosmetic,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use crate::{Player, Username, ZaiError};
+
+#[account] // 8 bytes
+pub struct Guild {
+    pub name: [u8; Username::MAX_LEN], // 16 bytes - normalized name, zero padded
+    pub leader: Pubkey, // 32 bytes - leader's player PDA
+    pub officers: Vec<Pubkey>, // 4 + 32 bytes per officer player PDA
+    pub member_count: u16, // 2 bytes - including the leader
+    pub member_cap: u16, // 2 bytes
+    pub join_policy: GuildJoinPolicy, // 1 byte
+    pub xp: u64, // 8 bytes - total XP rolled up from members
+    pub level: u8, // 1 byte
+    pub created_at: i64, // 8 bytes
+    pub bump: u8, // 1 byte
+}
+
+impl Guild {
+    pub const SEED: &'static [u8] = b"guild";
+    pub const MAX_OFFICERS: usize = 4;
+    pub const MAX_MEMBERS: u16 = 100;
+    pub const MAX_LEVEL: u8 = 50;
+    pub const LEVEL_XP: u64 = 10_000;
+    pub const LEN: usize = 8 + 16 + 32 + 4 + Self::MAX_OFFICERS * 32 + 2 + 2 + 1 + 8 + 1 + 8 + 1;
+
+    // Guild names follow the username rules and are unique through the PDA seed
+    pub fn normalize(name: &str) -> Result<[u8; Username::MAX_LEN]> {
+        Username::normalize(name).map_err(|_| error!(ZaiError::InvalidGuildName))
+    }
+
+    // Total guild XP needed to reach `level`, growing quadratically
+    pub fn required_xp(level: u8) -> u64 {
+        let level = level.saturating_sub(1) as u64;
+        Self::LEVEL_XP * level * level
+    }
+
+    // Adds member XP and returns the number of guild levels gained
+    pub fn add_xp(&mut self, xp: u64) -> u8 {
+        self.xp = self.xp.saturating_add(xp);
+        let start_level = self.level;
+        while self.level < Self::MAX_LEVEL && self.xp >= Self::required_xp(self.level + 1) {
+            self.level += 1;
+        }
+        self.level - start_level
+    }
+
+    pub fn is_officer(&self, player: &Pubkey) -> bool {
+        *player == self.leader || self.officers.contains(player)
+    }
+
+    pub fn add_member(&mut self, guild: Pubkey, player: &mut Player) -> Result<()> {
+        if player.guild != Pubkey::default() {
+            return Err(error!(ZaiError::AlreadyInGuild));
+        }
+        if self.member_count >= self.member_cap {
+            return Err(error!(ZaiError::GuildFull));
+        }
+        self.member_count += 1;
+        player.guild = guild;
+        Ok(())
+    }
+
+    pub fn remove_member(&mut self, player: &mut Player, player_key: &Pubkey) {
+        self.officers.retain(|officer| officer != player_key);
+        self.member_count = self.member_count.saturating_sub(1);
+        player.guild = Pubkey::default();
+    }
+}
+
+// How players get into a guild
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum GuildJoinPolicy {
+    Open, // join_guild joins directly
+    InviteOnly, // join_guild accepts an invite from an officer
+    Application, // join_guild applies, invite from an officer accepts the application
+}
+
+// Pending invite or application, closed once the player joins
+#[account] // 8 bytes
+pub struct GuildInvite {
+    pub guild: Pubkey, // 32 bytes
+    pub player: Pubkey, // 32 bytes - invited or applying player PDA
+    pub invited: bool, // 1 byte - an officer invited the player
+    pub applied: bool, // 1 byte - the player applied to join
+    pub bump: u8, // 1 byte
+}
+
+impl GuildInvite {
+    pub const SEED: &'static [u8] = b"guild_invite";
+    pub const LEN: usize = 96; // 75 byte total + 21 byte buffer.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guild(level: u8, xp: u64) -> Guild {
+        Guild {
+            name: [0; Username::MAX_LEN],
+            leader: Pubkey::default(),
+            officers: vec![],
+            member_count: 1,
+            member_cap: Guild::MAX_MEMBERS,
+            join_policy: GuildJoinPolicy::Open,
+            xp,
+            level,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn add_xp_below_next_level() {
+        let mut guild = guild(1, 0);
+        assert_eq!(guild.add_xp(Guild::required_xp(2) - 1), 0);
+        assert_eq!((guild.level, guild.xp), (1, Guild::required_xp(2) - 1));
+    }
+
+    #[test]
+    fn add_xp_gains_several_levels() {
+        let mut guild = guild(1, 0);
+        assert_eq!(guild.add_xp(Guild::required_xp(4)), 3);
+        assert_eq!(guild.level, 4);
+        assert_eq!(guild.add_xp(Guild::required_xp(5) - Guild::required_xp(4)), 1);
+        assert_eq!(guild.level, 5);
+    }
+
+    #[test]
+    fn add_xp_stops_at_max_level_and_saturates() {
+        let mut guild = guild(1, 0);
+        assert_eq!(guild.add_xp(u64::MAX), Guild::MAX_LEVEL - 1);
+        assert_eq!(guild.add_xp(1), 0);
+        assert_eq!((guild.level, guild.xp), (Guild::MAX_LEVEL, u64::MAX));
+    }
+}
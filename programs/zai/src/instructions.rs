@@ -1,46 +1,113 @@
 // src/instructions.rs
 use anchor_lang::prelude::*;
-use crate::{Player, PremiumItemType, ZaiError};
-use std::str::FromStr;
-
-// CONST
-const SERVER_PUBLIC_KEY: &str = "zaigDCJxJdzSh6ETPosxWfVrzemjLnMJxxqhZCfdEqU";
+use anchor_lang::{system_program, Discriminator};
+use crate::{require_role, AuthorityRegistry, ChestAwarded, ChestOpenRequest, ChestOpened, ClassChanged, CloseGuard, ConfigUpdate, GameConfig, Guild, GuildInvite, GuildJoinPolicy, GuildMembershipChanged, Inventory, ItemDefinition, ItemRegistry, ItemRevoked, LeveledUp, LevelUpRequest, LevelUpSummary, PlayerCreated, PlayerSuspended, PremiumEquipped, XpModified, RandomnessCommitment, RandomnessLookup, RandomnessSource, LootEntry, LootReward, LootTable, MatchRecord, MatchResult, PauseFlag, Player, PremiumItemType, ReferralConfig, ReferralMilestone, ReferralRewarded, Role, ServerAuthority, Session, SessionScope, Username, WalletAccount, XpVoucher, ZaiError};
 
 // func0 - create_player
-pub fn create_player(ctx: Context<CreatePlayer>, active_class: u8, active_weapon: u8) -> Result<()> {
-    // Validate the active_class is one of the allowed values
-    if ![101, 102, 103].contains(&active_class) {
-        return Err(error!(ZaiError::InvalidClass));
-    }
+pub fn create_player(ctx: Context<CreatePlayer>, slot: u8, active_class: u8, active_weapon: u8) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Create)?;
+
+    let owner = ctx.accounts.signer.key();
+    claim_slot(&mut ctx.accounts.wallet_account, owner, ctx.bumps.wallet_account, slot)?;
+    check_starting_loadout(&ctx.accounts.game_config, &ctx.accounts.item_registry, active_class, active_weapon)?;
 
     let player_account = &mut ctx.accounts.player_account;
-    let clock = Clock::get()?; // Use ? for error handling
+    init_player(player_account, owner, slot, active_class, active_weapon)?;
+    attribute_referral(player_account, &mut ctx.accounts.referrer);
 
-    // Set fields for the new player
-    player_account.player_id = *ctx.accounts.signer.key; // Assign signer's pubkey
-    player_account.level = 1;
-    player_account.xp = 0;
-    player_account.chests = 0;
-    player_account.active_class = active_class; // Now validated to match weapon
-    player_account.active_weapon = active_weapon; // Confirmed to match class
-    player_account.joined = clock.unix_timestamp;
-    
-    msg!("Player account {} created for player {} with class {} and weapon {}.", player_account.player_id, player_account.player_id, player_account.active_class, player_account.active_weapon);
+    msg!("Player account {} created for player {} in slot {} with class {} and weapon {}.", player_account.key(), player_account.player_id, slot, player_account.active_class, player_account.active_weapon);
     Ok(())
 }
 // func0 - create_player - ACC.
 #[derive(Accounts)]
+#[instruction(slot: u8)]
 pub struct CreatePlayer<'info> {
-    #[account(init, payer = signer, space = Player::LEN, seeds = [b"player", signer.key().as_ref()], bump)]
+    #[account(init, payer = signer, space = Player::LEN, seeds = [Player::SEED, signer.key().as_ref(), Player::slot_seed(&slot)], bump)]
     pub player_account: Account<'info, Player>, // This is the PDA account for player data
+    // Created with the wallet's first character
+    #[account(init_if_needed, payer = signer, space = WalletAccount::LEN, seeds = [WalletAccount::SEED, signer.key().as_ref()], bump)]
+    pub wallet_account: Account<'info, WalletAccount>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    // Player account of whoever referred this wallet, if any
+    #[account(mut, constraint = referrer.player_id != *signer.key @ ZaiError::InvalidReferrer)]
+    pub referrer: Option<Account<'info, Player>>,
     #[account(mut)]
     pub signer: Signer<'info>, // This is the player creating the account
     pub system_program: Program<'info, System>,
 }
 // func0 - create_player - END.
 
+// Sets up the wallet account with its first character and checks the slot is owned.
+// Returns true when the wallet account was created by this instruction.
+fn claim_slot(wallet_account: &mut WalletAccount, owner: Pubkey, bump: u8, slot: u8) -> Result<bool> {
+    // Every wallet starts with the default number of slots, more can be granted
+    let created = wallet_account.owner == Pubkey::default();
+    if created {
+        wallet_account.owner = owner;
+        wallet_account.bump = bump;
+        wallet_account.slots = WalletAccount::DEFAULT_SLOTS;
+    }
+    if slot >= wallet_account.slots {
+        return Err(error!(ZaiError::SlotNotOwned));
+    }
+    Ok(created)
+}
+
+// New players start at level 1 with a configured free class and a free weapon the class can use
+fn check_starting_loadout(game_config: &GameConfig, item_registry: &ItemRegistry, active_class: u8, active_weapon: u8) -> Result<()> {
+    if !game_config.is_default_class(active_class) {
+        return Err(error!(ZaiError::InvalidClass));
+    }
+    if item_registry.check_equip(PremiumItemType::Class, active_class, 1, active_class)?.premium {
+        return Err(error!(ZaiError::InvalidClass));
+    }
+    if item_registry.check_equip(PremiumItemType::Weapon, active_weapon, 1, active_class)?.premium {
+        return Err(error!(ZaiError::ItemNotOwned));
+    }
+    Ok(())
+}
+
+fn init_player(player_account: &mut Player, owner: Pubkey, slot: u8, active_class: u8, active_weapon: u8) -> Result<()> {
+    let clock = Clock::get()?; // Use ? for error handling
+
+    // Set fields for the new player
+    player_account.player_id = owner;
+    player_account.level = 1;
+    player_account.xp = 0;
+    player_account.chests = 0;
+    player_account.active_class = active_class; // Validated against the config and item registry
+    player_account.active_weapon = active_weapon; // Validated to be usable by the class
+    player_account.joined = clock.unix_timestamp;
+    player_account.version = Player::CURRENT_VERSION;
+    player_account.slot = slot;
+
+    emit!(PlayerCreated {
+        player: owner,
+        slot,
+        active_class,
+        active_weapon,
+    });
+    Ok(())
+}
+
+// Records the referrer on a new player and counts the referral for the referrer
+fn attribute_referral(player_account: &mut Player, referrer: &mut Option<Account<Player>>) {
+    if let Some(referrer) = referrer {
+        player_account.referred_by = referrer.key();
+        referrer.referral_count = referrer.referral_count.saturating_add(1);
+        msg!("Player {} referred by player {}.", player_account.player_id, referrer.player_id);
+    }
+}
+
 // func1 - change_default_class
-pub fn change_default_class(ctx: Context<ChangeDefaultClass>, new_class: u8) -> Result<()> {
+pub fn change_default_class(ctx: Context<ChangeDefaultClass>, slot: u8, new_class: u8) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::ClassChange)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::ClassChange)?;
+
     let player = &mut ctx.accounts.player_account;
 
     // Check if the new class is different from the current class
@@ -48,82 +115,126 @@ pub fn change_default_class(ctx: Context<ChangeDefaultClass>, new_class: u8) ->
         return Err(error!(ZaiError::ClassChangeToSameNotAllowed));
     }
 
-    // Validate the new class is one of the allowed values
-    if ![101, 102, 103].contains(&new_class) {
+    // Validate the new class is one of the configured classes
+    if !ctx.accounts.game_config.is_default_class(new_class) {
+        return Err(error!(ZaiError::InvalidClass));
+    }
+    let item_registry = &ctx.accounts.item_registry;
+    if item_registry.check_equip(PremiumItemType::Class, new_class, player.level, new_class)?.premium {
         return Err(error!(ZaiError::InvalidClass));
     }
 
-    // Update the player's class and weapon to the new class
+    // Update the player's class, swapping the weapon if the new class cannot use it
+    player.active_weapon = item_registry.weapon_for_class(player.active_weapon, new_class, player.level)?;
     player.active_class = new_class;
-    player.active_weapon = new_class; // Assuming the weapon should match the class
 
-    msg!("Player {} class changed to {}.", player.player_id, player.active_class);
+    emit!(ClassChanged::new(player));
+    msg!("Player {} slot {} class changed to {}.", player.player_id, slot, player.active_class);
     Ok(())
 }
 // func1 - change_default_class - ACC.
 #[derive(Accounts)]
+#[instruction(slot: u8)]
 pub struct ChangeDefaultClass<'info> {
-    #[account(mut)]
+    #[account(mut, seeds = [Player::SEED, player_account.player_id.as_ref(), Player::slot_seed(&slot)], bump)]
     pub player_account: Account<'info, Player>,
-    // Ensure that the signer is the player attempting to change their class
-    #[account(mut, constraint = player_account.player_id == *signer.key)]
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys attempting to change their class
+    #[account(mut, constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
     pub signer: Signer<'info>,
 }
 // func1 - change_default_class - END.
 
 // func2 - modify_player_xp
 pub fn modify_player_xp(ctx: Context<ModifyPlayerXp>, xp_change: i64) -> Result<()> {
-    let server_pubkey = Pubkey::from_str(SERVER_PUBLIC_KEY).unwrap();
-    require_keys_eq!(ctx.accounts.admin.key(), server_pubkey, ZaiError::Unauthorized);
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Xp)?;
+
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::XpGranter)?;
 
     let player = &mut ctx.accounts.player_account;
+    // Suspended players only take penalties, and only when the config allows them
+    if player.is_suspended(Clock::get()?.unix_timestamp) && (xp_change > 0 || !ctx.accounts.game_config.suspended_xp_penalties) {
+        return Err(error!(ZaiError::PlayerSuspended));
+    }
     // Implement logic to safely add xp_change to player.xp considering overflows or underflows
     player.xp = player.xp.checked_add(xp_change).ok_or(ZaiError::XpOverflow)?;
     
+    emit!(XpModified {
+        player: player.player_id,
+        slot: player.slot,
+        xp_change,
+        xp: player.xp,
+    });
     msg!("Player {} XP modified by {}.", player.player_id, xp_change);
+
+    let game_config = &ctx.accounts.game_config;
+    share_guild_xp(game_config, ctx.accounts.guild.as_mut_slice(), player, xp_change)?;
+
+    if xp_change > 0 {
+        auto_level(game_config, player);
+    }
+    Ok(())
+}
+
+// Positive grants roll a share up into the player's guild, which must be among `guilds` while sharing is on
+fn share_guild_xp(game_config: &GameConfig, guilds: &mut [Account<Guild>], player: &Player, xp_change: i64) -> Result<()> {
+    if xp_change <= 0 || player.guild == Pubkey::default() || game_config.guild_xp_share_percent == 0 {
+        return Ok(());
+    }
+    let guild = guilds.iter_mut().find(|guild| guild.key() == player.guild).ok_or(ZaiError::GuildAccountRequired)?;
+    let share = (xp_change as u64).checked_mul(game_config.guild_xp_share_percent as u64).ok_or(ZaiError::XpOverflow)? / 100;
+    let levels_gained = guild.add_xp(share);
+    msg!("Guild {} gained {} XP, {} levels, now level {}.", guild.key(), share, levels_gained, guild.level);
     Ok(())
 }
+
+// Applies pending level-ups with the same cap, XP subtraction and chest rolls as increase_player_level.
+// Skipped while level-ups are paused, the XP stays on the player until they can level up again.
+fn auto_level(game_config: &GameConfig, player: &mut Player) {
+    if !game_config.auto_level || game_config.is_paused(PauseFlag::LevelUp) {
+        return;
+    }
+    if let Ok(summary) = player.level_up_many(game_config.level_cap, u8::MAX) {
+        emit!(LeveledUp::new(player, summary.levels_gained));
+        msg!("Player {} auto-leveled {} times to level {}, {} chest rolls pending.", player.player_id, summary.levels_gained, summary.level, player.pending_chest_rolls);
+    }
+}
 //func2 - modify_player_xp - ACC.
 #[derive(Accounts)]
 pub struct ModifyPlayerXp<'info> {
     #[account(mut)]
     pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    // Required for guild members while the config shares XP with guilds
+    #[account(mut, constraint = guild.key() == player_account.guild @ ZaiError::NotInGuild)]
+    pub guild: Option<Account<'info, Guild>>,
     /// CHECK: This is only checked for authorization purposes, not dereferenced
     pub admin: Signer<'info>,
 }
 //func2 - modify_player_xp - END.
 
 //func3 - increase_player_level
-pub fn increase_player_level(ctx: Context<IncreasePlayerLevel>) -> Result<()> {
-    let player = &mut ctx.accounts.player_account;
-    // Ensure the player is not already at or above the level cap
-    if player.level >= Player::LEVEL_CAP {
-        return Err(ZaiError::LevelCapReached.into());
-    }
-    
-    // Calculate the required XP for the next level using the exponential curve
-    let required_xp_for_next_level = (4250.0 * f64::exp(0.055 * (player.level as f64))).round() as i64;
-
-    // Check if the player has enough XP to level up
-    if player.xp < required_xp_for_next_level {
-        return Err(ZaiError::NotEnoughXp.into());
-    }
+pub fn increase_player_level(ctx: Context<IncreasePlayerLevel>, slot: u8) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::LevelUp)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::LevelUp)?;
 
-    // Level up
-    player.level += 1;
-    // Subtract the required XP for leveling up from the player's current XP
-    player.xp -= required_xp_for_next_level;
+    let player = &mut ctx.accounts.player_account;
+    let required_xp_for_next_level = player.level_up(ctx.accounts.game_config.level_cap)?;
+    reward_referrer(player, &ctx.accounts.referral_config, &mut ctx.accounts.referrer)?;
 
-    // Attempt to give a chest with a 10% chance
-    let current_slot = Clock::get()?.slot;
-    let player_specific_data = player.player_id.to_bytes(); // Assuming player_id is a Pubkey
-    let randomness_seed = current_slot.wrapping_add(u64::from_le_bytes(player_specific_data[0..8].try_into().unwrap())); // Simple mix of slot and player_id
-    let chest_chance = randomness_seed % 10; // This gives us a value from 0 to 9
+    emit!(LeveledUp::new(player, 1));
+    msg!("Player {} slot {} leveled up to {}.", player.player_id, slot, player.level);
 
-    if chest_chance == 0 { // 10% chance
-        player.chests += 1;
-        msg!("Congratulations! You've received a chest for leveling up.");
-    }
+    msg!("Chest roll queued, {} pending. Settle with request_level_up and settle_level_up.", player.pending_chest_rolls);
 
     // Log the required XP for the next level and the player's new XP after leveling up
     msg!("Required XP for next level: {}", required_xp_for_next_level);
@@ -134,40 +245,88 @@ pub fn increase_player_level(ctx: Context<IncreasePlayerLevel>) -> Result<()> {
 
 //func3 - increase_player_level - ACC.
 #[derive(Accounts)]
+#[instruction(slot: u8)]
 pub struct IncreasePlayerLevel<'info> {
-    #[account(mut, constraint = player_account.level < Player::LEVEL_CAP && player_account.xp >= (4250.0 * f64::exp(0.055 * (player_account.level as f64))).round() as i64)]
+    #[account(mut, seeds = [Player::SEED, player_account.player_id.as_ref(), Player::slot_seed(&slot)], bump, constraint = player_account.level < game_config.level_cap && player_account.xp >= Player::required_xp(player_account.level))]
     pub player_account: Account<'info, Player>,
-    // Ensure that the signer is the player attempting to level up
-    #[account(mut, constraint = player_account.player_id == *signer.key)]
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    // Optional until the referral program is configured
+    #[account(seeds = [ReferralConfig::SEED], bump = referral_config.bump)]
+    pub referral_config: Option<Account<'info, ReferralConfig>>,
+    // Required for referred players to pay out milestone rewards
+    #[account(mut, constraint = referrer.key() == player_account.referred_by @ ZaiError::InvalidReferrer)]
+    pub referrer: Option<Account<'info, Player>>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys attempting to level up
+    #[account(mut, constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
     pub signer: Signer<'info>,
 }
 //func3 - END.
 
+// Pays the referrer for milestones reached by a referred player.
+// Milestones stay unpaid until a level-up passes both accounts, or claim_referral_reward is called.
+fn reward_referrer(player: &mut Player, referral_config: &Option<Account<ReferralConfig>>, referrer: &mut Option<Account<Player>>) -> Result<()> {
+    let (Some(referral_config), Some(referrer)) = (referral_config, referrer) else {
+        return Ok(());
+    };
+    pay_referrer(player, referral_config, referrer)?;
+    Ok(())
+}
+
+// Returns true if any milestone reward was paid
+fn pay_referrer(player: &mut Player, referral_config: &ReferralConfig, referrer: &mut Player) -> Result<bool> {
+    let Some((xp, chests)) = referral_config.reward(player, referrer)? else {
+        return Ok(false);
+    };
+    emit!(ReferralRewarded {
+        player: referrer.player_id,
+        slot: referrer.slot,
+        referee: player.player_id,
+        referee_level: player.level,
+        xp,
+        chests,
+    });
+    msg!("Referrer {} rewarded {} XP and {} chests for player {} reaching level {}.", referrer.player_id, xp, chests, player.player_id, player.level);
+    Ok(true)
+}
+
 // func4 - equip_premium_item - START.
 pub fn equip_premium_item(ctx: Context<EquipPremiumItem>, item_type: PremiumItemType, item_id: u8) -> Result<()> {
-    let server_pubkey = Pubkey::from_str(SERVER_PUBLIC_KEY).unwrap();
-    // Ensure only the admin can execute this function
-    require_keys_eq!(ctx.accounts.admin.key(), server_pubkey, ZaiError::Unauthorized);
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Equip)?;
 
+    // Ensure only an item granter can execute this function
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::ItemGranter)?;
+
+    let item_registry = &ctx.accounts.item_registry;
     let player = &mut ctx.accounts.player_account;
+    let item = item_registry.check_equip(item_type, item_id, player.level, player.active_class)?;
+    // The server equips on the player's behalf but only from what the player owns
+    if item.premium && !ctx.accounts.inventory.owns(item_type, item_id) {
+        return Err(error!(ZaiError::ItemNotOwned));
+    }
 
     match item_type {
         PremiumItemType::Class => {
-            // Ensure the new class is not one of the default free classes
-            if [101, 102, 103].contains(&item_id) {
+            // Ensure the new class is not one of the free classes
+            if !item.premium {
                 return Err(error!(ZaiError::InvalidPremiumClass));
             }
+            player.active_weapon = item_registry.weapon_for_class(player.active_weapon, item_id, player.level)?;
             player.active_class = item_id;
         },
         PremiumItemType::Weapon => {
-            // Ensure the new weapon is not one of the default free weapons
-            if [101, 102, 103].contains(&item_id) { 
+            // Ensure the new weapon is not one of the free weapons
+            if !item.premium {
                 return Err(error!(ZaiError::InvalidPremiumWeapon));
             }
             player.active_weapon = item_id;
         },
+        PremiumItemType::Cosmetic => player.active_cosmetic = item_id,
     }
 
+    emit!(PremiumEquipped::new(player, item_type, item_id));
     msg!("Player {} equipped premium item {} of type {:?}.", player.player_id, item_id, item_type);
     Ok(())
 }
@@ -177,7 +336,1760 @@ pub fn equip_premium_item(ctx: Context<EquipPremiumItem>, item_type: PremiumItem
 pub struct EquipPremiumItem<'info> {
     #[account(mut)]
     pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [Inventory::SEED, player_account.key().as_ref()], bump = inventory.bump)]
+    pub inventory: Account<'info, Inventory>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
     /// CHECK: This is only checked for authorization purposes, not dereferenced
     pub admin: Signer<'info>,
 }
-// func4 - END.
\ No newline at end of file
+// func4 - END.
+
+// func5 - initialize_config
+pub fn initialize_config(ctx: Context<InitializeConfig>, server_key: Pubkey, level_cap: u8, classes: Vec<u8>) -> Result<()> {
+    if level_cap == 0 {
+        return Err(error!(ZaiError::InvalidConfig));
+    }
+    let classes = GameConfig::pack_classes(&classes).ok_or(ZaiError::InvalidConfig)?;
+
+    let game_config = &mut ctx.accounts.game_config;
+    game_config.authority = ctx.accounts.authority.key();
+    game_config.server_key = server_key;
+    game_config.level_cap = level_cap;
+    game_config.classes = classes;
+    game_config.bump = ctx.bumps.game_config;
+    game_config.key_overlap_slots = GameConfig::DEFAULT_KEY_OVERLAP_SLOTS;
+    ctx.accounts.authority_registry.bump = ctx.bumps.authority_registry;
+
+    msg!("Game config initialized with server {} and level cap {}.", game_config.server_key, game_config.level_cap);
+    Ok(())
+}
+// func5 - initialize_config - ACC.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(init, payer = authority, space = GameConfig::LEN, seeds = [GameConfig::SEED], bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(init, payer = authority, space = AuthorityRegistry::LEN, seeds = [AuthorityRegistry::SEED], bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    // Only the program upgrade authority may create the config
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ ZaiError::Unauthorized)]
+    pub program: Program<'info, crate::program::Zai>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ZaiError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+// func5 - initialize_config - END.
+
+// func6 - update_config
+pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    let game_config = &mut ctx.accounts.game_config;
+
+    if let Some(level_cap) = update.level_cap {
+        if level_cap == 0 {
+            return Err(error!(ZaiError::InvalidConfig));
+        }
+        game_config.level_cap = level_cap;
+    }
+    if let Some(classes) = update.classes {
+        game_config.classes = GameConfig::pack_classes(&classes).ok_or(ZaiError::InvalidConfig)?;
+    }
+    if let Some(key_overlap_slots) = update.key_overlap_slots {
+        game_config.key_overlap_slots = key_overlap_slots;
+    }
+    if let Some(oracle) = update.oracle {
+        game_config.oracle = oracle;
+    }
+    if let Some(oracle_fulfiller) = update.oracle_fulfiller {
+        game_config.oracle_fulfiller = oracle_fulfiller;
+    }
+    if let Some(randomness_source) = update.randomness_source {
+        // An oracle source needs an owner program and a trusted fulfiller to validate accounts against
+        if randomness_source == RandomnessSource::Oracle && (game_config.oracle.program == Pubkey::default() || game_config.oracle_fulfiller.key == Pubkey::default()) {
+            return Err(error!(ZaiError::InvalidConfig));
+        }
+        game_config.randomness_source = randomness_source;
+    }
+    if let Some(auto_level) = update.auto_level {
+        game_config.auto_level = auto_level;
+    }
+    if let Some(close_guards) = update.close_guards {
+        game_config.close_guards = close_guards;
+    }
+    if let Some(username_cooldown) = update.username_cooldown {
+        if username_cooldown < 0 {
+            return Err(error!(ZaiError::InvalidConfig));
+        }
+        game_config.username_cooldown = username_cooldown;
+    }
+    if let Some(username_change_fee) = update.username_change_fee {
+        game_config.username_change_fee = username_change_fee;
+    }
+    if let Some(suspended_xp_penalties) = update.suspended_xp_penalties {
+        game_config.suspended_xp_penalties = suspended_xp_penalties;
+    }
+    if let Some(sponsorship_daily_budget) = update.sponsorship_daily_budget {
+        game_config.sponsorship_daily_budget = sponsorship_daily_budget;
+    }
+    if let Some(guild_xp_share_percent) = update.guild_xp_share_percent {
+        if guild_xp_share_percent > 100 {
+            return Err(error!(ZaiError::InvalidConfig));
+        }
+        game_config.guild_xp_share_percent = guild_xp_share_percent;
+    }
+
+    msg!("Game config updated with level cap {}.", game_config.level_cap);
+    Ok(())
+}
+// func6 - update_config - ACC.
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump, has_one = authority @ ZaiError::Unauthorized)]
+    pub game_config: Account<'info, GameConfig>,
+    pub authority: Signer<'info>,
+}
+// func6 - update_config - END.
+
+
+// func7 - grant_role
+pub fn grant_role(ctx: Context<ManageRole>, key: Pubkey, role: Role) -> Result<()> {
+    let registry = &mut ctx.accounts.authority_registry;
+
+    match registry.authorities.iter_mut().find(|entry| entry.key == key) {
+        Some(entry) => entry.roles |= role.mask(),
+        None => {
+            if registry.authorities.len() >= AuthorityRegistry::MAX_AUTHORITIES {
+                return Err(error!(ZaiError::AuthorityRegistryFull));
+            }
+            registry.authorities.push(ServerAuthority { key, roles: role.mask() });
+        }
+    }
+
+    msg!("Granted role {:?} to {}.", role, key);
+    Ok(())
+}
+// func7 - grant_role - ACC.
+#[derive(Accounts)]
+pub struct ManageRole<'info> {
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump, has_one = authority @ ZaiError::Unauthorized)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(mut, seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub authority: Signer<'info>,
+}
+// func7 - grant_role - END.
+
+// func8 - revoke_role
+pub fn revoke_role(ctx: Context<ManageRole>, key: Pubkey, role: Role) -> Result<()> {
+    let registry = &mut ctx.accounts.authority_registry;
+
+    if let Some(entry) = registry.authorities.iter_mut().find(|entry| entry.key == key) {
+        entry.roles &= !role.mask();
+    }
+    // Drop keys that no longer hold any role
+    registry.authorities.retain(|entry| entry.roles != 0);
+
+    msg!("Revoked role {:?} from {}.", role, key);
+    Ok(())
+}
+// func8 - revoke_role - END.
+
+// func9 - propose_server_key
+pub fn propose_server_key(ctx: Context<ProposeServerKey>, new_server_key: Pubkey) -> Result<()> {
+    let game_config = &mut ctx.accounts.game_config;
+    game_config.pending_server_key = new_server_key;
+
+    msg!("Server key {} proposed, waiting for acceptance.", new_server_key);
+    Ok(())
+}
+// func9 - propose_server_key - ACC.
+#[derive(Accounts)]
+pub struct ProposeServerKey<'info> {
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump, has_one = authority @ ZaiError::Unauthorized)]
+    pub game_config: Account<'info, GameConfig>,
+    pub authority: Signer<'info>,
+}
+// func9 - propose_server_key - END.
+
+// func10 - accept_server_key
+pub fn accept_server_key(ctx: Context<AcceptServerKey>) -> Result<()> {
+    let clock = Clock::get()?;
+    let game_config = &mut ctx.accounts.game_config;
+
+    // Keep the old key valid for the overlap window so in-flight transactions still land
+    game_config.previous_server_key = game_config.server_key;
+    game_config.previous_key_expiry_slot = clock.slot.saturating_add(game_config.key_overlap_slots);
+    game_config.server_key = game_config.pending_server_key;
+    game_config.pending_server_key = Pubkey::default();
+
+    msg!("Server key rotated to {}, previous key valid until slot {}.", game_config.server_key, game_config.previous_key_expiry_slot);
+    Ok(())
+}
+// func10 - accept_server_key - ACC.
+#[derive(Accounts)]
+pub struct AcceptServerKey<'info> {
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump, constraint = game_config.pending_server_key == new_server_key.key() @ ZaiError::Unauthorized)]
+    pub game_config: Account<'info, GameConfig>,
+    // The proposed key must sign to prove it is controlled by the server
+    pub new_server_key: Signer<'info>,
+}
+// func10 - accept_server_key - END.
+
+// func11 - create_loot_table
+pub fn create_loot_table(ctx: Context<CreateLootTable>, entries: Vec<LootEntry>) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    if entries.is_empty() || entries.len() > LootTable::MAX_ENTRIES {
+        return Err(error!(ZaiError::InvalidLootTable));
+    }
+    let mut total_weight: u32 = 0;
+    for entry in entries.iter() {
+        if entry.weight == 0 {
+            return Err(error!(ZaiError::InvalidLootTable));
+        }
+        // Premium drops must not be one of the default free classes
+        match entry.reward {
+            LootReward::PremiumClass { class_id: item_id } | LootReward::PremiumWeapon { weapon_id: item_id } => {
+                if item_id == 0 || ctx.accounts.game_config.is_default_class(item_id) {
+                    return Err(error!(ZaiError::InvalidLootTable));
+                }
+            }
+            LootReward::XpBonus { .. } | LootReward::Cosmetic { .. } => {}
+        }
+        total_weight += entry.weight as u32;
+    }
+
+    let game_config = &mut ctx.accounts.game_config;
+    let loot_table = &mut ctx.accounts.loot_table;
+    loot_table.version = game_config.next_loot_table_version();
+    loot_table.total_weight = total_weight;
+    loot_table.bump = ctx.bumps.loot_table;
+    loot_table.entries = entries;
+
+    // New tables become active immediately
+    game_config.latest_loot_table_version = loot_table.version;
+    game_config.loot_table_version = loot_table.version;
+
+    msg!("Loot table version {} created with {} entries.", loot_table.version, loot_table.entries.len());
+    Ok(())
+}
+// func11 - create_loot_table - ACC.
+#[derive(Accounts)]
+pub struct CreateLootTable<'info> {
+    #[account(init, payer = admin, space = LootTable::LEN, seeds = [LootTable::SEED, &game_config.next_loot_table_version().to_le_bytes()], bump)]
+    pub loot_table: Account<'info, LootTable>,
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func11 - create_loot_table - END.
+
+// func12 - set_loot_table_version
+pub fn set_loot_table_version(ctx: Context<SetLootTableVersion>) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    // Roll back or forward to any previously created table
+    let game_config = &mut ctx.accounts.game_config;
+    game_config.loot_table_version = ctx.accounts.loot_table.version;
+
+    msg!("Active loot table set to version {}.", game_config.loot_table_version);
+    Ok(())
+}
+// func12 - set_loot_table_version - ACC.
+#[derive(Accounts)]
+pub struct SetLootTableVersion<'info> {
+    #[account(seeds = [LootTable::SEED, &loot_table.version.to_le_bytes()], bump = loot_table.bump)]
+    pub loot_table: Account<'info, LootTable>,
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func12 - set_loot_table_version - END.
+
+// func13 - open_chest
+pub fn open_chest(ctx: Context<OpenChest>, commitment: [u8; 32]) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::Chests)?;
+
+    let player = &mut ctx.accounts.player_account;
+
+    // Consume one chest, the loot is rolled once the committed randomness is available
+    player.chests = player.chests.checked_sub(1).ok_or(ZaiError::NoChestsToOpen)?;
+
+    let request = &mut ctx.accounts.chest_open_request;
+    request.player = player.key();
+    request.randomness = RandomnessCommitment::commit(&ctx.accounts.game_config, &ctx.accounts.randomness_account, commitment)?;
+    request.loot_table_version = ctx.accounts.loot_table.version;
+    request.bump = ctx.bumps.chest_open_request;
+
+    msg!("Player {} is opening a chest from loot table version {}.", player.player_id, request.loot_table_version);
+    Ok(())
+}
+// func13 - open_chest - ACC.
+#[derive(Accounts)]
+pub struct OpenChest<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    // Only the active loot table can be rolled against
+    #[account(seeds = [LootTable::SEED, &game_config.loot_table_version.to_le_bytes()], bump = loot_table.bump)]
+    pub loot_table: Account<'info, LootTable>,
+    // Only one chest can be opening per player
+    #[account(init, payer = signer, space = ChestOpenRequest::LEN, seeds = [ChestOpenRequest::SEED, player_account.key().as_ref()], bump)]
+    pub chest_open_request: Account<'info, ChestOpenRequest>,
+    /// CHECK: Validated against the configured randomness source
+    pub randomness_account: UncheckedAccount<'info>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys opening the chest
+    #[account(mut, constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func13 - open_chest - END.
+
+// func14 - request_level_up
+pub fn request_level_up(ctx: Context<RequestLevelUp>, commitment: [u8; 32]) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::LevelUp)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::LevelUp)?;
+
+    let player = &mut ctx.accounts.player_account;
+
+    // Level up when XP allows, otherwise only settle chest rolls that are already queued
+    match player.level_up(ctx.accounts.game_config.level_cap) {
+        Ok(required_xp_for_next_level) => {
+            emit!(LeveledUp::new(player, 1));
+            msg!("Player {} leveled up to {} using {} XP.", player.player_id, player.level, required_xp_for_next_level);
+        }
+        Err(err) if player.pending_chest_rolls == 0 => return Err(err),
+        Err(_) => {}
+    }
+
+    let request = &mut ctx.accounts.level_up_request;
+    request.player = player.key();
+    request.randomness = RandomnessCommitment::commit(&ctx.accounts.game_config, &ctx.accounts.randomness_account, commitment)?;
+    request.rolls = player.pending_chest_rolls;
+    request.bump = ctx.bumps.level_up_request;
+    player.pending_chest_rolls = 0;
+
+    msg!("Committed {} chest rolls to {:?} randomness.", request.rolls, request.randomness.source);
+    Ok(())
+}
+// func14 - request_level_up - ACC.
+#[derive(Accounts)]
+pub struct RequestLevelUp<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    // Only one request can be open per player
+    #[account(init, payer = signer, space = LevelUpRequest::LEN, seeds = [LevelUpRequest::SEED, player_account.key().as_ref()], bump)]
+    pub level_up_request: Account<'info, LevelUpRequest>,
+    /// CHECK: Validated against the configured randomness source
+    pub randomness_account: UncheckedAccount<'info>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys attempting to level up
+    #[account(mut, constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func14 - request_level_up - END.
+
+// func15 - settle_level_up
+pub fn settle_level_up(ctx: Context<SettleLevelUp>, secret: [u8; 32]) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::LevelUp)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::LevelUp)?;
+
+    let request = &ctx.accounts.level_up_request;
+
+    let randomness = match request.randomness.reveal(&ctx.accounts.game_config, &ctx.accounts.randomness_account, &secret)? {
+        RandomnessLookup::Found(randomness) => randomness,
+        RandomnessLookup::Pending => return Err(error!(ZaiError::RevealTooEarly)),
+        RandomnessLookup::Expired => {
+            // Forfeit rather than re-roll, otherwise a bad outcome could be skipped by waiting
+            msg!("Randomness for target slot {} expired, {} chest rolls forfeited.", request.randomness.target_slot, request.rolls);
+            return Ok(());
+        }
+    };
+
+    let player = &mut ctx.accounts.player_account;
+    let mut chests_awarded: u8 = 0;
+    for index in 0..request.rolls {
+        let chest_chance = RandomnessCommitment::roll(&secret, &randomness, &request.player, index) % LevelUpRequest::CHEST_ODDS;
+        if chest_chance == 0 {
+            player.chests = player.chests.saturating_add(1);
+            chests_awarded += 1;
+            msg!("Congratulations! You've received a chest for leveling up.");
+        }
+    }
+
+    emit!(ChestAwarded {
+        player: player.player_id,
+        slot: player.slot,
+        rolls: request.rolls,
+        chests_awarded,
+        chests: player.chests,
+    });
+
+    msg!("Settled {} chest rolls, player {} now has {} chests.", request.rolls, player.player_id, player.chests);
+    Ok(())
+}
+// func15 - settle_level_up - ACC.
+#[derive(Accounts)]
+pub struct SettleLevelUp<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    // The request is closed back to the player once settled
+    #[account(mut, close = signer, seeds = [LevelUpRequest::SEED, player_account.key().as_ref()], bump = level_up_request.bump)]
+    pub level_up_request: Account<'info, LevelUpRequest>,
+    /// CHECK: Checked against the account recorded in the request
+    pub randomness_account: UncheckedAccount<'info>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys settling the request
+    #[account(mut, constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+}
+// func15 - settle_level_up - END.
+
+// func16 - settle_chest_open
+pub fn settle_chest_open(ctx: Context<SettleChestOpen>, secret: [u8; 32]) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::Chests)?;
+
+    let request = &ctx.accounts.chest_open_request;
+
+    let randomness = match request.randomness.reveal(&ctx.accounts.game_config, &ctx.accounts.randomness_account, &secret)? {
+        RandomnessLookup::Found(randomness) => randomness,
+        RandomnessLookup::Pending => return Err(error!(ZaiError::RevealTooEarly)),
+        RandomnessLookup::Expired => {
+            // Forfeit rather than re-roll, otherwise a bad outcome could be skipped by waiting
+            msg!("Randomness for target slot {} expired, chest forfeited.", request.randomness.target_slot);
+            return Ok(());
+        }
+    };
+
+    // Roll against the loot table recorded when the chest was opened
+    let loot_table = &ctx.accounts.loot_table;
+    let roll = RandomnessCommitment::roll(&secret, &randomness, &request.player, 0);
+    let reward = loot_table.pick(roll).ok_or(ZaiError::InvalidLootTable)?.reward;
+
+    // Write the outcome to the player's state, item drops are also recorded as owned
+    let player = &mut ctx.accounts.player_account;
+    let inventory = &mut ctx.accounts.inventory;
+    if inventory.player == Pubkey::default() {
+        inventory.player = player.key();
+        inventory.bump = ctx.bumps.inventory;
+    }
+    match reward {
+        LootReward::XpBonus { amount } => {
+            player.xp = player.xp.checked_add(amount as i64).ok_or(ZaiError::XpOverflow)?;
+            emit!(XpModified {
+                player: player.player_id,
+                slot: player.slot,
+                xp_change: amount as i64,
+                xp: player.xp,
+            });
+        }
+        LootReward::PremiumClass { class_id: item_id } | LootReward::PremiumWeapon { weapon_id: item_id } | LootReward::Cosmetic { cosmetic_id: item_id } => {
+            let item_type = match reward {
+                LootReward::PremiumClass { .. } => PremiumItemType::Class,
+                LootReward::PremiumWeapon { .. } => PremiumItemType::Weapon,
+                _ => PremiumItemType::Cosmetic,
+            };
+            inventory.add(item_type, item_id)?;
+            // Drops are always kept, but only equipped when the player can currently use them and equipping is not paused
+            if ctx.accounts.game_config.is_paused(PauseFlag::Equip) {
+                msg!("{:?} {} added to the inventory, equipping is paused.", item_type, item_id);
+            } else {
+                match equip_checked(&ctx.accounts.item_registry, player, item_type, item_id) {
+                    Ok(()) => emit!(PremiumEquipped::new(player, item_type, item_id)),
+                    Err(_) => msg!("{:?} {} added to the inventory without equipping.", item_type, item_id),
+                }
+            }
+        }
+    }
+
+    emit!(ChestOpened {
+        player: player.player_id,
+        slot: player.slot,
+        loot_table_version: loot_table.version,
+        reward,
+        chests_remaining: player.chests,
+    });
+    msg!("Player {} opened a chest and received {:?}.", player.player_id, reward);
+    Ok(())
+}
+// func16 - settle_chest_open - ACC.
+#[derive(Accounts)]
+pub struct SettleChestOpen<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [LootTable::SEED, &chest_open_request.loot_table_version.to_le_bytes()], bump = loot_table.bump)]
+    pub loot_table: Account<'info, LootTable>,
+    // The request is closed back to the player once settled
+    #[account(mut, close = signer, seeds = [ChestOpenRequest::SEED, player_account.key().as_ref()], bump = chest_open_request.bump)]
+    pub chest_open_request: Account<'info, ChestOpenRequest>,
+    #[account(init_if_needed, payer = signer, space = Inventory::LEN, seeds = [Inventory::SEED, player_account.key().as_ref()], bump)]
+    pub inventory: Account<'info, Inventory>,
+    /// CHECK: Checked against the account recorded in the request
+    pub randomness_account: UncheckedAccount<'info>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys settling the request
+    #[account(mut, constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func16 - settle_chest_open - END.
+
+// func17 - level_up_all
+pub fn level_up_all(ctx: Context<IncreasePlayerLevel>, _slot: u8, max_levels: u8) -> Result<LevelUpSummary> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::LevelUp)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::LevelUp)?;
+
+    let player = &mut ctx.accounts.player_account;
+    let summary = player.level_up_many(ctx.accounts.game_config.level_cap, max_levels)?;
+    reward_referrer(player, &ctx.accounts.referral_config, &mut ctx.accounts.referrer)?;
+
+    emit!(LeveledUp::new(player, summary.levels_gained));
+    msg!("Player {} gained {} levels, now level {} with {} XP.", player.player_id, summary.levels_gained, summary.level, summary.xp);
+    msg!("Chest rolls queued: {}, {} pending. Settle with request_level_up and settle_level_up.", summary.chest_rolls_queued, player.pending_chest_rolls);
+    Ok(summary)
+}
+// func17 - level_up_all - END.
+
+// func18 - migrate_player
+pub fn migrate_player(ctx: Context<MigratePlayer>) -> Result<()> {
+    let player_info = ctx.accounts.player_account.to_account_info();
+
+    // Legacy layouts cannot be loaded as the current Player, so check the raw data
+    let version = {
+        let data = player_info.try_borrow_data()?;
+        if data.len() < Player::LEGACY_LEN || data[..8] != Player::DISCRIMINATOR {
+            return Err(error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch));
+        }
+        data[Player::VERSION_OFFSET]
+    };
+    if version >= Player::CURRENT_VERSION {
+        return Err(error!(ZaiError::PlayerAlreadyMigrated));
+    }
+
+    // Top up rent for the new size from the payer
+    let required_lamports = Rent::get()?.minimum_balance(Player::LEN);
+    let current_lamports = player_info.lamports();
+    if current_lamports < required_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: player_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    // New fields are appended, so growing with zeroed bytes keeps existing values in place
+    if player_info.data_len() < Player::LEN {
+        player_info.realloc(Player::LEN, true)?;
+    }
+
+    let mut data = player_info.try_borrow_mut_data()?;
+    let mut player = Player::try_deserialize(&mut &data[..])?;
+    player.version = Player::CURRENT_VERSION;
+    player.try_serialize(&mut &mut data[..])?;
+
+    msg!("Player {} migrated from version {} to {}.", player.player_id, version, player.version);
+    Ok(())
+}
+// func18 - migrate_player - ACC.
+#[derive(Accounts)]
+pub struct MigratePlayer<'info> {
+    /// CHECK: Older layouts cannot be deserialized as Player, the discriminator is checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub player_account: UncheckedAccount<'info>,
+    // Anyone can pay to migrate an account, the layout change is the same for every player
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func18 - migrate_player - END.
+// func19 - grant_item
+pub fn grant_item(ctx: Context<GrantItem>, item_type: PremiumItemType, item_id: u8) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::ItemGranter)?;
+
+    // Free classes and weapons are available to everyone and never need to be owned
+    let item = ctx.accounts.item_registry.definition(item_type, item_id)?;
+    match item_type {
+        PremiumItemType::Class if !item.premium => return Err(error!(ZaiError::InvalidPremiumClass)),
+        PremiumItemType::Weapon if !item.premium => return Err(error!(ZaiError::InvalidPremiumWeapon)),
+        _ => {}
+    }
+
+    let inventory = &mut ctx.accounts.inventory;
+    if inventory.player == Pubkey::default() {
+        inventory.player = ctx.accounts.player_account.key();
+        inventory.bump = ctx.bumps.inventory;
+    }
+    if inventory.add(item_type, item_id)? {
+        msg!("Granted {:?} {} to player {}.", item_type, item_id, ctx.accounts.player_account.player_id);
+    } else {
+        msg!("Player {} already owns {:?} {}.", ctx.accounts.player_account.player_id, item_type, item_id);
+    }
+    Ok(())
+}
+// func19 - grant_item - ACC.
+#[derive(Accounts)]
+pub struct GrantItem<'info> {
+    pub player_account: Account<'info, Player>,
+    // Created on the first grant, paid for by the granting server
+    #[account(init_if_needed, payer = admin, space = Inventory::LEN, seeds = [Inventory::SEED, player_account.key().as_ref()], bump)]
+    pub inventory: Account<'info, Inventory>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func19 - grant_item - END.
+
+// func20 - revoke_item
+pub fn revoke_item(ctx: Context<RevokeItem>, item_type: PremiumItemType, item_id: u8) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::ItemGranter)?;
+
+    if !ctx.accounts.inventory.remove(item_type, item_id) {
+        return Err(error!(ZaiError::ItemNotOwned));
+    }
+
+    // Unequip the revoked item, falling back to the first default class and a free weapon.
+    // Fails if the registry has no free weapon to fall back to, so an operator can add one first.
+    let default_class = ctx.accounts.game_config.classes[0];
+    let item_registry = &ctx.accounts.item_registry;
+    let player = &mut ctx.accounts.player_account;
+    match item_type {
+        PremiumItemType::Class if player.active_class == item_id => {
+            player.active_class = default_class;
+            player.active_weapon = item_registry.free_weapon(default_class, player.level).ok_or(ZaiError::WeaponClassMismatch)?;
+            emit!(ClassChanged::new(player));
+        }
+        PremiumItemType::Weapon if player.active_weapon == item_id => {
+            player.active_weapon = item_registry.free_weapon(player.active_class, player.level).ok_or(ZaiError::WeaponClassMismatch)?;
+        }
+        PremiumItemType::Cosmetic if player.active_cosmetic == item_id => player.active_cosmetic = 0,
+        _ => {}
+    }
+    emit!(ItemRevoked::new(player, item_type, item_id));
+
+    msg!("Revoked {:?} {} from player {}.", item_type, item_id, player.player_id);
+    Ok(())
+}
+// func20 - revoke_item - ACC.
+#[derive(Accounts)]
+pub struct RevokeItem<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(mut, seeds = [Inventory::SEED, player_account.key().as_ref()], bump = inventory.bump)]
+    pub inventory: Account<'info, Inventory>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func20 - revoke_item - END.
+
+// func21 - equip_item
+pub fn equip_item(ctx: Context<EquipItem>, item_type: PremiumItemType, item_id: u8) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Equip)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+    Session::require_valid(&ctx.accounts.session, SessionScope::Equip)?;
+
+    // Free items are available to everyone, premium items must be in the inventory
+    let premium = ctx.accounts.item_registry.definition(item_type, item_id)?.premium;
+    if premium && !ctx.accounts.inventory.owns(item_type, item_id) {
+        return Err(error!(ZaiError::ItemNotOwned));
+    }
+
+    let player = &mut ctx.accounts.player_account;
+    equip_checked(&ctx.accounts.item_registry, player, item_type, item_id)?;
+    if premium {
+        emit!(PremiumEquipped::new(player, item_type, item_id));
+    } else {
+        emit!(ClassChanged::new(player));
+    }
+
+    msg!("Player {} equipped owned {:?} {}.", player.player_id, item_type, item_id);
+    Ok(())
+}
+// func21 - equip_item - ACC.
+#[derive(Accounts)]
+pub struct EquipItem<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [Inventory::SEED, player_account.key().as_ref()], bump = inventory.bump)]
+    pub inventory: Account<'info, Inventory>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [Session::SEED, player_account.key().as_ref(), signer.key().as_ref()], bump = session.bump)]
+    pub session: Option<Account<'info, Session>>,
+    // Ensure that the signer is the player or one of their session keys equipping the item
+    #[account(constraint = player_account.player_id == *signer.key || session.is_some() @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+}
+// func21 - equip_item - END.
+
+// Equips an owned item after checking it against the registry, swapping the weapon on class changes if needed
+fn equip_checked(item_registry: &ItemRegistry, player: &mut Player, item_type: PremiumItemType, item_id: u8) -> Result<()> {
+    item_registry.check_equip(item_type, item_id, player.level, player.active_class)?;
+    match item_type {
+        PremiumItemType::Class => {
+            player.active_weapon = item_registry.weapon_for_class(player.active_weapon, item_id, player.level)?;
+            player.active_class = item_id;
+        }
+        PremiumItemType::Weapon => player.active_weapon = item_id,
+        PremiumItemType::Cosmetic => player.active_cosmetic = item_id,
+    }
+    Ok(())
+}
+
+// func22 - set_item_definition
+pub fn set_item_definition(ctx: Context<SetItemDefinition>, item: ItemDefinition) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    if item.id == 0 {
+        return Err(error!(ZaiError::InvalidConfig));
+    }
+
+    let item_registry = &mut ctx.accounts.item_registry;
+    item_registry.bump = ctx.bumps.item_registry;
+    match item_registry.items.iter_mut().find(|entry| entry.kind == item.kind && entry.id == item.id) {
+        Some(entry) => *entry = item,
+        None => {
+            if item_registry.items.len() >= ItemRegistry::MAX_ITEMS {
+                return Err(error!(ZaiError::ItemRegistryFull));
+            }
+            item_registry.items.push(item);
+        }
+    }
+
+    msg!("Item definition set for {:?} {}.", item.kind, item.id);
+    Ok(())
+}
+// func22 - set_item_definition - ACC.
+#[derive(Accounts)]
+pub struct SetItemDefinition<'info> {
+    // Created with the first definition
+    #[account(init_if_needed, payer = admin, space = ItemRegistry::LEN, seeds = [ItemRegistry::SEED], bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func22 - set_item_definition - END.
+
+// func23 - remove_item_definition
+pub fn remove_item_definition(ctx: Context<RemoveItemDefinition>, item_type: PremiumItemType, item_id: u8) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    // Players keep removed items in their inventory but can no longer equip them
+    let item_registry = &mut ctx.accounts.item_registry;
+    item_registry.definition(item_type, item_id)?;
+    item_registry.items.retain(|entry| entry.kind != item_type || entry.id != item_id);
+
+    msg!("Item definition removed for {:?} {}.", item_type, item_id);
+    Ok(())
+}
+// func23 - remove_item_definition - ACC.
+#[derive(Accounts)]
+pub struct RemoveItemDefinition<'info> {
+    #[account(mut, seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func23 - remove_item_definition - END.
+
+// func24 - close_player
+pub fn close_player(ctx: Context<ClosePlayer>) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    let game_config = &ctx.accounts.game_config;
+    let player = &ctx.accounts.player_account;
+
+    // Open requests hold chest rolls and chests already taken off the player
+    let requests_open = ctx.accounts.level_up_request.owner == &crate::ID || ctx.accounts.chest_open_request.owner == &crate::ID;
+    if game_config.has_close_guard(CloseGuard::UnopenedChests) && (player.chests > 0 || player.pending_chest_rolls > 0 || requests_open) {
+        return Err(error!(ZaiError::UnopenedChests));
+    }
+    if game_config.has_close_guard(CloseGuard::InMatch) && player.in_match {
+        return Err(error!(ZaiError::PlayerInMatch));
+    }
+    // Leaving keeps the guild's member count right, and lets a leader hand over the guild first
+    if player.guild != Pubkey::default() {
+        return Err(error!(ZaiError::PlayerInGuild));
+    }
+    // Release the username with the player so the name can be taken again
+    if player.username != [0u8; Username::MAX_LEN] && ctx.accounts.username_account.is_none() {
+        return Err(error!(ZaiError::UsernameNotReleased));
+    }
+
+    // A new player in the same slot gets the same PDA, so nothing tied to it may outlive the player
+    let recipient = ctx.accounts.recipient.to_account_info();
+    close_program_account::<Inventory>(&ctx.accounts.inventory, &recipient)?;
+    close_program_account::<LevelUpRequest>(&ctx.accounts.level_up_request, &recipient)?;
+    close_program_account::<ChestOpenRequest>(&ctx.accounts.chest_open_request, &recipient)?;
+
+    // Sessions are keyed by session key as well, so they are passed as remaining accounts
+    let mut open_sessions = player.open_sessions;
+    for session_info in ctx.remaining_accounts {
+        if session_info.owner != &crate::ID || Session::try_deserialize(&mut &session_info.try_borrow_data()?[..])?.player != player.key() {
+            return Err(error!(ZaiError::InvalidSession));
+        }
+        close_program_account::<Session>(session_info, &recipient)?;
+        open_sessions = open_sessions.checked_sub(1).ok_or(ZaiError::InvalidSession)?;
+    }
+    if open_sessions > 0 {
+        return Err(error!(ZaiError::OpenSessions));
+    }
+
+    msg!("Player {} closed, rent returned to {}.", player.player_id, ctx.accounts.recipient.key());
+    Ok(())
+}
+// func24 - close_player - ACC.
+#[derive(Accounts)]
+pub struct ClosePlayer<'info> {
+    #[account(mut, close = recipient)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(mut, close = recipient, constraint = username_account.player == player_account.key() @ ZaiError::Unauthorized)]
+    pub username_account: Option<Account<'info, Username>>,
+    /// CHECK: Inventory PDA of the player, closed in the handler if it exists
+    #[account(mut, seeds = [Inventory::SEED, player_account.key().as_ref()], bump)]
+    pub inventory: UncheckedAccount<'info>,
+    /// CHECK: LevelUpRequest PDA of the player, closed in the handler if it exists
+    #[account(mut, seeds = [LevelUpRequest::SEED, player_account.key().as_ref()], bump)]
+    pub level_up_request: UncheckedAccount<'info>,
+    /// CHECK: ChestOpenRequest PDA of the player, closed in the handler if it exists
+    #[account(mut, seeds = [ChestOpenRequest::SEED, player_account.key().as_ref()], bump)]
+    pub chest_open_request: UncheckedAccount<'info>,
+    /// CHECK: Any account chosen by the player can receive the reclaimed rent
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    // Ensure that the signer is the player closing the account
+    #[account(constraint = player_account.player_id == *signer.key)]
+    pub signer: Signer<'info>,
+}
+// func24 - close_player - END.
+
+// Closes an account of type T owned by the program, skipping addresses that were never created
+fn close_program_account<T: Discriminator>(info: &AccountInfo, recipient: &AccountInfo) -> Result<()> {
+    if info.owner != &crate::ID {
+        return Ok(());
+    }
+    if info.try_borrow_data()?.get(..8) != Some(&T::DISCRIMINATOR[..]) {
+        return Err(error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch));
+    }
+    let lamports = recipient.lamports().checked_add(info.lamports()).ok_or(ProgramError::ArithmeticOverflow)?;
+    **recipient.try_borrow_mut_lamports()? = lamports;
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&system_program::ID);
+    info.realloc(0, false)?;
+    Ok(())
+}
+
+// func25 - set_in_match
+pub fn set_in_match(ctx: Context<SetInMatch>, in_match: bool) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    let player = &mut ctx.accounts.player_account;
+    player.in_match = in_match;
+
+    msg!("Player {} in match: {}.", player.player_id, in_match);
+    Ok(())
+}
+// func25 - set_in_match - ACC.
+#[derive(Accounts)]
+pub struct SetInMatch<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func25 - set_in_match - END.
+
+// func26 - grant_character_slots
+pub fn grant_character_slots(ctx: Context<GrantCharacterSlots>, count: u8) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::ItemGranter)?;
+
+    let wallet_account = &mut ctx.accounts.wallet_account;
+    if wallet_account.owner == Pubkey::default() {
+        wallet_account.owner = ctx.accounts.owner.key();
+        wallet_account.bump = ctx.bumps.wallet_account;
+        wallet_account.slots = WalletAccount::DEFAULT_SLOTS;
+    }
+    let slots = wallet_account.slots.checked_add(count).filter(|slots| *slots <= WalletAccount::MAX_SLOTS).ok_or(ZaiError::TooManySlots)?;
+    wallet_account.slots = slots;
+
+    msg!("Wallet {} now owns {} character slots.", wallet_account.owner, wallet_account.slots);
+    Ok(())
+}
+// func26 - grant_character_slots - ACC.
+#[derive(Accounts)]
+pub struct GrantCharacterSlots<'info> {
+    #[account(init_if_needed, payer = admin, space = WalletAccount::LEN, seeds = [WalletAccount::SEED, owner.key().as_ref()], bump)]
+    pub wallet_account: Account<'info, WalletAccount>,
+    /// CHECK: Only used as the seed of the wallet account receiving the slots
+    pub owner: UncheckedAccount<'info>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func26 - grant_character_slots - END.
+
+// func27 - set_username
+pub fn set_username(ctx: Context<SetUsername>, name: String) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    let normalized = Username::normalize(&name)?;
+    let player = &mut ctx.accounts.player_account;
+    if player.username != [0u8; Username::MAX_LEN] {
+        return Err(error!(ZaiError::UsernameAlreadySet));
+    }
+
+    // The PDA is seeded by the normalized name, so init fails if it is already taken
+    let username_account = &mut ctx.accounts.username_account;
+    username_account.player = player.key();
+    username_account.name = normalized;
+    username_account.bump = ctx.bumps.username_account;
+
+    player.username = normalized;
+    player.username_changed_at = Clock::get()?.unix_timestamp;
+
+    msg!("Player {} registered username {}.", player.player_id, name.to_ascii_lowercase());
+    Ok(())
+}
+// func27 - set_username - ACC.
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct SetUsername<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(init, payer = signer, space = Username::LEN, seeds = [Username::SEED, &Username::seed(&name)], bump)]
+    pub username_account: Account<'info, Username>,
+    // Ensure that the signer is the player registering the name
+    #[account(mut, constraint = player_account.player_id == *signer.key)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func27 - set_username - END.
+
+// func28 - change_username
+pub fn change_username(ctx: Context<ChangeUsername>, name: String) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    let normalized = Username::normalize(&name)?;
+    let game_config = &ctx.accounts.game_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    let player = &mut ctx.accounts.player_account;
+    if now < player.username_changed_at.saturating_add(game_config.username_cooldown) {
+        return Err(error!(ZaiError::UsernameCooldown));
+    }
+
+    if game_config.username_change_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.fee_recipient.to_account_info(),
+                },
+            ),
+            game_config.username_change_fee,
+        )?;
+    }
+
+    // The old name is released when its account is closed
+    let username_account = &mut ctx.accounts.new_username_account;
+    username_account.player = player.key();
+    username_account.name = normalized;
+    username_account.bump = ctx.bumps.new_username_account;
+
+    player.username = normalized;
+    player.username_changed_at = now;
+
+    msg!("Player {} changed username to {}.", player.player_id, name.to_ascii_lowercase());
+    Ok(())
+}
+// func28 - change_username - ACC.
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct ChangeUsername<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(mut, close = signer, constraint = old_username_account.player == player_account.key() @ ZaiError::Unauthorized)]
+    pub old_username_account: Account<'info, Username>,
+    #[account(init, payer = signer, space = Username::LEN, seeds = [Username::SEED, &Username::seed(&name)], bump)]
+    pub new_username_account: Account<'info, Username>,
+    /// CHECK: Receives the name change fee, must be the config authority
+    #[account(mut, address = game_config.authority @ ZaiError::Unauthorized)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    // Ensure that the signer is the player changing the name
+    #[account(mut, constraint = player_account.player_id == *signer.key)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func28 - change_username - END.
+
+// func29 - set_paused
+pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    let game_config = &mut ctx.accounts.game_config;
+    game_config.paused = paused;
+
+    msg!("Paused instruction groups set to {:#07b}.", game_config.paused);
+    Ok(())
+}
+// func29 - set_paused - ACC.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func29 - set_paused - END.
+
+// func30 - suspend_player
+pub fn suspend_player(ctx: Context<SuspendPlayer>, suspended_until: i64, reason: u8) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    if suspended_until <= Clock::get()?.unix_timestamp {
+        return Err(error!(ZaiError::InvalidConfig));
+    }
+
+    let player = &mut ctx.accounts.player_account;
+    player.suspended_until = suspended_until;
+    player.suspension_reason = reason;
+
+    emit!(PlayerSuspended {
+        player: player.player_id,
+        slot: player.slot,
+        suspended_until,
+        reason,
+    });
+    msg!("Player {} suspended until {} with reason {}.", player.player_id, suspended_until, reason);
+    Ok(())
+}
+// func30 - suspend_player - ACC.
+#[derive(Accounts)]
+pub struct SuspendPlayer<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func30 - suspend_player - END.
+
+// func31 - unsuspend_player
+pub fn unsuspend_player(ctx: Context<SuspendPlayer>) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    let player = &mut ctx.accounts.player_account;
+    player.suspended_until = 0;
+    player.suspension_reason = 0;
+
+    emit!(PlayerSuspended {
+        player: player.player_id,
+        slot: player.slot,
+        suspended_until: 0,
+        reason: 0,
+    });
+    msg!("Player {} unsuspended.", player.player_id);
+    Ok(())
+}
+// func31 - unsuspend_player - END.
+
+// func32 - create_session
+pub fn create_session(ctx: Context<CreateSession>, expires_at: i64, scope: u8, top_up: u64) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if expires_at <= now || expires_at > now.saturating_add(Session::MAX_DURATION_SECONDS) || scope == 0 {
+        return Err(error!(ZaiError::InvalidSession));
+    }
+
+    // Fund the session key so it can pay fees and rent without the wallet
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.session_key.to_account_info(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let session_key = ctx.accounts.session_key.key();
+    let session = &mut ctx.accounts.session;
+    session.player = ctx.accounts.player_account.key();
+    session.session_key = session_key;
+    session.expires_at = expires_at;
+    session.scope = scope;
+    session.bump = ctx.bumps.session;
+    let player = &mut ctx.accounts.player_account;
+    player.open_sessions = player.open_sessions.checked_add(1).ok_or(ZaiError::InvalidSession)?;
+
+    msg!("Session key {} created for player {} until {} with scope {:#06b}.", session_key, ctx.accounts.player_account.player_id, expires_at, scope);
+    Ok(())
+}
+// func32 - create_session - ACC.
+#[derive(Accounts)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(init, payer = signer, space = Session::LEN, seeds = [Session::SEED, player_account.key().as_ref(), session_key.key().as_ref()], bump)]
+    pub session: Account<'info, Session>,
+    /// CHECK: The ephemeral key being registered, only receives the optional top-up
+    #[account(mut)]
+    pub session_key: UncheckedAccount<'info>,
+    // Only the wallet that owns the player can create sessions
+    #[account(mut, constraint = player_account.player_id == *signer.key @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func32 - create_session - END.
+
+// func33 - revoke_session
+pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+    let player = &mut ctx.accounts.player_account;
+    player.open_sessions = player.open_sessions.checked_sub(1).ok_or(ZaiError::InvalidSession)?;
+    msg!("Session key {} revoked for player {}.", ctx.accounts.session.session_key, ctx.accounts.player_account.player_id);
+    Ok(())
+}
+// func33 - revoke_session - ACC.
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    // Expired sessions are revoked the same way to reclaim their rent
+    #[account(mut, close = signer, seeds = [Session::SEED, player_account.key().as_ref(), session.session_key.as_ref()], bump = session.bump)]
+    pub session: Account<'info, Session>,
+    // Only the wallet that owns the player can revoke sessions
+    #[account(mut, constraint = player_account.player_id == *signer.key @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+}
+// func33 - revoke_session - END.
+
+// func34 - create_player_sponsored
+pub fn create_player_sponsored(ctx: Context<CreatePlayerSponsored>, slot: u8, active_class: u8, active_weapon: u8) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Create)?;
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.payer.key, Role::Sponsor)?;
+
+    let owner = ctx.accounts.player.key();
+    let wallet_created = claim_slot(&mut ctx.accounts.wallet_account, owner, ctx.bumps.wallet_account, slot)?;
+    check_starting_loadout(&ctx.accounts.game_config, &ctx.accounts.item_registry, active_class, active_weapon)?;
+
+    // Rent paid for the player, and the wallet account on a wallet's first character, counts against today's budget
+    let rent = Rent::get()?;
+    let mut sponsored_lamports = rent.minimum_balance(Player::LEN);
+    if wallet_created {
+        sponsored_lamports += rent.minimum_balance(WalletAccount::LEN);
+    }
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.game_config.spend_sponsorship(sponsored_lamports, now)?;
+
+    let player_account = &mut ctx.accounts.player_account;
+    init_player(player_account, owner, slot, active_class, active_weapon)?;
+    attribute_referral(player_account, &mut ctx.accounts.referrer);
+
+    msg!("Player account {} created for player {} in slot {}, {} lamports of rent sponsored by {}.", player_account.key(), owner, slot, sponsored_lamports, ctx.accounts.payer.key());
+    Ok(())
+}
+// func34 - create_player_sponsored - ACC.
+#[derive(Accounts)]
+#[instruction(slot: u8)]
+pub struct CreatePlayerSponsored<'info> {
+    #[account(init, payer = payer, space = Player::LEN, seeds = [Player::SEED, player.key().as_ref(), Player::slot_seed(&slot)], bump)]
+    pub player_account: Account<'info, Player>,
+    #[account(init_if_needed, payer = payer, space = WalletAccount::LEN, seeds = [WalletAccount::SEED, player.key().as_ref()], bump)]
+    pub wallet_account: Account<'info, WalletAccount>,
+    #[account(mut, seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(seeds = [ItemRegistry::SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, constraint = referrer.player_id != *player.key @ ZaiError::InvalidReferrer)]
+    pub referrer: Option<Account<'info, Player>>,
+    pub player: Signer<'info>, // The wallet the player is created for, needs no SOL
+    #[account(mut)]
+    pub payer: Signer<'info>, // Server key with the Sponsor role paying the rent
+    pub system_program: Program<'info, System>,
+}
+// func34 - create_player_sponsored - END.
+
+// func35 - set_referral_config
+pub fn set_referral_config(ctx: Context<SetReferralConfig>, max_rewarded_referrals: u16, milestones: Vec<ReferralMilestone>) -> Result<()> {
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::Operator)?;
+
+    if milestones.len() > ReferralConfig::MAX_MILESTONES || milestones.iter().any(|milestone| milestone.level <= 1) {
+        return Err(error!(ZaiError::InvalidConfig));
+    }
+
+    let referral_config = &mut ctx.accounts.referral_config;
+    referral_config.set_milestones(milestones)?;
+    referral_config.bump = ctx.bumps.referral_config;
+    referral_config.max_rewarded_referrals = max_rewarded_referrals;
+
+    msg!("Referral config set with {} milestones and a cap of {} rewarded referrals.", referral_config.milestones.len(), max_rewarded_referrals);
+    Ok(())
+}
+// func35 - set_referral_config - ACC.
+#[derive(Accounts)]
+pub struct SetReferralConfig<'info> {
+    #[account(init_if_needed, payer = admin, space = ReferralConfig::LEN, seeds = [ReferralConfig::SEED], bump)]
+    pub referral_config: Account<'info, ReferralConfig>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func35 - set_referral_config - END.
+
+// func36 - create_guild
+pub fn create_guild(ctx: Context<CreateGuild>, name: String, join_policy: GuildJoinPolicy, member_cap: u16) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    if member_cap == 0 || member_cap > Guild::MAX_MEMBERS {
+        return Err(error!(ZaiError::InvalidConfig));
+    }
+
+    let guild_key = ctx.accounts.guild.key();
+    let player = &mut ctx.accounts.player_account;
+    let guild = &mut ctx.accounts.guild;
+    guild.name = Guild::normalize(&name)?;
+    guild.leader = player.key();
+    guild.member_cap = member_cap;
+    guild.join_policy = join_policy;
+    guild.level = 1;
+    guild.created_at = Clock::get()?.unix_timestamp;
+    guild.bump = ctx.bumps.guild;
+    guild.add_member(guild_key, player)?;
+
+    emit!(GuildMembershipChanged::new(player, guild_key, true));
+    msg!("Guild {} ({}) created by player {} with a cap of {} members.", name, guild_key, player.player_id, member_cap);
+    Ok(())
+}
+// func36 - create_guild - ACC.
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateGuild<'info> {
+    #[account(init, payer = signer, space = Guild::LEN, seeds = [Guild::SEED, &Username::seed(&name)], bump)]
+    pub guild: Account<'info, Guild>,
+    // The creating player becomes the leader
+    #[account(mut, constraint = player_account.player_id == *signer.key @ ZaiError::Unauthorized)]
+    pub player_account: Account<'info, Player>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func36 - create_guild - END.
+
+// func37 - invite
+pub fn invite(ctx: Context<Invite>) -> Result<()> {
+    ctx.accounts.officer_account.require_not_suspended()?;
+
+    let guild_key = ctx.accounts.guild.key();
+    let player_key = ctx.accounts.player_account.key();
+    let guild_invite = &mut ctx.accounts.guild_invite;
+    guild_invite.guild = guild_key;
+    guild_invite.player = player_key;
+    guild_invite.bump = ctx.bumps.guild_invite;
+
+    // Inviting a player that already applied accepts the application, the invite rent goes to the officer
+    if guild_invite.applied {
+        let player = &mut ctx.accounts.player_account;
+        ctx.accounts.guild.add_member(guild_key, player)?;
+        ctx.accounts.guild_invite.close(ctx.accounts.signer.to_account_info())?;
+
+        emit!(GuildMembershipChanged::new(player, guild_key, true));
+        msg!("Player {} accepted into guild {}.", player.player_id, guild_key);
+        return Ok(());
+    }
+
+    guild_invite.invited = true;
+    msg!("Player {} invited to guild {}.", ctx.accounts.player_account.player_id, guild_key);
+    Ok(())
+}
+// func37 - invite - ACC.
+#[derive(Accounts)]
+pub struct Invite<'info> {
+    #[account(mut)]
+    pub guild: Account<'info, Guild>,
+    #[account(constraint = officer_account.player_id == *signer.key @ ZaiError::Unauthorized, constraint = guild.is_officer(&officer_account.key()) @ ZaiError::NotGuildOfficer)]
+    pub officer_account: Account<'info, Player>,
+    #[account(mut, constraint = player_account.guild == Pubkey::default() @ ZaiError::AlreadyInGuild)]
+    pub player_account: Account<'info, Player>,
+    #[account(init_if_needed, payer = signer, space = GuildInvite::LEN, seeds = [GuildInvite::SEED, guild.key().as_ref(), player_account.key().as_ref()], bump)]
+    pub guild_invite: Account<'info, GuildInvite>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func37 - invite - END.
+
+// func38 - join_guild
+pub fn join_guild(ctx: Context<JoinGuild>) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    let guild_key = ctx.accounts.guild.key();
+    let guild = &mut ctx.accounts.guild;
+    let guild_invite = &mut ctx.accounts.guild_invite;
+    guild_invite.guild = guild_key;
+    guild_invite.player = ctx.accounts.player_account.key();
+    guild_invite.bump = ctx.bumps.guild_invite;
+
+    // Open guilds and invited players join directly, applications wait for an officer invite
+    if guild.join_policy != GuildJoinPolicy::Open && !guild_invite.invited {
+        if guild.join_policy == GuildJoinPolicy::InviteOnly {
+            return Err(error!(ZaiError::GuildInviteRequired));
+        }
+        guild_invite.applied = true;
+        msg!("Player {} applied to guild {}.", ctx.accounts.player_account.player_id, guild_key);
+        return Ok(());
+    }
+
+    let player = &mut ctx.accounts.player_account;
+    guild.add_member(guild_key, player)?;
+    ctx.accounts.guild_invite.close(ctx.accounts.signer.to_account_info())?;
+
+    emit!(GuildMembershipChanged::new(player, guild_key, true));
+    msg!("Player {} joined guild {}, {} of {} members.", player.player_id, guild_key, guild.member_count, guild.member_cap);
+    Ok(())
+}
+// func38 - join_guild - ACC.
+#[derive(Accounts)]
+pub struct JoinGuild<'info> {
+    #[account(mut)]
+    pub guild: Account<'info, Guild>,
+    #[account(mut, constraint = player_account.player_id == *signer.key @ ZaiError::Unauthorized, constraint = player_account.guild == Pubkey::default() @ ZaiError::AlreadyInGuild)]
+    pub player_account: Account<'info, Player>,
+    // Holds the invite or application, open guilds create and close it in the same instruction
+    #[account(init_if_needed, payer = signer, space = GuildInvite::LEN, seeds = [GuildInvite::SEED, guild.key().as_ref(), player_account.key().as_ref()], bump)]
+    pub guild_invite: Account<'info, GuildInvite>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func38 - join_guild - END.
+
+// func39 - leave_guild
+pub fn leave_guild(ctx: Context<LeaveGuild>) -> Result<()> {
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    let guild_key = ctx.accounts.guild.key();
+    let player_key = ctx.accounts.player_account.key();
+    let player = &mut ctx.accounts.player_account;
+    let guild = &mut ctx.accounts.guild;
+
+    // The leader hands over leadership first, unless they are the last member
+    if guild.leader == player_key && guild.member_count > 1 {
+        return Err(error!(ZaiError::GuildLeaderCannotLeave));
+    }
+    guild.remove_member(player, &player_key);
+    emit!(GuildMembershipChanged::new(player, guild_key, false));
+
+    // The last member leaving disbands the guild and frees its name
+    if guild.member_count == 0 {
+        guild.close(ctx.accounts.signer.to_account_info())?;
+        msg!("Player {} left and disbanded guild {}.", player.player_id, guild_key);
+        return Ok(());
+    }
+    msg!("Player {} left guild {}.", player.player_id, guild_key);
+    Ok(())
+}
+// func39 - leave_guild - ACC.
+#[derive(Accounts)]
+pub struct LeaveGuild<'info> {
+    #[account(mut)]
+    pub guild: Account<'info, Guild>,
+    #[account(mut, constraint = player_account.player_id == *signer.key @ ZaiError::Unauthorized, constraint = player_account.guild == guild.key() @ ZaiError::NotInGuild)]
+    pub player_account: Account<'info, Player>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
+// func39 - leave_guild - END.
+
+// func40 - kick
+pub fn kick(ctx: Context<Kick>) -> Result<()> {
+    ctx.accounts.officer_account.require_not_suspended()?;
+
+    let guild_key = ctx.accounts.guild.key();
+    let officer_key = ctx.accounts.officer_account.key();
+    let player_key = ctx.accounts.player_account.key();
+    let player = &mut ctx.accounts.player_account;
+    let guild = &mut ctx.accounts.guild;
+
+    // Nobody can kick the leader, and only the leader can kick officers
+    if player_key == guild.leader || (guild.officers.contains(&player_key) && officer_key != guild.leader) {
+        return Err(error!(ZaiError::NotGuildOfficer));
+    }
+    guild.remove_member(player, &player_key);
+
+    emit!(GuildMembershipChanged::new(player, guild_key, false));
+    msg!("Player {} kicked from guild {} by player {}.", player.player_id, guild_key, ctx.accounts.officer_account.player_id);
+    Ok(())
+}
+// func40 - kick - ACC.
+#[derive(Accounts)]
+pub struct Kick<'info> {
+    #[account(mut)]
+    pub guild: Account<'info, Guild>,
+    #[account(constraint = officer_account.player_id == *signer.key @ ZaiError::Unauthorized, constraint = guild.is_officer(&officer_account.key()) @ ZaiError::NotGuildOfficer)]
+    pub officer_account: Account<'info, Player>,
+    #[account(mut, constraint = player_account.guild == guild.key() @ ZaiError::NotInGuild)]
+    pub player_account: Account<'info, Player>,
+    pub signer: Signer<'info>,
+}
+// func40 - kick - END.
+
+// func41 - transfer_leadership
+pub fn transfer_leadership(ctx: Context<ManageGuildRoles>) -> Result<()> {
+    ctx.accounts.leader_account.require_not_suspended()?;
+
+    let new_leader = ctx.accounts.player_account.key();
+    let guild = &mut ctx.accounts.guild;
+
+    // The previous leader stays on as a regular member
+    guild.officers.retain(|officer| *officer != new_leader);
+    guild.leader = new_leader;
+
+    msg!("Guild {} leadership transferred from player {} to player {}.", guild.key(), ctx.accounts.leader_account.player_id, ctx.accounts.player_account.player_id);
+    Ok(())
+}
+// func41 - transfer_leadership - ACC.
+#[derive(Accounts)]
+pub struct ManageGuildRoles<'info> {
+    #[account(mut)]
+    pub guild: Account<'info, Guild>,
+    #[account(constraint = leader_account.player_id == *signer.key @ ZaiError::Unauthorized, constraint = leader_account.key() == guild.leader @ ZaiError::NotGuildOfficer)]
+    pub leader_account: Account<'info, Player>,
+    #[account(constraint = player_account.guild == guild.key() @ ZaiError::NotInGuild)]
+    pub player_account: Account<'info, Player>,
+    pub signer: Signer<'info>,
+}
+// func41 - transfer_leadership - END.
+
+// func42 - set_guild_officer
+pub fn set_guild_officer(ctx: Context<ManageGuildRoles>, promote: bool) -> Result<()> {
+    ctx.accounts.leader_account.require_not_suspended()?;
+
+    let player_key = ctx.accounts.player_account.key();
+    let guild = &mut ctx.accounts.guild;
+
+    guild.officers.retain(|officer| *officer != player_key);
+    if promote && player_key != guild.leader {
+        if guild.officers.len() >= Guild::MAX_OFFICERS {
+            return Err(error!(ZaiError::TooManyOfficers));
+        }
+        guild.officers.push(player_key);
+    }
+
+    msg!("Player {} officer status in guild {} set to {}.", ctx.accounts.player_account.player_id, guild.key(), promote);
+    Ok(())
+}
+// func42 - set_guild_officer - END.
+
+// func43 - settle_match
+pub fn settle_match<'info>(ctx: Context<'_, '_, 'info, 'info, SettleMatch<'info>>, match_id: u64, results: Vec<MatchResult>) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Xp)?;
+
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::XpGranter)?;
+
+    if results.is_empty() || results.len() > ctx.remaining_accounts.len() || results.len() > MatchRecord::MAX_PLAYERS {
+        return Err(error!(ZaiError::InvalidMatchResults));
+    }
+    // One player per result, followed by the guilds of players whose XP is shared
+    let (players, guild_infos) = ctx.remaining_accounts.split_at(results.len());
+    let mut guilds = (0..guild_infos.len()).map(|index| load_remaining_account::<Guild>(guild_infos, index, ZaiError::InvalidMatchResults)).collect::<Result<Vec<_>>>()?;
+
+    let game_config = &ctx.accounts.game_config;
+    let now = Clock::get()?.unix_timestamp;
+    for (index, result) in results.iter().enumerate() {
+        let mut player = load_remaining_account::<Player>(players, index, ZaiError::InvalidMatchResults)?;
+        player.in_match = false;
+
+        // Suspended players leave the match without rewards or penalties instead of failing the whole settlement
+        if player.is_suspended(now) {
+            msg!("Player {} is suspended, match results skipped.", player.player_id);
+        } else {
+            player.xp = player.xp.checked_add(result.xp_change).ok_or(ZaiError::XpOverflow)?;
+            player.chests = player.chests.saturating_add(result.chests as u16);
+
+            emit!(XpModified {
+                player: player.player_id,
+                slot: player.slot,
+                xp_change: result.xp_change,
+                xp: player.xp,
+            });
+            if result.chests > 0 {
+                emit!(ChestAwarded {
+                    player: player.player_id,
+                    slot: player.slot,
+                    rolls: 0,
+                    chests_awarded: result.chests,
+                    chests: player.chests,
+                });
+            }
+            share_guild_xp(game_config, &mut guilds, &player, result.xp_change)?;
+            if result.xp_change > 0 {
+                auto_level(game_config, &mut player);
+            }
+        }
+        player.exit(&crate::ID)?;
+    }
+    for guild in &guilds {
+        guild.exit(&crate::ID)?;
+    }
+
+    let match_record = &mut ctx.accounts.match_record;
+    match_record.match_id = match_id;
+    match_record.settled_at = now;
+    match_record.player_count = results.len() as u8;
+    match_record.bump = ctx.bumps.match_record;
+
+    msg!("Match {} settled for {} players.", match_id, results.len());
+    Ok(())
+}
+// func43 - settle_match - ACC.
+#[derive(Accounts)]
+#[instruction(match_id: u64)]
+pub struct SettleMatch<'info> {
+    // Fails to initialize if the match was already settled
+    #[account(init, payer = admin, space = MatchRecord::LEN, seeds = [MatchRecord::SEED, match_id.to_le_bytes().as_ref()], bump)]
+    pub match_record: Account<'info, MatchRecord>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+// func43 - settle_match - END.
+
+// Loads the account at `index` in a remaining accounts slice after checking it is writable, unique, owned by this program
+// and carries the discriminator of T. Each account is written back separately, so a duplicate would overwrite an earlier result.
+fn load_remaining_account<'info, T: AccountSerialize + AccountDeserialize + Discriminator + Owner + Clone>(accounts: &'info [AccountInfo<'info>], index: usize, error: ZaiError) -> Result<Account<'info, T>> {
+    let info = &accounts[index];
+    if !info.is_writable || accounts[..index].iter().any(|other| other.key == info.key) {
+        msg!("Account {} at index {} is read-only or duplicated.", info.key, index);
+        return Err(error!(error));
+    }
+    if info.owner != &crate::ID {
+        return Err(error!(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram));
+    }
+    if info.try_borrow_data()?.get(..8) != Some(&T::DISCRIMINATOR[..]) {
+        return Err(error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch));
+    }
+    Account::try_from_unchecked(info)
+}
+
+// func44 - modify_player_xp_batch
+pub fn modify_player_xp_batch<'info>(ctx: Context<'_, '_, 'info, 'info, ModifyPlayerXpBatch<'info>>, xp_changes: Vec<i64>) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Xp)?;
+
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, ctx.accounts.admin.key, Role::XpGranter)?;
+
+    if xp_changes.is_empty() || xp_changes.len() > ctx.remaining_accounts.len() {
+        return Err(error!(ZaiError::InvalidXpBatch));
+    }
+    // One player per delta, followed by the guilds of players whose XP is shared
+    let (players, guild_infos) = ctx.remaining_accounts.split_at(xp_changes.len());
+    let mut guilds = (0..guild_infos.len()).map(|index| load_remaining_account::<Guild>(guild_infos, index, ZaiError::InvalidXpBatch)).collect::<Result<Vec<_>>>()?;
+
+    let game_config = &ctx.accounts.game_config;
+    let now = Clock::get()?.unix_timestamp;
+    let mut skipped = 0;
+    for (index, xp_change) in xp_changes.iter().copied().enumerate() {
+        let mut player = load_remaining_account::<Player>(players, index, ZaiError::InvalidXpBatch)?;
+
+        // Same rule as modify_player_xp, but suspended players are skipped instead of failing the whole batch
+        if player.is_suspended(now) && (xp_change > 0 || !game_config.suspended_xp_penalties) {
+            msg!("Player {} is suspended, XP change skipped.", player.player_id);
+            skipped += 1;
+            continue;
+        }
+        player.xp = player.xp.checked_add(xp_change).ok_or(ZaiError::XpOverflow)?;
+
+        emit!(XpModified {
+            player: player.player_id,
+            slot: player.slot,
+            xp_change,
+            xp: player.xp,
+        });
+        share_guild_xp(game_config, &mut guilds, &player, xp_change)?;
+        if xp_change > 0 {
+            auto_level(game_config, &mut player);
+        }
+        player.exit(&crate::ID)?;
+    }
+    for guild in &guilds {
+        guild.exit(&crate::ID)?;
+    }
+
+    msg!("XP modified for {} players, {} skipped.", xp_changes.len() - skipped, skipped);
+    Ok(())
+}
+// func44 - modify_player_xp_batch - ACC.
+#[derive(Accounts)]
+pub struct ModifyPlayerXpBatch<'info> {
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    pub admin: Signer<'info>,
+}
+// func44 - modify_player_xp_batch - END.
+
+// func45 - redeem_xp_voucher
+pub fn redeem_xp_voucher(ctx: Context<RedeemXpVoucher>, voucher: XpVoucher) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Xp)?;
+    ctx.accounts.player_account.require_not_suspended()?;
+
+    if voucher.player != ctx.accounts.player_account.key() {
+        return Err(error!(ZaiError::InvalidVoucher));
+    }
+    if Clock::get()?.unix_timestamp >= voucher.expires_at {
+        return Err(error!(ZaiError::VoucherExpired));
+    }
+
+    // The voucher must be signed by a key that could grant the XP directly
+    let voucher_signer = voucher.verify_signature(&ctx.accounts.instructions)?;
+    require_role(&ctx.accounts.game_config, &ctx.accounts.authority_registry, &voucher_signer, Role::XpGranter)?;
+
+    let player = &mut ctx.accounts.player_account;
+    player.redeem_voucher_nonce(voucher.nonce)?;
+    player.xp = player.xp.checked_add(voucher.xp as i64).ok_or(ZaiError::XpOverflow)?;
+
+    emit!(XpModified {
+        player: player.player_id,
+        slot: player.slot,
+        xp_change: voucher.xp as i64,
+        xp: player.xp,
+    });
+    msg!("Player {} redeemed voucher {} from {} for {} XP.", player.player_id, voucher.nonce, voucher_signer, voucher.xp);
+
+    let game_config = &ctx.accounts.game_config;
+    share_guild_xp(game_config, ctx.accounts.guild.as_mut_slice(), player, voucher.xp as i64)?;
+    auto_level(game_config, player);
+    Ok(())
+}
+// func45 - redeem_xp_voucher - ACC.
+#[derive(Accounts)]
+pub struct RedeemXpVoucher<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [AuthorityRegistry::SEED], bump = authority_registry.bump)]
+    pub authority_registry: Account<'info, AuthorityRegistry>,
+    // Required for guild members while the config shares XP with guilds
+    #[account(mut, constraint = guild.key() == player_account.guild @ ZaiError::NotInGuild)]
+    pub guild: Option<Account<'info, Guild>>,
+    /// CHECK: Instructions sysvar, read to find the Ed25519 verification of the voucher
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    // Ensure that the signer is the player redeeming their own voucher
+    #[account(constraint = player_account.player_id == *signer.key @ ZaiError::Unauthorized)]
+    pub signer: Signer<'info>,
+}
+// func45 - redeem_xp_voucher - END.
+
+// func46 - claim_referral_reward
+pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PauseFlag::Xp)?;
+
+    // Catches up milestones reached through XP grants that level up without the referral accounts
+    let player = &mut ctx.accounts.player_account;
+    if !pay_referrer(player, &ctx.accounts.referral_config, &mut ctx.accounts.referrer)? {
+        msg!("No referral rewards due for player {} at level {}.", player.player_id, player.level);
+    }
+    Ok(())
+}
+// func46 - claim_referral_reward - ACC.
+#[derive(Accounts)]
+pub struct ClaimReferralReward<'info> {
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+    #[account(seeds = [GameConfig::SEED], bump = game_config.bump)]
+    pub game_config: Account<'info, GameConfig>,
+    #[account(seeds = [ReferralConfig::SEED], bump = referral_config.bump)]
+    pub referral_config: Account<'info, ReferralConfig>,
+    #[account(mut, constraint = referrer.key() == player_account.referred_by @ ZaiError::InvalidReferrer)]
+    pub referrer: Account<'info, Player>,
+    // Anyone can pay out rewards that are already due
+    pub signer: Signer<'info>,
+}
+// func46 - claim_referral_reward - END.
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{PremiumItemType, ZaiError};
+
+#[account] // 8 bytes
+pub struct Inventory {
+    pub player: Pubkey, // 32 bytes - player PDA the inventory belongs to
+    pub bump: u8, // 1 byte
+    pub classes: Vec<u8>, // 4 + 1 byte per owned premium class
+    pub weapons: Vec<u8>, // 4 + 1 byte per owned premium weapon
+    pub cosmetics: Vec<u8>, // 4 + 1 byte per owned cosmetic
+}
+
+impl Inventory {
+    pub const SEED: &'static [u8] = b"inventory";
+    pub const MAX_ITEMS_PER_KIND: usize = 32;
+    pub const LEN: usize = 8 + 32 + 1 + 3 * (4 + Self::MAX_ITEMS_PER_KIND);
+
+    pub fn items(&self, item_type: PremiumItemType) -> &Vec<u8> {
+        match item_type {
+            PremiumItemType::Class => &self.classes,
+            PremiumItemType::Weapon => &self.weapons,
+            PremiumItemType::Cosmetic => &self.cosmetics,
+        }
+    }
+
+    fn items_mut(&mut self, item_type: PremiumItemType) -> &mut Vec<u8> {
+        match item_type {
+            PremiumItemType::Class => &mut self.classes,
+            PremiumItemType::Weapon => &mut self.weapons,
+            PremiumItemType::Cosmetic => &mut self.cosmetics,
+        }
+    }
+
+    pub fn owns(&self, item_type: PremiumItemType, item_id: u8) -> bool {
+        self.items(item_type).contains(&item_id)
+    }
+
+    // Records the item as owned, returns false if it already was
+    pub fn add(&mut self, item_type: PremiumItemType, item_id: u8) -> Result<bool> {
+        let items = self.items_mut(item_type);
+        if items.contains(&item_id) {
+            return Ok(false);
+        }
+        if items.len() >= Self::MAX_ITEMS_PER_KIND {
+            return Err(error!(ZaiError::InventoryFull));
+        }
+        items.push(item_id);
+        Ok(true)
+    }
+
+    // Removes the item, returns false if it was not owned
+    pub fn remove(&mut self, item_type: PremiumItemType, item_id: u8) -> bool {
+        let items = self.items_mut(item_type);
+        let owned = items.len();
+        items.retain(|owned_id| *owned_id != item_id);
+        items.len() != owned
+    }
+}
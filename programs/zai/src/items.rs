@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::{PremiumItemType, ZaiError};
+
+#[account] // 8 bytes
+pub struct ItemRegistry {
+    pub bump: u8, // 1 byte
+    pub items: Vec<ItemDefinition>, // 4 + 12 bytes per entry
+}
+
+impl ItemRegistry {
+    pub const SEED: &'static [u8] = b"items";
+    pub const MAX_ITEMS: usize = 64;
+    pub const LEN: usize = 8 + 1 + 4 + Self::MAX_ITEMS * ItemDefinition::LEN;
+
+    pub fn get(&self, item_type: PremiumItemType, item_id: u8) -> Option<&ItemDefinition> {
+        self.items.iter().find(|item| item.kind == item_type && item.id == item_id)
+    }
+
+    pub fn definition(&self, item_type: PremiumItemType, item_id: u8) -> Result<&ItemDefinition> {
+        match self.get(item_type, item_id) {
+            Some(item) => Ok(item),
+            None => {
+                msg!("{:?} {} is not in the item registry.", item_type, item_id);
+                Err(error!(ZaiError::ItemNotRegistered))
+            }
+        }
+    }
+
+    // Checks the level requirement and, for weapons and cosmetics, that the class may use the item
+    pub fn check_equip(&self, item_type: PremiumItemType, item_id: u8, level: u8, active_class: u8) -> Result<&ItemDefinition> {
+        let item = self.definition(item_type, item_id)?;
+        if level < item.min_level {
+            return Err(error!(ZaiError::ItemLevelTooLow));
+        }
+        match item_type {
+            PremiumItemType::Weapon if !item.allows_class(active_class) => Err(error!(ZaiError::WeaponClassMismatch)),
+            PremiumItemType::Cosmetic if !item.allows_class(active_class) => Err(error!(ZaiError::ItemClassMismatch)),
+            _ => Ok(item),
+        }
+    }
+
+    // First free weapon the class can use at this level
+    pub fn free_weapon(&self, class: u8, level: u8) -> Option<u8> {
+        self.items
+            .iter()
+            .find(|item| item.kind == PremiumItemType::Weapon && !item.premium && level >= item.min_level && item.allows_class(class))
+            .map(|item| item.id)
+    }
+
+    // Keeps the current weapon if the new class can use it, otherwise falls back to a free weapon for the class
+    pub fn weapon_for_class(&self, active_weapon: u8, class: u8, level: u8) -> Result<u8> {
+        if self.check_equip(PremiumItemType::Weapon, active_weapon, level, class).is_ok() {
+            return Ok(active_weapon);
+        }
+        self.free_weapon(class, level).ok_or(error!(ZaiError::WeaponClassMismatch))
+    }
+}
+
+// Catalog entry describing a class, weapon or cosmetic
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ItemDefinition {
+    pub id: u8, // 1 byte
+    pub kind: PremiumItemType, // 1 byte
+    pub premium: bool, // 1 byte - must be owned in the player's inventory to equip
+    pub min_level: u8, // 1 byte
+    pub allowed_classes: [u8; ItemDefinition::MAX_ALLOWED_CLASSES], // 8 bytes - 0 = empty slot, all empty = any class
+}
+
+impl ItemDefinition {
+    pub const LEN: usize = 12;
+    pub const MAX_ALLOWED_CLASSES: usize = 8;
+
+    // Classes never restrict themselves, weapons and cosmetics can be limited to a set of classes
+    pub fn allows_class(&self, class: u8) -> bool {
+        self.kind == PremiumItemType::Class || self.allowed_classes.iter().all(|allowed| *allowed == 0) || (class != 0 && self.allowed_classes.contains(&class))
+    }
+}
@@ -1,9 +1,22 @@
 use anchor_lang::prelude::*;
-use crate::{errors::ZaiError, instructions::*, player::Player, player::PremiumItemType};
+use crate::instructions::*;
+pub use crate::{config::*, errors::ZaiError, events::*, guild::*, inventory::*, items::*, loot::*, matches::*, player::LevelUpSummary, player::Player, player::PremiumItemType, player::REQUIRED_XP, player::WalletAccount, randomness::*, referral::*, session::*, username::*, voucher::*};
 
+mod config;
 mod errors;
+mod events;
+mod guild;
 mod instructions;
+mod inventory;
+mod items;
+mod loot;
+mod matches;
 mod player;
+mod randomness;
+mod referral;
+mod session;
+mod username;
+mod voucher;
 
 declare_id!("HWjAY4TNEiAQquRKmwRXMabXf1PMGp36QyQgA162XdNr");
 
@@ -11,22 +24,40 @@ declare_id!("HWjAY4TNEiAQquRKmwRXMabXf1PMGp36QyQgA162XdNr");
 pub mod zai {
     use super::*;
     use crate::instructions::{
-        change_default_class as cdc, create_player as cp, equip_premium_item as epi,
-        increase_player_level as ipl, modify_player_xp as mpxp,
+        accept_server_key as ask, change_default_class as cdc, change_username as cu,
+        claim_referral_reward as crr, close_player as clp, create_guild as cg,
+        create_loot_table as clt, create_player as cp, create_player_sponsored as cps,
+        create_session as cs, equip_item as ei, equip_premium_item as epi,
+        grant_character_slots as gcs, grant_item as gi, grant_role as gr,
+        increase_player_level as ipl, initialize_config as ic, invite as inv, join_guild as jg,
+        kick as kck, leave_guild as lg, level_up_all as lua, migrate_player as mp,
+        modify_player_xp as mpxp, modify_player_xp_batch as mpxb, open_chest as oc,
+        propose_server_key as psk, redeem_xp_voucher as rxv, remove_item_definition as rid,
+        request_level_up as rlu, revoke_item as ri, revoke_role as rr, revoke_session as rs,
+        set_guild_officer as sgo, set_in_match as sim, set_item_definition as sid,
+        set_loot_table_version as sltv, set_paused as sp, set_referral_config as src,
+        set_username as su, settle_chest_open as sco, settle_level_up as slu,
+        settle_match as sm, suspend_player as susp, transfer_leadership as tl,
+        unsuspend_player as unsp, update_config as uc,
     };
 
     // Wrapper for create_player
     pub fn create_player(
         ctx: Context<CreatePlayer>,
+        slot: u8,
         active_class: u8,
         active_weapon: u8,
     ) -> Result<()> {
-        cp(ctx, active_class, active_weapon)
+        cp(ctx, slot, active_class, active_weapon)
     }
 
     // Wrapper for change_default_class
-    pub fn change_default_class(ctx: Context<ChangeDefaultClass>, new_class: u8) -> Result<()> {
-        cdc(ctx, new_class)
+    pub fn change_default_class(
+        ctx: Context<ChangeDefaultClass>,
+        slot: u8,
+        new_class: u8,
+    ) -> Result<()> {
+        cdc(ctx, slot, new_class)
     }
 
     // Wrapper for modify_player_xp
@@ -35,8 +66,8 @@ pub mod zai {
     }
 
     // Wrapper for increase_player_level
-    pub fn increase_player_level(ctx: Context<IncreasePlayerLevel>) -> Result<()> {
-        ipl(ctx)
+    pub fn increase_player_level(ctx: Context<IncreasePlayerLevel>, slot: u8) -> Result<()> {
+        ipl(ctx, slot)
     }
 
     // Wrapper for equip_premium_item
@@ -47,4 +78,268 @@ pub mod zai {
     ) -> Result<()> {
         epi(ctx, item_type, item_id)
     }
+
+    // Wrapper for initialize_config
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        server_key: Pubkey,
+        level_cap: u8,
+        classes: Vec<u8>,
+    ) -> Result<()> {
+        ic(ctx, server_key, level_cap, classes)
+    }
+
+    // Wrapper for update_config
+    pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+        uc(ctx, update)
+    }
+
+    // Wrapper for grant_role
+    pub fn grant_role(ctx: Context<ManageRole>, key: Pubkey, role: Role) -> Result<()> {
+        gr(ctx, key, role)
+    }
+
+    // Wrapper for revoke_role
+    pub fn revoke_role(ctx: Context<ManageRole>, key: Pubkey, role: Role) -> Result<()> {
+        rr(ctx, key, role)
+    }
+
+    // Wrapper for propose_server_key
+    pub fn propose_server_key(
+        ctx: Context<ProposeServerKey>,
+        new_server_key: Pubkey,
+    ) -> Result<()> {
+        psk(ctx, new_server_key)
+    }
+
+    // Wrapper for accept_server_key
+    pub fn accept_server_key(ctx: Context<AcceptServerKey>) -> Result<()> {
+        ask(ctx)
+    }
+
+    // Wrapper for create_loot_table
+    pub fn create_loot_table(ctx: Context<CreateLootTable>, entries: Vec<LootEntry>) -> Result<()> {
+        clt(ctx, entries)
+    }
+
+    // Wrapper for set_loot_table_version
+    pub fn set_loot_table_version(ctx: Context<SetLootTableVersion>) -> Result<()> {
+        sltv(ctx)
+    }
+
+    // Wrapper for open_chest
+    pub fn open_chest(ctx: Context<OpenChest>, commitment: [u8; 32]) -> Result<()> {
+        oc(ctx, commitment)
+    }
+
+    // Wrapper for request_level_up
+    pub fn request_level_up(ctx: Context<RequestLevelUp>, commitment: [u8; 32]) -> Result<()> {
+        rlu(ctx, commitment)
+    }
+
+    // Wrapper for settle_level_up
+    pub fn settle_level_up(ctx: Context<SettleLevelUp>, secret: [u8; 32]) -> Result<()> {
+        slu(ctx, secret)
+    }
+
+    // Wrapper for settle_chest_open
+    pub fn settle_chest_open(ctx: Context<SettleChestOpen>, secret: [u8; 32]) -> Result<()> {
+        sco(ctx, secret)
+    }
+
+    // Wrapper for level_up_all
+    pub fn level_up_all(
+        ctx: Context<IncreasePlayerLevel>,
+        slot: u8,
+        max_levels: u8,
+    ) -> Result<LevelUpSummary> {
+        lua(ctx, slot, max_levels)
+    }
+
+    // Wrapper for migrate_player
+    pub fn migrate_player(ctx: Context<MigratePlayer>) -> Result<()> {
+        mp(ctx)
+    }
+
+    // Wrapper for grant_item
+    pub fn grant_item(
+        ctx: Context<GrantItem>,
+        item_type: PremiumItemType,
+        item_id: u8,
+    ) -> Result<()> {
+        gi(ctx, item_type, item_id)
+    }
+
+    // Wrapper for revoke_item
+    pub fn revoke_item(
+        ctx: Context<RevokeItem>,
+        item_type: PremiumItemType,
+        item_id: u8,
+    ) -> Result<()> {
+        ri(ctx, item_type, item_id)
+    }
+
+    // Wrapper for equip_item
+    pub fn equip_item(
+        ctx: Context<EquipItem>,
+        item_type: PremiumItemType,
+        item_id: u8,
+    ) -> Result<()> {
+        ei(ctx, item_type, item_id)
+    }
+
+    // Wrapper for set_item_definition
+    pub fn set_item_definition(
+        ctx: Context<SetItemDefinition>,
+        item: ItemDefinition,
+    ) -> Result<()> {
+        sid(ctx, item)
+    }
+
+    // Wrapper for remove_item_definition
+    pub fn remove_item_definition(
+        ctx: Context<RemoveItemDefinition>,
+        item_type: PremiumItemType,
+        item_id: u8,
+    ) -> Result<()> {
+        rid(ctx, item_type, item_id)
+    }
+
+    // Wrapper for close_player
+    pub fn close_player(ctx: Context<ClosePlayer>) -> Result<()> {
+        clp(ctx)
+    }
+
+    // Wrapper for set_in_match
+    pub fn set_in_match(ctx: Context<SetInMatch>, in_match: bool) -> Result<()> {
+        sim(ctx, in_match)
+    }
+
+    // Wrapper for grant_character_slots
+    pub fn grant_character_slots(ctx: Context<GrantCharacterSlots>, count: u8) -> Result<()> {
+        gcs(ctx, count)
+    }
+
+    // Wrapper for set_username
+    pub fn set_username(ctx: Context<SetUsername>, name: String) -> Result<()> {
+        su(ctx, name)
+    }
+
+    // Wrapper for change_username
+    pub fn change_username(ctx: Context<ChangeUsername>, name: String) -> Result<()> {
+        cu(ctx, name)
+    }
+
+    // Wrapper for set_paused
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        sp(ctx, paused)
+    }
+
+    // Wrapper for suspend_player
+    pub fn suspend_player(
+        ctx: Context<SuspendPlayer>,
+        suspended_until: i64,
+        reason: u8,
+    ) -> Result<()> {
+        susp(ctx, suspended_until, reason)
+    }
+
+    // Wrapper for unsuspend_player
+    pub fn unsuspend_player(ctx: Context<SuspendPlayer>) -> Result<()> {
+        unsp(ctx)
+    }
+
+    // Wrapper for create_session
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        expires_at: i64,
+        scope: u8,
+        top_up: u64,
+    ) -> Result<()> {
+        cs(ctx, expires_at, scope, top_up)
+    }
+
+    // Wrapper for revoke_session
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        rs(ctx)
+    }
+
+    // Wrapper for create_player_sponsored
+    pub fn create_player_sponsored(
+        ctx: Context<CreatePlayerSponsored>,
+        slot: u8,
+        active_class: u8,
+        active_weapon: u8,
+    ) -> Result<()> {
+        cps(ctx, slot, active_class, active_weapon)
+    }
+
+    // Wrapper for set_referral_config
+    pub fn set_referral_config(
+        ctx: Context<SetReferralConfig>,
+        max_rewarded_referrals: u16,
+        milestones: Vec<ReferralMilestone>,
+    ) -> Result<()> {
+        src(ctx, max_rewarded_referrals, milestones)
+    }
+
+    // Wrapper for create_guild
+    pub fn create_guild(
+        ctx: Context<CreateGuild>,
+        name: String,
+        join_policy: GuildJoinPolicy,
+        member_cap: u16,
+    ) -> Result<()> {
+        cg(ctx, name, join_policy, member_cap)
+    }
+
+    // Wrapper for invite
+    pub fn invite(ctx: Context<Invite>) -> Result<()> {
+        inv(ctx)
+    }
+
+    // Wrapper for join_guild
+    pub fn join_guild(ctx: Context<JoinGuild>) -> Result<()> {
+        jg(ctx)
+    }
+
+    // Wrapper for leave_guild
+    pub fn leave_guild(ctx: Context<LeaveGuild>) -> Result<()> {
+        lg(ctx)
+    }
+
+    // Wrapper for kick
+    pub fn kick(ctx: Context<Kick>) -> Result<()> {
+        kck(ctx)
+    }
+
+    // Wrapper for transfer_leadership
+    pub fn transfer_leadership(ctx: Context<ManageGuildRoles>) -> Result<()> {
+        tl(ctx)
+    }
+
+    // Wrapper for set_guild_officer
+    pub fn set_guild_officer(ctx: Context<ManageGuildRoles>, promote: bool) -> Result<()> {
+        sgo(ctx, promote)
+    }
+
+    // Wrapper for settle_match
+    pub fn settle_match<'info>(ctx: Context<'_, '_, 'info, 'info, SettleMatch<'info>>, match_id: u64, results: Vec<MatchResult>) -> Result<()> {
+        sm(ctx, match_id, results)
+    }
+
+    // Wrapper for modify_player_xp_batch
+    pub fn modify_player_xp_batch<'info>(ctx: Context<'_, '_, 'info, 'info, ModifyPlayerXpBatch<'info>>, xp_changes: Vec<i64>) -> Result<()> {
+        mpxb(ctx, xp_changes)
+    }
+
+    // Wrapper for redeem_xp_voucher
+    pub fn redeem_xp_voucher(ctx: Context<RedeemXpVoucher>, voucher: XpVoucher) -> Result<()> {
+        rxv(ctx, voucher)
+    }
+
+    // Wrapper for claim_referral_reward
+    pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+        crr(ctx)
+    }
 }
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+#[account] // 8 bytes
+pub struct LootTable {
+    pub version: u16, // 2 bytes
+    pub total_weight: u32, // 4 bytes
+    pub bump: u8, // 1 byte
+    pub entries: Vec<LootEntry>, // 4 + 7 bytes per entry
+}
+
+impl LootTable {
+    pub const SEED: &'static [u8] = b"loot_table";
+    pub const MAX_ENTRIES: usize = 32;
+    pub const LEN: usize = 8 + 2 + 4 + 1 + 4 + Self::MAX_ENTRIES * LootEntry::LEN;
+
+    // Picks the entry whose cumulative weight range contains roll % total_weight
+    pub fn pick(&self, roll: u64) -> Option<&LootEntry> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let mut target = roll % self.total_weight as u64;
+        for entry in self.entries.iter() {
+            if target < entry.weight as u64 {
+                return Some(entry);
+            }
+            target -= entry.weight as u64;
+        }
+        None
+    }
+}
+
+// A weighted reward in a loot table
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct LootEntry {
+    pub weight: u16, // 2 bytes
+    pub reward: LootReward, // 5 bytes
+}
+
+impl LootEntry {
+    pub const LEN: usize = 7;
+}
+
+// Rewards that can drop from a chest
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub enum LootReward {
+    XpBonus { amount: u32 },
+    PremiumClass { class_id: u8 },
+    PremiumWeapon { weapon_id: u8 },
+    Cosmetic { cosmetic_id: u8 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(weights: &[u16]) -> LootTable {
+        let entries: Vec<LootEntry> = weights.iter().enumerate().map(|(index, weight)| LootEntry { weight: *weight, reward: LootReward::XpBonus { amount: index as u32 } }).collect();
+        LootTable { version: 1, total_weight: weights.iter().map(|weight| *weight as u32).sum(), bump: 0, entries }
+    }
+
+    fn picked(table: &LootTable, roll: u64) -> Option<u32> {
+        table.pick(roll).map(|entry| match entry.reward {
+            LootReward::XpBonus { amount } => amount,
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn pick_walks_cumulative_weights() {
+        let table = table(&[1, 3, 6]);
+        assert_eq!(picked(&table, 0), Some(0));
+        assert_eq!(picked(&table, 1), Some(1));
+        assert_eq!(picked(&table, 3), Some(1));
+        assert_eq!(picked(&table, 4), Some(2));
+        assert_eq!(picked(&table, 9), Some(2));
+    }
+
+    #[test]
+    fn pick_wraps_rolls_by_total_weight() {
+        let table = table(&[1, 3, 6]);
+        assert_eq!(picked(&table, 10), Some(0));
+        assert_eq!(picked(&table, u64::MAX), picked(&table, u64::MAX % 10));
+    }
+
+    #[test]
+    fn pick_on_empty_table_is_none() {
+        assert_eq!(picked(&table(&[]), 7), None);
+    }
+}
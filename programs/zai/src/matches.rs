@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account] // 8 bytes
+pub struct MatchRecord {
+    pub match_id: u64, // 8 bytes
+    pub settled_at: i64, // 8 bytes - unix timestamp
+    pub player_count: u8, // 1 byte
+    pub bump: u8, // 1 byte
+}
+
+impl MatchRecord {
+    pub const SEED: &'static [u8] = b"match";
+    pub const LEN: usize = 32; // 26 byte total + 6 byte buffer.
+    // Keeps a settlement within the transaction account and compute limits
+    pub const MAX_PLAYERS: usize = 16;
+}
+
+// Result for one player, passed in the same order as the player accounts in remaining_accounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct MatchResult {
+    pub xp_change: i64, // 8 bytes
+    pub chests: u8, // 1 byte
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use crate::{Username, ZaiError};
 
 #[account] // 8 bytes
+#[derive(Default)]
 pub struct Player {
     pub player_id: Pubkey, //32 bytes
     pub level: u8, // 1 byte
@@ -9,17 +11,251 @@ pub struct Player {
     pub active_class: u8, // 1 byte
     pub active_weapon: u8, // 1 byte
     pub joined: i64, // 8 bytes
+    pub active_cosmetic: u8, // 1 byte
+    pub pending_chest_rolls: u8, // 1 byte - level-ups waiting for a commit-reveal chest roll
+    pub version: u8, // 1 byte - layout version, 0 = legacy 64 byte account
+    pub in_match: bool, // 1 byte - set by the server while the player is in a match
+    pub slot: u8, // 1 byte - character slot of the owning wallet
+    pub username: [u8; Username::MAX_LEN], // 16 bytes - normalized username, all zero = none
+    pub username_changed_at: i64, // 8 bytes - unix timestamp of the last set or change
+    pub suspended_until: i64, // 8 bytes - unix timestamp the suspension ends, i64::MAX = permanent ban
+    pub suspension_reason: u8, // 1 byte - reason code chosen by the server, 0 = none
+    pub referred_by: Pubkey, // 32 bytes - referrer's player PDA, default = not referred
+    pub referral_count: u16, // 2 bytes - players created with this player as referrer
+    pub rewarded_referrals: u16, // 2 bytes - referees that have paid out milestone rewards to this player
+    pub referral_milestones_paid: u8, // 1 byte - bitmask of ReferralConfig milestones paid to the referrer
+    pub guild: Pubkey, // 32 bytes - guild PDA, default = not in a guild
+    pub voucher_nonce_base: u64, // 8 bytes - lowest XP voucher nonce still redeemable
+    pub voucher_nonce_bitmap: u64, // 8 bytes - redeemed nonces from voucher_nonce_base upwards, bit 0 = base
+    pub open_sessions: u8, // 1 byte - Session accounts not yet revoked, close_player must close them
 }
 
 impl Player {
-    pub const LEN: usize = 64; // 61 byte total + 3 byte buffer.
-    pub const LEVEL_CAP: u8 = 100;
+    pub const SEED: &'static [u8] = b"player";
+    pub const LEN: usize = 256; // 185 byte total + 71 byte buffer.
+    pub const LEGACY_LEN: usize = 64;
+    pub const CURRENT_VERSION: u8 = 1;
+    // Offset of the version byte, fixed so it can be read from any layout
+    pub const VERSION_OFFSET: usize = 63;
+
+    // Slot 0 adds no seed so characters created before slots existed keep their address
+    pub fn slot_seed(slot: &u8) -> &[u8] {
+        if *slot == 0 {
+            &[]
+        } else {
+            std::slice::from_ref(slot)
+        }
+    }
+
+    pub fn is_suspended(&self, now: i64) -> bool {
+        now < self.suspended_until
+    }
+
+    // Checked by every player-signed instruction
+    pub fn require_not_suspended(&self) -> Result<()> {
+        if self.is_suspended(Clock::get()?.unix_timestamp) {
+            msg!("Player {} is suspended until {} with reason {}.", self.player_id, self.suspended_until, self.suspension_reason);
+            return Err(error!(ZaiError::PlayerSuspended));
+        }
+        Ok(())
+    }
+
+    // Marks a voucher nonce as redeemed. The bitmap is a sliding window of the last 64 nonces,
+    // so vouchers can be redeemed out of order as long as they are not older than the window.
+    pub fn redeem_voucher_nonce(&mut self, nonce: u64) -> Result<()> {
+        if nonce < self.voucher_nonce_base {
+            return Err(error!(ZaiError::VoucherAlreadyRedeemed));
+        }
+        let window = u64::BITS as u64;
+        if nonce - self.voucher_nonce_base >= window {
+            let shift = nonce - self.voucher_nonce_base - (window - 1);
+            self.voucher_nonce_bitmap = if shift >= window { 0 } else { self.voucher_nonce_bitmap >> shift };
+            self.voucher_nonce_base += shift;
+        }
+        let bit = 1u64 << (nonce - self.voucher_nonce_base);
+        if self.voucher_nonce_bitmap & bit != 0 {
+            return Err(error!(ZaiError::VoucherAlreadyRedeemed));
+        }
+        self.voucher_nonce_bitmap |= bit;
+        Ok(())
+    }
+
+    // XP needed to go from `level` to `level + 1`
+    pub fn required_xp(level: u8) -> i64 {
+        REQUIRED_XP[level as usize]
+    }
+
+    // Levels the player up once, subtracting the required XP and queueing a chest roll
+    pub fn level_up(&mut self, level_cap: u8) -> Result<i64> {
+        // Ensure the player is not already at or above the level cap
+        if self.level >= level_cap {
+            return Err(ZaiError::LevelCapReached.into());
+        }
+
+        // Look up the required XP for the next level on the exponential curve
+        let required_xp_for_next_level = Player::required_xp(self.level);
+
+        // Check if the player has enough XP to level up
+        if self.xp < required_xp_for_next_level {
+            return Err(ZaiError::NotEnoughXp.into());
+        }
+
+        // Level up
+        self.level += 1;
+        // Subtract the required XP for leveling up from the player's current XP
+        self.xp -= required_xp_for_next_level;
+        // The 10% chest chance is rolled later through request_level_up / settle_level_up
+        self.pending_chest_rolls = self.pending_chest_rolls.saturating_add(1);
+
+        Ok(required_xp_for_next_level)
+    }
+
+    // Levels up while XP covers the next requirement, stopping at the cap or after max_levels
+    pub fn level_up_many(&mut self, level_cap: u8, max_levels: u8) -> Result<LevelUpSummary> {
+        if max_levels == 0 {
+            return Err(error!(ZaiError::InvalidMaxLevels));
+        }
+        let mut levels_gained: u8 = 0;
+        while levels_gained < max_levels {
+            match self.level_up(level_cap) {
+                Ok(_) => levels_gained += 1,
+                // At least one level is required, later failures just end the loop
+                Err(err) if levels_gained == 0 => return Err(err),
+                Err(_) => break,
+            }
+        }
+
+        Ok(LevelUpSummary {
+            levels_gained,
+            chest_rolls_queued: levels_gained,
+            level: self.level,
+            xp: self.xp,
+        })
+    }
 }
 
-// Enum to specify the type of premium item to equip
+#[account] // 8 bytes
+pub struct WalletAccount {
+    pub owner: Pubkey, // 32 bytes
+    pub bump: u8, // 1 byte
+    pub slots: u8, // 1 byte - character slots owned, slot indexes 0..slots are usable
+}
+
+impl WalletAccount {
+    pub const SEED: &'static [u8] = b"account";
+    pub const LEN: usize = 64; // 42 byte total + 22 byte buffer.
+    pub const DEFAULT_SLOTS: u8 = 3;
+    pub const MAX_SLOTS: u8 = 16;
+}
+
+// Returned by level_up_all. Chests are rolled per level through request_level_up / settle_level_up.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct LevelUpSummary {
+    pub levels_gained: u8,
+    pub chest_rolls_queued: u8,
+    pub level: u8,
+    pub xp: i64,
+}
+
+// Enum to specify the type of premium item to equip
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
 pub enum PremiumItemType {
     Class,
     Weapon,
+    Cosmetic,
 }
 
+// round(4250 * e^(0.055 * level)) for every level, computed off-chain with exact rounding
+// so clients and the program agree without floating point.
+pub const REQUIRED_XP: [i64; 256] = [
+    4250, 4490, 4744, 5012, 5296, 5595, 5912, 6246,
+    6599, 6972, 7366, 7783, 8223, 8688, 9179, 9698,
+    10246, 10826, 11438, 12084, 12768, 13490, 14252, 15058,
+    15910, 16809, 17759, 18764, 19825, 20945, 22130, 23381,
+    24703, 26100, 27575, 29134, 30782, 32522, 34361, 36304,
+    38356, 40525, 42816, 45237, 47795, 50497, 53352, 56369,
+    59556, 62923, 66481, 70240, 74211, 78407, 82841, 87525,
+    92473, 97702, 103226, 109062, 115229, 121744, 128627, 135900,
+    143584, 151702, 160279, 169342, 178916, 189033, 199721, 211013,
+    222944, 235549, 248867, 262938, 277805, 293512, 310107, 327641,
+    346166, 365739, 386418, 408266, 431350, 455738, 481506, 508731,
+    537495, 567885, 599994, 633918, 669760, 707628, 747638, 789910,
+    834572, 881759, 931614, 984288, 1039941, 1098740, 1160863, 1226499,
+    1295846, 1369114, 1446524, 1528312, 1614723, 1706021, 1802480, 1904394,
+    2012069, 2125833, 2246029, 2373021, 2507193, 2648951, 2798724, 2956966,
+    3124155, 3300796, 3487425, 3684606, 3892936, 4113045, 4345599, 4591302,
+    4850897, 5125170, 5414950, 5721115, 6044590, 6386355, 6747444, 7128948,
+    7532023, 7957889, 8407832, 8883216, 9385479, 9916140, 10476804, 11069169,
+    11695027, 12356271, 13054902, 13793034, 14572901, 15396862, 16267410, 17187179,
+    18158953, 19185671, 20270441, 21416544, 22627448, 23906818, 25258525, 26686657,
+    28195537, 29789730, 31474060, 33253622, 35133803, 37120289, 39219093, 41436565,
+    43779414, 46254729, 48870000, 51633139, 54552509, 57636941, 60895769, 64338854,
+    67976612, 71820051, 75880801, 80171148, 84704074, 89493295, 94553301, 99899402,
+    105547776, 111515512, 117820668, 124482321, 131520628, 138956885, 146813593, 155114523,
+    163884794, 173150941, 182941001, 193284598, 204213028, 215759358, 227958525, 240847440,
+    254465102, 268852716, 284053813, 300114391, 317083043, 335011113, 353952847, 373965559,
+    395109801, 417449552, 441052406, 465989781, 492337129, 520174173, 549585141, 580659022,
+    613489840, 648176933, 684825255, 723545696, 764455415, 807678194, 853344815, 901593456,
+    952570104, 1006429003, 1063333117, 1123454625, 1186975440, 1254087761, 1324994654, 1399910667,
+    1479062476, 1562689578, 1651045007, 1744396106, 1843025335, 1947231120, 2057328764, 2173651397,
+    2296550983, 2426399388, 2563589500, 2708536426, 2861678741, 3023479816, 3194429223, 3375044215,
+    3565871289, 3767487844, 3980503922, 4205564061, 4443349238, 4694578935, 4960013314, 5240455515,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(level: u8, xp: i64) -> Player {
+        Player { level, xp, ..Default::default() }
+    }
+
+    #[test]
+    fn level_up_many_rejects_zero_max_levels() {
+        let mut player = player(1, Player::required_xp(1));
+        assert_eq!(player.level_up_many(50, 0).unwrap_err(), error!(ZaiError::InvalidMaxLevels));
+        assert_eq!((player.level, player.pending_chest_rolls), (1, 0));
+    }
+
+    #[test]
+    fn level_up_many_stops_at_max_levels_and_cap() {
+        let mut player = player(1, Player::required_xp(1) + Player::required_xp(2) + Player::required_xp(3));
+        let summary = player.level_up_many(50, 2).unwrap();
+        assert_eq!((summary.levels_gained, summary.level, player.pending_chest_rolls), (2, 3, 2));
+
+        let summary = player.level_up_many(4, u8::MAX).unwrap();
+        assert_eq!((summary.levels_gained, summary.level, summary.xp), (1, 4, 0));
+        assert_eq!(player.level_up_many(4, 1).unwrap_err(), error!(ZaiError::LevelCapReached));
+    }
+
+    #[test]
+    fn redeem_voucher_nonce_accepts_out_of_order_and_rejects_replay() {
+        let mut player = player(1, 0);
+        for nonce in [5, 0, 63, 2] {
+            player.redeem_voucher_nonce(nonce).unwrap();
+        }
+        assert_eq!(player.voucher_nonce_base, 0);
+        for nonce in [5, 0, 63, 2] {
+            assert_eq!(player.redeem_voucher_nonce(nonce).unwrap_err(), error!(ZaiError::VoucherAlreadyRedeemed));
+        }
+    }
+
+    #[test]
+    fn redeem_voucher_nonce_shifts_the_window() {
+        let mut player = player(1, 0);
+        player.redeem_voucher_nonce(10).unwrap();
+        player.redeem_voucher_nonce(70).unwrap();
+        // 70 is now the top of the window, so 7 is the oldest nonce still accepted
+        assert_eq!(player.voucher_nonce_base, 7);
+        assert_eq!(player.redeem_voucher_nonce(6).unwrap_err(), error!(ZaiError::VoucherAlreadyRedeemed));
+        assert_eq!(player.redeem_voucher_nonce(10).unwrap_err(), error!(ZaiError::VoucherAlreadyRedeemed));
+        player.redeem_voucher_nonce(7).unwrap();
+
+        // A jump past the whole window clears it
+        player.redeem_voucher_nonce(1_000).unwrap();
+        assert_eq!((player.voucher_nonce_base, player.voucher_nonce_bitmap), (937, 1 << 63));
+        assert_eq!(player.redeem_voucher_nonce(70).unwrap_err(), error!(ZaiError::VoucherAlreadyRedeemed));
+        player.redeem_voucher_nonce(999).unwrap();
+        assert_eq!(player.redeem_voucher_nonce(1_000).unwrap_err(), error!(ZaiError::VoucherAlreadyRedeemed));
+    }
+}
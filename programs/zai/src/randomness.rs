@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::slot_hashes};
+use crate::{GameConfig, ZaiError};
+
+#[account] // 8 bytes
+pub struct LevelUpRequest {
+    pub player: Pubkey, // 32 bytes - player PDA the request belongs to
+    pub randomness: RandomnessCommitment, // 73 bytes
+    pub rolls: u8, // 1 byte - chest rolls committed to this request
+    pub bump: u8, // 1 byte
+}
+
+impl LevelUpRequest {
+    pub const SEED: &'static [u8] = b"level_up";
+    pub const LEN: usize = 128; // 115 byte total + 13 byte buffer.
+    // 10% chance per roll
+    pub const CHEST_ODDS: u64 = 10;
+}
+
+#[account] // 8 bytes
+pub struct ChestOpenRequest {
+    pub player: Pubkey, // 32 bytes - player PDA the request belongs to
+    pub randomness: RandomnessCommitment, // 73 bytes
+    pub loot_table_version: u16, // 2 bytes - table the chest is rolled against
+    pub bump: u8, // 1 byte
+}
+
+impl ChestOpenRequest {
+    pub const SEED: &'static [u8] = b"chest_open";
+    pub const LEN: usize = 128; // 116 byte total + 12 byte buffer.
+}
+
+// Where chest drops and loot rolls take their entropy from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum RandomnessSource {
+    // Hash of a future slot from the SlotHashes sysvar
+    #[default]
+    SlotHashes,
+    // Fulfilment written by an external VRF-style oracle program
+    Oracle,
+}
+
+// Owner program and fulfilment layout of oracle randomness accounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct OracleConfig {
+    pub program: Pubkey, // 32 bytes - program that must own the randomness account
+    pub discriminator: [u8; 8], // 8 bytes - expected first 8 bytes of account data
+    pub fulfilled_offset: u16, // 2 bytes - non-zero byte once randomness is written
+    pub randomness_offset: u16, // 2 bytes - start of the 32 byte randomness value
+}
+
+impl OracleConfig {
+    pub const LEN: usize = 44;
+
+    // Returns the randomness once the oracle has fulfilled the account. The account must name the
+    // trusted fulfiller, otherwise the requester could create one they fulfil themselves.
+    fn read(&self, fulfiller: &OracleFulfiller, account: &AccountInfo) -> Result<Option<[u8; 32]>> {
+        if *account.owner != self.program || fulfiller.key == Pubkey::default() {
+            return Err(error!(ZaiError::InvalidRandomnessAccount));
+        }
+        let data = account.try_borrow_data()?;
+        if data.get(0..8) != Some(&self.discriminator[..]) {
+            return Err(error!(ZaiError::InvalidRandomnessAccount));
+        }
+        let start = fulfiller.offset as usize;
+        if data.get(start..start + 32) != Some(fulfiller.key.as_ref()) {
+            return Err(error!(ZaiError::InvalidRandomnessAccount));
+        }
+        let fulfilled = *data.get(self.fulfilled_offset as usize).ok_or(ZaiError::InvalidRandomnessAccount)?;
+        if fulfilled == 0 {
+            return Ok(None);
+        }
+        let start = self.randomness_offset as usize;
+        let randomness = data.get(start..start + 32).ok_or(ZaiError::InvalidRandomnessAccount)?;
+        Ok(Some(randomness.try_into().unwrap()))
+    }
+}
+
+// Key the oracle records as the only party allowed to fulfil a randomness account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct OracleFulfiller {
+    pub key: Pubkey, // 32 bytes - trusted fulfiller, default = oracle randomness disabled
+    pub offset: u16, // 2 bytes - start of the 32 byte fulfiller key in the randomness account
+}
+
+impl OracleFulfiller {
+    pub const LEN: usize = 34;
+}
+
+// Result of reading the randomness a request committed to
+#[derive(Debug, PartialEq)]
+pub enum RandomnessLookup {
+    Found([u8; 32]),
+    // The slot has not been produced or the oracle has not fulfilled yet
+    Pending,
+    // The slot is older than the oldest retained SlotHashes entry, or the oracle missed its deadline
+    Expired,
+}
+
+// Randomness a two-phase request is bound to, fixed before the outcome can be known
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RandomnessCommitment {
+    pub source: RandomnessSource, // 1 byte
+    pub account: Pubkey, // 32 bytes - SlotHashes sysvar or oracle randomness account
+    pub target_slot: u64, // 8 bytes - slot whose hash seeds the rolls, or start of the oracle fulfilment window
+    pub commitment: [u8; 32], // 32 bytes - sha256 of the player's secret
+}
+
+impl RandomnessCommitment {
+    // Slots to wait after the request before its slot hash can be used
+    pub const REVEAL_DELAY_SLOTS: u64 = 2;
+    // Slots the oracle has to fulfil after the target slot, about an hour, before the request can be forfeited
+    pub const ORACLE_EXPIRY_SLOTS: u64 = 9_000;
+
+    // Binds a request to the configured source, rejecting oracle accounts that are already fulfilled
+    pub fn commit(game_config: &GameConfig, account: &AccountInfo, commitment: [u8; 32]) -> Result<Self> {
+        let current_slot = Clock::get()?.slot;
+        match game_config.randomness_source {
+            RandomnessSource::SlotHashes => {
+                if *account.key != slot_hashes::ID {
+                    return Err(error!(ZaiError::InvalidRandomnessAccount));
+                }
+            }
+            RandomnessSource::Oracle => {
+                if game_config.oracle.read(&game_config.oracle_fulfiller, account)?.is_some() {
+                    return Err(error!(ZaiError::InvalidRandomnessAccount));
+                }
+            }
+        }
+        Ok(Self {
+            source: game_config.randomness_source,
+            account: *account.key,
+            target_slot: current_slot + Self::REVEAL_DELAY_SLOTS,
+            commitment,
+        })
+    }
+
+    // Checks the revealed secret and reads the committed randomness
+    pub fn reveal(&self, game_config: &GameConfig, account: &AccountInfo, secret: &[u8; 32]) -> Result<RandomnessLookup> {
+        if hashv(&[secret]).to_bytes() != self.commitment {
+            return Err(error!(ZaiError::InvalidReveal));
+        }
+        if *account.key != self.account {
+            return Err(error!(ZaiError::InvalidRandomnessAccount));
+        }
+        match self.source {
+            RandomnessSource::SlotHashes => find_slot_hash(&account.try_borrow_data()?, self.target_slot),
+            RandomnessSource::Oracle => Ok(match game_config.oracle.read(&game_config.oracle_fulfiller, account)? {
+                Some(randomness) => RandomnessLookup::Found(randomness),
+                // An oracle that never fulfils must not lock the request PDA forever
+                None if Clock::get()?.slot > self.target_slot.saturating_add(Self::ORACLE_EXPIRY_SLOTS) => RandomnessLookup::Expired,
+                None => RandomnessLookup::Pending,
+            }),
+        }
+    }
+
+    // Mixes the revealed secret, the committed randomness and the roll index into a roll value
+    pub fn roll(secret: &[u8; 32], randomness: &[u8; 32], player: &Pubkey, index: u8) -> u64 {
+        let hash = hashv(&[secret, randomness, player.as_ref(), &[index]]).to_bytes();
+        u64::from_le_bytes(hash[0..8].try_into().unwrap())
+    }
+}
+
+// Finds the hash of the first produced slot at or after target_slot in raw SlotHashes data.
+// Entries are stored newest first as (slot: u64, hash: [u8; 32]) after a u64 length prefix.
+pub fn find_slot_hash(data: &[u8], target_slot: u64) -> Result<RandomnessLookup> {
+    const ENTRY_LEN: usize = 40;
+    let len_bytes: [u8; 8] = data.get(0..8).ok_or(ProgramError::InvalidAccountData)?.try_into().unwrap();
+    let len = u64::from_le_bytes(len_bytes) as usize;
+
+    let mut closest = None;
+    for index in 0..len {
+        let offset = 8 + index * ENTRY_LEN;
+        let entry = data.get(offset..offset + ENTRY_LEN).ok_or(ProgramError::InvalidAccountData)?;
+        let slot = u64::from_le_bytes(entry[0..8].try_into().unwrap());
+        let hash: [u8; 32] = entry[8..ENTRY_LEN].try_into().unwrap();
+        if slot < target_slot {
+            // The target is inside the retained window, a skipped target uses the next produced slot
+            return Ok(match closest {
+                Some(hash) => RandomnessLookup::Found(hash),
+                None => RandomnessLookup::Pending,
+            });
+        }
+        if slot == target_slot {
+            return Ok(RandomnessLookup::Found(hash));
+        }
+        closest = Some(hash);
+    }
+
+    // Every retained entry is newer than the target, so it may have been evicted
+    Ok(match closest {
+        Some(_) => RandomnessLookup::Expired,
+        None => RandomnessLookup::Pending,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Raw SlotHashes data with the entries given newest first
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn find_slot_hash_exact_slot() {
+        let data = slot_hashes(&[12, 11, 10, 9]);
+        assert_eq!(find_slot_hash(&data, 10).unwrap(), RandomnessLookup::Found([10; 32]));
+    }
+
+    #[test]
+    fn find_slot_hash_skipped_slot_uses_next_produced() {
+        // Slot 10 was skipped, 11 is the first produced slot after it
+        let data = slot_hashes(&[12, 11, 9, 8]);
+        assert_eq!(find_slot_hash(&data, 10).unwrap(), RandomnessLookup::Found([11; 32]));
+    }
+
+    #[test]
+    fn find_slot_hash_pending() {
+        let data = slot_hashes(&[9, 8]);
+        assert_eq!(find_slot_hash(&data, 10).unwrap(), RandomnessLookup::Pending);
+        assert_eq!(find_slot_hash(&slot_hashes(&[]), 10).unwrap(), RandomnessLookup::Pending);
+    }
+
+    #[test]
+    fn find_slot_hash_expired_window() {
+        // Every retained entry is newer than the target
+        let data = slot_hashes(&[14, 13, 12]);
+        assert_eq!(find_slot_hash(&data, 10).unwrap(), RandomnessLookup::Expired);
+    }
+
+    #[test]
+    fn find_slot_hash_truncated_data() {
+        let mut data = slot_hashes(&[12, 11]);
+        data.truncate(8 + 40 + 20);
+        assert!(find_slot_hash(&data, 10).is_err());
+        assert!(find_slot_hash(&[0; 4], 10).is_err());
+    }
+}
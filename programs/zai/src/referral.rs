@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::{Player, ZaiError};
+
+#[account] // 8 bytes
+pub struct ReferralConfig {
+    pub bump: u8, // 1 byte
+    pub max_rewarded_referrals: u16, // 2 bytes - referees per referrer that can pay out rewards, 0 = rewards disabled
+    pub milestones: Vec<ReferralMilestone>, // 4 + 6 bytes per entry
+}
+
+impl ReferralConfig {
+    pub const SEED: &'static [u8] = b"referrals";
+    // Paid milestones are tracked as a u8 bitmask on the referee
+    pub const MAX_MILESTONES: usize = 8;
+    pub const LEN: usize = 8 + 1 + 2 + 4 + Self::MAX_MILESTONES * ReferralMilestone::LEN;
+
+    // Replaces the milestones. Paid milestones are tracked by index on each referee, so existing
+    // entries keep their position and level and may only change their reward, new ones are appended.
+    pub fn set_milestones(&mut self, milestones: Vec<ReferralMilestone>) -> Result<()> {
+        if milestones.len() < self.milestones.len() || self.milestones.iter().zip(&milestones).any(|(old, new)| old.level != new.level) {
+            return Err(error!(ZaiError::ReferralMilestonesChanged));
+        }
+        self.milestones = milestones;
+        Ok(())
+    }
+
+    // Pays the referrer for every milestone the referee has reached but not yet paid out.
+    // Returns the XP and chests awarded, None if nothing was due or the referrer is at the cap.
+    pub fn reward(&self, referee: &mut Player, referrer: &mut Player) -> Result<Option<(u64, u16)>> {
+        let mut due = 0u8;
+        let mut xp = 0u64;
+        let mut chests = 0u16;
+        for (index, milestone) in self.milestones.iter().enumerate() {
+            let bit = 1 << index;
+            if referee.level >= milestone.level && referee.referral_milestones_paid & bit == 0 {
+                due |= bit;
+                xp += milestone.xp as u64;
+                chests += milestone.chests as u16;
+            }
+        }
+        if due == 0 {
+            return Ok(None);
+        }
+
+        // A referee's first payout uses up one of the referrer's rewarded referrals
+        if referee.referral_milestones_paid == 0 {
+            if referrer.rewarded_referrals >= self.max_rewarded_referrals {
+                msg!("Referrer {} reached the cap of {} rewarded referrals.", referrer.player_id, self.max_rewarded_referrals);
+                return Ok(None);
+            }
+            referrer.rewarded_referrals += 1;
+        }
+
+        referee.referral_milestones_paid |= due;
+        referrer.xp = referrer.xp.checked_add(xp as i64).ok_or(ZaiError::XpOverflow)?;
+        referrer.chests = referrer.chests.saturating_add(chests);
+        Ok(Some((xp, chests)))
+    }
+}
+
+// Reward paid to the referrer once a referee reaches the level
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ReferralMilestone {
+    pub level: u8, // 1 byte
+    pub xp: u32, // 4 bytes
+    pub chests: u8, // 1 byte
+}
+
+impl ReferralMilestone {
+    pub const LEN: usize = 6;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestone(level: u8, xp: u32) -> ReferralMilestone {
+        ReferralMilestone { level, xp, chests: 0 }
+    }
+
+    fn config(milestones: Vec<ReferralMilestone>) -> ReferralConfig {
+        ReferralConfig { bump: 0, max_rewarded_referrals: 10, milestones }
+    }
+
+    #[test]
+    fn set_milestones_allows_appends_and_reward_changes() {
+        let mut config = config(vec![]);
+        config.set_milestones(vec![milestone(5, 100)]).unwrap();
+        config.set_milestones(vec![milestone(5, 250), milestone(10, 500)]).unwrap();
+        assert_eq!(config.milestones.iter().map(|milestone| (milestone.level, milestone.xp)).collect::<Vec<_>>(), [(5, 250), (10, 500)]);
+    }
+
+    #[test]
+    fn set_milestones_rejects_moved_or_removed_entries() {
+        let mut config = config(vec![milestone(5, 100), milestone(10, 500)]);
+        let changed = [
+            vec![milestone(5, 100)],
+            vec![milestone(10, 500), milestone(5, 100)],
+            vec![milestone(3, 50), milestone(5, 100), milestone(10, 500)],
+            vec![milestone(5, 100), milestone(11, 500)],
+        ];
+        for milestones in changed {
+            assert_eq!(config.set_milestones(milestones).unwrap_err(), error!(ZaiError::ReferralMilestonesChanged));
+        }
+        assert_eq!(config.milestones.len(), 2);
+    }
+}
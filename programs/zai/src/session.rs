@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::ZaiError;
+
+#[account] // 8 bytes
+pub struct Session {
+    pub player: Pubkey, // 32 bytes - player PDA the session acts for
+    pub session_key: Pubkey, // 32 bytes - ephemeral key allowed to sign for the player
+    pub expires_at: i64, // 8 bytes - unix timestamp
+    pub scope: u8, // 1 byte - SessionScope bitmask of allowed instructions
+    pub bump: u8, // 1 byte
+}
+
+impl Session {
+    pub const SEED: &'static [u8] = b"session";
+    pub const LEN: usize = 96; // 82 byte total + 14 byte buffer.
+    pub const MAX_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    // Owner signatures need no session, session keys must be unexpired and scoped for the instruction
+    pub fn require_valid(session: &Option<Account<Session>>, scope: SessionScope) -> Result<()> {
+        let Some(session) = session else {
+            return Ok(());
+        };
+        if Clock::get()?.unix_timestamp >= session.expires_at {
+            return Err(error!(ZaiError::SessionExpired));
+        }
+        if session.scope & scope.mask() == 0 {
+            return Err(error!(ZaiError::SessionScopeMismatch));
+        }
+        Ok(())
+    }
+}
+
+// Player-signed instruction groups a session key can be allowed to sign
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionScope {
+    ClassChange,
+    LevelUp,
+    Chests,
+    Equip,
+}
+
+impl SessionScope {
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::ZaiError;
+
+#[account] // 8 bytes
+pub struct Username {
+    pub player: Pubkey, // 32 bytes - player PDA the name points at
+    pub name: [u8; Username::MAX_LEN], // 16 bytes - normalized name, zero padded
+    pub bump: u8, // 1 byte
+}
+
+impl Username {
+    pub const SEED: &'static [u8] = b"username";
+    pub const LEN: usize = 64; // 57 byte total + 7 byte buffer.
+    pub const MIN_LEN: usize = 3;
+    pub const MAX_LEN: usize = 16;
+
+    // Lowercased name used as the PDA seed, cut off past MAX_LEN so the seed stays valid.
+    // Over-long names are rejected by normalize in the handler.
+    pub fn seed(name: &str) -> Vec<u8> {
+        name.bytes().take(Self::MAX_LEN + 1).map(|byte| byte.to_ascii_lowercase()).collect()
+    }
+
+    // Validates length and charset (a-z, 0-9, _) and returns the zero padded lowercase name
+    pub fn normalize(name: &str) -> Result<[u8; Username::MAX_LEN]> {
+        if name.len() < Self::MIN_LEN || name.len() > Self::MAX_LEN {
+            return Err(error!(ZaiError::InvalidUsername));
+        }
+        let mut normalized = [0u8; Self::MAX_LEN];
+        for (index, byte) in name.bytes().enumerate() {
+            let byte = byte.to_ascii_lowercase();
+            if !(byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_') {
+                return Err(error!(ZaiError::InvalidUsername));
+            }
+            normalized[index] = byte;
+        }
+        Ok(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn padded(name: &str) -> [u8; Username::MAX_LEN] {
+        let mut padded = [0u8; Username::MAX_LEN];
+        padded[..name.len()].copy_from_slice(name.as_bytes());
+        padded
+    }
+
+    #[test]
+    fn normalize_lowercases_and_pads() {
+        assert_eq!(Username::normalize("Zai_Player42").unwrap(), padded("zai_player42"));
+    }
+
+    #[test]
+    fn normalize_checks_length() {
+        assert!(Username::normalize("ab").is_err());
+        assert_eq!(Username::normalize("abc").unwrap(), padded("abc"));
+        assert_eq!(Username::normalize("a234567890123456").unwrap(), padded("a234567890123456"));
+        assert!(Username::normalize("a2345678901234567").is_err());
+    }
+
+    #[test]
+    fn normalize_rejects_other_characters() {
+        for name in ["zai-player", "zai player", "zai.player", "zaï_player", "zai\0player"] {
+            assert_eq!(Username::normalize(name).unwrap_err(), error!(ZaiError::InvalidUsername), "{name}");
+        }
+    }
+
+    #[test]
+    fn seed_lowercases_and_stops_past_max_len() {
+        assert_eq!(Username::seed("ZaiPlayer"), b"zaiplayer".to_vec());
+        assert_eq!(Username::seed(&"A".repeat(40)), b"a".repeat(Username::MAX_LEN + 1));
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions::{load_current_index_checked, load_instruction_at_checked}};
+use crate::ZaiError;
+
+// Off-chain XP grant signed by a server key and redeemed by the player through redeem_xp_voucher
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct XpVoucher {
+    pub player: Pubkey, // 32 bytes - player PDA the XP is granted to
+    pub xp: u32, // 4 bytes
+    pub nonce: u64, // 8 bytes - unique per player, checked against Player::voucher_nonce_bitmap
+    pub expires_at: i64, // 8 bytes - unix timestamp
+}
+
+impl XpVoucher {
+    // Domain prefix so a voucher signature cannot be mistaken for any other signed message
+    pub const MESSAGE_PREFIX: &'static [u8] = b"zai_xp_voucher";
+    // Layout of the Ed25519 program instruction data
+    const SIGNATURE_OFFSETS_START: usize = 2;
+    const SIGNATURE_OFFSETS_LEN: usize = 14;
+
+    // Signed message: prefix, program id, then the borsh encoded voucher
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = [Self::MESSAGE_PREFIX, crate::ID.as_ref()].concat();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    // Checks that the instruction right before this one is an Ed25519 verification of this voucher
+    // with all data inline, and returns the key that signed it.
+    pub fn verify_signature(&self, instructions: &AccountInfo) -> Result<Pubkey> {
+        let current_index = load_current_index_checked(instructions)?;
+        if current_index == 0 {
+            return Err(error!(ZaiError::InvalidVoucher));
+        }
+        let ix = load_instruction_at_checked(current_index as usize - 1, instructions)?;
+        if ix.program_id != ed25519_program::ID || !ix.accounts.is_empty() || ix.data.first() != Some(&1) {
+            return Err(error!(ZaiError::InvalidVoucher));
+        }
+
+        let offsets = ix.data.get(Self::SIGNATURE_OFFSETS_START..Self::SIGNATURE_OFFSETS_START + Self::SIGNATURE_OFFSETS_LEN).ok_or(ZaiError::InvalidVoucher)?;
+        let read = |index: usize| u16::from_le_bytes([offsets[index * 2], offsets[index * 2 + 1]]);
+        let (signature_ix, public_key_offset, public_key_ix) = (read(1), read(2) as usize, read(3));
+        let (message_offset, message_size, message_ix) = (read(4) as usize, read(5) as usize, read(6));
+        // u16::MAX points the Ed25519 program at its own instruction data
+        if signature_ix != u16::MAX || public_key_ix != u16::MAX || message_ix != u16::MAX {
+            return Err(error!(ZaiError::InvalidVoucher));
+        }
+
+        let public_key = ix.data.get(public_key_offset..public_key_offset + 32).ok_or(ZaiError::InvalidVoucher)?;
+        let message = ix.data.get(message_offset..message_offset + message_size).ok_or(ZaiError::InvalidVoucher)?;
+        if message != self.message()? {
+            return Err(error!(ZaiError::InvalidVoucher));
+        }
+        Ok(Pubkey::try_from(public_key).map_err(|_| ZaiError::InvalidVoucher)?)
+    }
+}
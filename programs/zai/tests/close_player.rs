@@ -0,0 +1,84 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use solana_sdk::{instruction::{AccountMeta, Instruction}, signature::{Keypair, Signer}};
+use zai::{ChestOpenRequest, CloseGuard, Inventory, LevelUpRequest, Player, RandomnessCommitment, RandomnessSource, Session, ZaiError};
+
+struct Accounts {
+    player: Pubkey,
+    inventory: Pubkey,
+    level_up_request: Pubkey,
+    session: Pubkey,
+}
+
+// Player with an inventory, an open level-up request and one session
+fn add_player_with_accounts(program_test: &mut solana_program_test::ProgramTest, owner: Pubkey) -> Accounts {
+    let player = player_address(&owner);
+    add_account(program_test, player, &Player { player_id: owner, level: 2, version: Player::CURRENT_VERSION, open_sessions: 1, ..Default::default() }, Player::LEN);
+
+    let (inventory, bump) = Pubkey::find_program_address(&[Inventory::SEED, player.as_ref()], &zai::ID);
+    add_account(program_test, inventory, &Inventory { player, bump, classes: vec![5], weapons: vec![], cosmetics: vec![] }, Inventory::LEN);
+
+    let (level_up_request, bump) = Pubkey::find_program_address(&[LevelUpRequest::SEED, player.as_ref()], &zai::ID);
+    let randomness = RandomnessCommitment { source: RandomnessSource::SlotHashes, account: Pubkey::default(), target_slot: 10, commitment: [0; 32] };
+    add_account(program_test, level_up_request, &LevelUpRequest { player, randomness, rolls: 1, bump }, LevelUpRequest::LEN);
+
+    let session_key = Pubkey::new_unique();
+    let (session, bump) = Pubkey::find_program_address(&[Session::SEED, player.as_ref(), session_key.as_ref()], &zai::ID);
+    add_account(program_test, session, &Session { player, session_key, expires_at: i64::MAX, scope: 1, bump }, Session::LEN);
+
+    Accounts { player, inventory, level_up_request, session }
+}
+
+fn close_player(accounts: &Accounts, owner: Pubkey, recipient: Pubkey, sessions: &[Pubkey]) -> Instruction {
+    let mut instruction = instruction(
+        zai::accounts::ClosePlayer {
+            player_account: accounts.player,
+            game_config: game_config_address().0,
+            username_account: None,
+            inventory: accounts.inventory,
+            level_up_request: accounts.level_up_request,
+            chest_open_request: Pubkey::find_program_address(&[ChestOpenRequest::SEED, accounts.player.as_ref()], &zai::ID).0,
+            recipient,
+            signer: owner,
+        },
+        zai::instruction::ClosePlayer {},
+    );
+    instruction.accounts.extend(sessions.iter().map(|session| AccountMeta::new(*session, false)));
+    instruction
+}
+
+#[tokio::test]
+async fn close_player_closes_every_account_tied_to_the_player() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    add_game_config(&mut program_test, &game_config(Pubkey::new_unique()));
+    let accounts = add_player_with_accounts(&mut program_test, owner.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let recipient = Pubkey::new_unique();
+
+    // The open session has to be passed along
+    let result = process_instruction(&mut context, close_player(&accounts, owner.pubkey(), recipient, &[]), &[&owner]).await;
+    assert_zai_error(result, ZaiError::OpenSessions);
+
+    process_instruction(&mut context, close_player(&accounts, owner.pubkey(), recipient, &[accounts.session]), &[&owner]).await.unwrap();
+
+    for address in [accounts.player, accounts.inventory, accounts.level_up_request, accounts.session] {
+        assert!(context.banks_client.get_account(address).await.unwrap().is_none());
+    }
+    assert!(context.banks_client.get_balance(recipient).await.unwrap() > 0);
+}
+
+#[tokio::test]
+async fn unopened_chests_guard_counts_open_requests() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    add_game_config(&mut program_test, &zai::GameConfig { close_guards: CloseGuard::UnopenedChests.mask(), ..game_config(Pubkey::new_unique()) });
+    let accounts = add_player_with_accounts(&mut program_test, owner.pubkey());
+    let mut context = program_test.start_with_context().await;
+
+    let result = process_instruction(&mut context, close_player(&accounts, owner.pubkey(), owner.pubkey(), &[accounts.session]), &[&owner]).await;
+    assert_zai_error(result, ZaiError::UnopenedChests);
+    assert!(context.banks_client.get_account(accounts.level_up_request).await.unwrap().is_some());
+}
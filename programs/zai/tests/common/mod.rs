@@ -0,0 +1,151 @@
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{account::Account, instruction::{Instruction, InstructionError}, signature::{Keypair, Signer}, transaction::{Transaction, TransactionError}};
+use zai::{AuthorityRegistry, GameConfig, Guild, GuildJoinPolicy, Player, Username};
+
+// Anchor's entry wants the accounts to outlive the instruction, which the test processor does not provide
+fn process(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    zai::entry(program_id, accounts, data)
+}
+
+fn process_mock_oracle(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    mock_oracle::entry(program_id, accounts, data)
+}
+
+// zai and the mock oracle as native programs
+pub fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new("zai", zai::ID, processor!(process));
+    program_test.add_program("mock_oracle", mock_oracle::ID, processor!(process_mock_oracle));
+    program_test
+}
+
+// Writes an Anchor account owned by the program, padded to its allocated length
+pub fn add_account<T: AccountSerialize>(program_test: &mut ProgramTest, address: Pubkey, account: &T, len: usize) {
+    let mut data = Vec::with_capacity(len);
+    account.try_serialize(&mut data).unwrap();
+    data.resize(len, 0);
+    program_test.add_account(address, Account {
+        lamports: Rent::default().minimum_balance(len),
+        data,
+        owner: zai::ID,
+        executable: false,
+        rent_epoch: 0,
+    });
+}
+
+pub fn game_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GameConfig::SEED], &zai::ID)
+}
+
+pub fn player_address(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Player::SEED, owner.as_ref()], &zai::ID).0
+}
+
+// Config as initialize_config leaves it, with the server key set
+pub fn game_config(server_key: Pubkey) -> GameConfig {
+    GameConfig {
+        server_key,
+        level_cap: 50,
+        classes: [1, 2, 0, 0, 0, 0, 0, 0],
+        bump: game_config_address().1,
+        key_overlap_slots: GameConfig::DEFAULT_KEY_OVERLAP_SLOTS,
+        ..Default::default()
+    }
+}
+
+pub fn add_game_config(program_test: &mut ProgramTest, game_config: &GameConfig) {
+    add_account(program_test, game_config_address().0, game_config, GameConfig::LEN);
+}
+
+// Registry without entries, so only the config server key holds roles
+pub fn add_authority_registry(program_test: &mut ProgramTest) -> Pubkey {
+    let (address, bump) = Pubkey::find_program_address(&[AuthorityRegistry::SEED], &zai::ID);
+    add_account(program_test, address, &AuthorityRegistry { bump, authorities: vec![] }, AuthorityRegistry::LEN);
+    address
+}
+
+// Slot 0 player of the owner wallet
+pub fn add_player(program_test: &mut ProgramTest, owner: Pubkey, level: u8, xp: i64) -> Pubkey {
+    let address = player_address(&owner);
+    let player = Player {
+        player_id: owner,
+        level,
+        xp,
+        active_class: 1,
+        version: Player::CURRENT_VERSION,
+        ..Default::default()
+    };
+    add_account(program_test, address, &player, Player::LEN);
+    address
+}
+
+pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    program_instruction(zai::ID, accounts, data)
+}
+
+pub fn program_instruction(program_id: Pubkey, accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub async fn process_instruction(context: &mut ProgramTestContext, instruction: Instruction, signers: &[&Keypair]) -> std::result::Result<(), BanksClientError> {
+    process_instructions(context, &[instruction], signers).await
+}
+
+pub async fn process_instructions(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> std::result::Result<(), BanksClientError> {
+    // A fresh blockhash keeps repeated identical transactions from being deduplicated
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(instructions, Some(&context.payer.pubkey()), &all_signers, blockhash);
+    context.banks_client.process_transaction(transaction).await
+}
+
+pub async fn fetch<T: AccountDeserialize>(context: &mut ProgramTestContext, address: Pubkey) -> Option<T> {
+    let account = context.banks_client.get_account(address).await.unwrap()?;
+    Some(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+}
+
+pub fn assert_zai_error(result: std::result::Result<(), BanksClientError>, error: zai::ZaiError) {
+    assert_zai_error_at(result, 0, error);
+}
+
+pub fn assert_zai_error_at(result: std::result::Result<(), BanksClientError>, index: u8, error: zai::ZaiError) {
+    let expected = TransactionError::InstructionError(index, InstructionError::Custom(error.into()));
+    assert_eq!(result.unwrap_err().unwrap(), expected);
+}
+
+// Guild at a fresh address, guild PDAs are only checked when the guild is created
+pub fn add_guild(program_test: &mut ProgramTest, member_count: u16) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let guild = Guild {
+        name: [0; Username::MAX_LEN],
+        leader: Pubkey::new_unique(),
+        officers: vec![],
+        member_count,
+        member_cap: Guild::MAX_MEMBERS,
+        join_policy: GuildJoinPolicy::Open,
+        xp: 0,
+        level: 1,
+        created_at: 0,
+        bump: 0,
+    };
+    add_account(program_test, address, &guild, Guild::LEN);
+    address
+}
+
+// Slot 0 player in the guild
+pub fn add_guild_member(program_test: &mut ProgramTest, guild: Pubkey) -> Pubkey {
+    let owner = Pubkey::new_unique();
+    let address = player_address(&owner);
+    add_account(program_test, address, &Player { player_id: owner, level: 1, version: Player::CURRENT_VERSION, guild, ..Default::default() }, Player::LEN);
+    address
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use zai::{GameConfig, Player, ZaiError};
+
+#[tokio::test]
+async fn previous_server_key_is_accepted_until_the_overlap_expires() {
+    let mut program_test = program_test();
+    let (authority, old_key, new_key) = (Keypair::new(), Keypair::new(), Keypair::new());
+    add_game_config(&mut program_test, &GameConfig { authority: authority.pubkey(), ..game_config(old_key.pubkey()) });
+    let authority_registry = add_authority_registry(&mut program_test);
+    let player = add_player(&mut program_test, Pubkey::new_unique(), 1, 0);
+    let mut context = program_test.start_with_context().await;
+    let (address, _) = game_config_address();
+
+    let propose = instruction(
+        zai::accounts::ProposeServerKey { game_config: address, authority: authority.pubkey() },
+        zai::instruction::ProposeServerKey { new_server_key: new_key.pubkey() },
+    );
+    process_instruction(&mut context, propose, &[&authority]).await.unwrap();
+    let accept = instruction(zai::accounts::AcceptServerKey { game_config: address, new_server_key: new_key.pubkey() }, zai::instruction::AcceptServerKey {});
+    process_instruction(&mut context, accept, &[&new_key]).await.unwrap();
+
+    let rotated: GameConfig = fetch(&mut context, address).await.unwrap();
+    assert_eq!((rotated.server_key, rotated.previous_server_key), (new_key.pubkey(), old_key.pubkey()));
+    let grant = |admin: Pubkey| instruction(
+        zai::accounts::ModifyPlayerXp { player_account: player, game_config: address, authority_registry, guild: None, admin },
+        zai::instruction::ModifyPlayerXp { xp_change: 10 },
+    );
+
+    // Both keys work inside the overlap window, up to and including the expiry slot
+    process_instruction(&mut context, grant(old_key.pubkey()), &[&old_key]).await.unwrap();
+    context.warp_to_slot(rotated.previous_key_expiry_slot).unwrap();
+    process_instruction(&mut context, grant(old_key.pubkey()), &[&old_key]).await.unwrap();
+    process_instruction(&mut context, grant(new_key.pubkey()), &[&new_key]).await.unwrap();
+
+    context.warp_to_slot(rotated.previous_key_expiry_slot + 1).unwrap();
+    let result = process_instruction(&mut context, grant(old_key.pubkey()), &[&old_key]).await;
+    assert_zai_error(result, ZaiError::MissingRole);
+    process_instruction(&mut context, grant(new_key.pubkey()), &[&new_key]).await.unwrap();
+
+    let granted: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!(granted.xp, 40);
+}
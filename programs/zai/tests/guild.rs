@@ -0,0 +1,180 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_program;
+use common::*;
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{account::Account, instruction::Instruction, signature::{Keypair, Signer}};
+use zai::{Guild, GuildInvite, GuildJoinPolicy, Player, Username, ZaiError};
+
+// Wallet with lamports for rent and its slot 0 player
+fn add_wallet_player(program_test: &mut ProgramTest) -> (Keypair, Pubkey) {
+    let owner = Keypair::new();
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let player = add_player(program_test, owner.pubkey(), 1, 0);
+    (owner, player)
+}
+
+fn guild_address(name: &str) -> Pubkey {
+    Pubkey::find_program_address(&[Guild::SEED, &Username::seed(name)], &zai::ID).0
+}
+
+fn guild_invite_address(guild: Pubkey, player: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[GuildInvite::SEED, guild.as_ref(), player.as_ref()], &zai::ID).0
+}
+
+async fn create_guild(context: &mut ProgramTestContext, name: &str, join_policy: GuildJoinPolicy, (owner, player): &(Keypair, Pubkey)) -> Pubkey {
+    let guild = guild_address(name);
+    let create = instruction(
+        zai::accounts::CreateGuild { guild, player_account: *player, signer: owner.pubkey(), system_program: system_program::ID },
+        zai::instruction::CreateGuild { name: name.to_string(), join_policy, member_cap: 10 },
+    );
+    process_instruction(context, create, &[owner]).await.unwrap();
+    guild
+}
+
+fn join_guild(guild: Pubkey, (owner, player): &(Keypair, Pubkey)) -> Instruction {
+    instruction(
+        zai::accounts::JoinGuild { guild, player_account: *player, guild_invite: guild_invite_address(guild, *player), signer: owner.pubkey(), system_program: system_program::ID },
+        zai::instruction::JoinGuild {},
+    )
+}
+
+fn invite(guild: Pubkey, (owner, officer): &(Keypair, Pubkey), player: Pubkey) -> Instruction {
+    instruction(
+        zai::accounts::Invite {
+            guild,
+            officer_account: *officer,
+            player_account: player,
+            guild_invite: guild_invite_address(guild, player),
+            signer: owner.pubkey(),
+            system_program: system_program::ID,
+        },
+        zai::instruction::Invite {},
+    )
+}
+
+fn kick(guild: Pubkey, (owner, officer): &(Keypair, Pubkey), player: Pubkey) -> Instruction {
+    instruction(zai::accounts::Kick { guild, officer_account: *officer, player_account: player, signer: owner.pubkey() }, zai::instruction::Kick {})
+}
+
+fn set_guild_officer(guild: Pubkey, (owner, leader): &(Keypair, Pubkey), player: Pubkey, promote: bool) -> Instruction {
+    instruction(
+        zai::accounts::ManageGuildRoles { guild, leader_account: *leader, player_account: player, signer: owner.pubkey() },
+        zai::instruction::SetGuildOfficer { promote },
+    )
+}
+
+fn transfer_leadership(guild: Pubkey, (owner, leader): &(Keypair, Pubkey), player: Pubkey) -> Instruction {
+    instruction(
+        zai::accounts::ManageGuildRoles { guild, leader_account: *leader, player_account: player, signer: owner.pubkey() },
+        zai::instruction::TransferLeadership {},
+    )
+}
+
+fn leave_guild(guild: Pubkey, (owner, player): &(Keypair, Pubkey)) -> Instruction {
+    instruction(zai::accounts::LeaveGuild { guild, player_account: *player, signer: owner.pubkey() }, zai::instruction::LeaveGuild {})
+}
+
+#[tokio::test]
+async fn open_guilds_join_directly_and_invite_only_guilds_need_an_invite() {
+    let mut program_test = program_test();
+    let (open_leader, invite_leader) = (add_wallet_player(&mut program_test), add_wallet_player(&mut program_test));
+    let (joiner, invitee) = (add_wallet_player(&mut program_test), add_wallet_player(&mut program_test));
+    let mut context = program_test.start_with_context().await;
+
+    let open_guild = create_guild(&mut context, "open_guild", GuildJoinPolicy::Open, &open_leader).await;
+    process_instruction(&mut context, join_guild(open_guild, &joiner), &[&joiner.0]).await.unwrap();
+    let joined: Player = fetch(&mut context, joiner.1).await.unwrap();
+    assert_eq!(joined.guild, open_guild);
+    let guild: Guild = fetch(&mut context, open_guild).await.unwrap();
+    assert_eq!(guild.member_count, 2);
+    assert!(context.banks_client.get_account(guild_invite_address(open_guild, joiner.1)).await.unwrap().is_none());
+
+    let invite_guild = create_guild(&mut context, "invite_guild", GuildJoinPolicy::InviteOnly, &invite_leader).await;
+    let result = process_instruction(&mut context, join_guild(invite_guild, &invitee), &[&invitee.0]).await;
+    assert_zai_error(result, ZaiError::GuildInviteRequired);
+    // Members of other guilds cannot be invited
+    let result = process_instruction(&mut context, invite(invite_guild, &invite_leader, joiner.1), &[&invite_leader.0]).await;
+    assert_zai_error(result, ZaiError::AlreadyInGuild);
+
+    process_instruction(&mut context, invite(invite_guild, &invite_leader, invitee.1), &[&invite_leader.0]).await.unwrap();
+    let invited: Player = fetch(&mut context, invitee.1).await.unwrap();
+    assert_eq!(invited.guild, Pubkey::default());
+    process_instruction(&mut context, join_guild(invite_guild, &invitee), &[&invitee.0]).await.unwrap();
+    let invited: Player = fetch(&mut context, invitee.1).await.unwrap();
+    assert_eq!(invited.guild, invite_guild);
+    assert!(context.banks_client.get_account(guild_invite_address(invite_guild, invitee.1)).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn applications_wait_for_an_officer_invite() {
+    let mut program_test = program_test();
+    let (leader, applicant, outsider) = (add_wallet_player(&mut program_test), add_wallet_player(&mut program_test), add_wallet_player(&mut program_test));
+    let mut context = program_test.start_with_context().await;
+
+    let guild = create_guild(&mut context, "applications", GuildJoinPolicy::Application, &leader).await;
+    process_instruction(&mut context, join_guild(guild, &applicant), &[&applicant.0]).await.unwrap();
+    let applied: GuildInvite = fetch(&mut context, guild_invite_address(guild, applicant.1)).await.unwrap();
+    assert!(applied.applied && !applied.invited);
+    let waiting: Player = fetch(&mut context, applicant.1).await.unwrap();
+    assert_eq!(waiting.guild, Pubkey::default());
+
+    let result = process_instruction(&mut context, invite(guild, &outsider, applicant.1), &[&outsider.0]).await;
+    assert_zai_error(result, ZaiError::NotGuildOfficer);
+
+    process_instruction(&mut context, invite(guild, &leader, applicant.1), &[&leader.0]).await.unwrap();
+    let accepted: Player = fetch(&mut context, applicant.1).await.unwrap();
+    assert_eq!(accepted.guild, guild);
+    let members: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!(members.member_count, 2);
+    assert!(context.banks_client.get_account(guild_invite_address(guild, applicant.1)).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn officers_kick_members_and_the_leader_manages_roles() {
+    let mut program_test = program_test();
+    let leader = add_wallet_player(&mut program_test);
+    let members = [add_wallet_player(&mut program_test), add_wallet_player(&mut program_test), add_wallet_player(&mut program_test)];
+    let mut context = program_test.start_with_context().await;
+
+    let guild = create_guild(&mut context, "roles", GuildJoinPolicy::Open, &leader).await;
+    for member in &members {
+        process_instruction(&mut context, join_guild(guild, member), &[&member.0]).await.unwrap();
+    }
+    let [officer, member, other_officer] = &members;
+
+    // Regular members can neither kick nor manage roles
+    let result = process_instruction(&mut context, kick(guild, officer, member.1), &[&officer.0]).await;
+    assert_zai_error(result, ZaiError::NotGuildOfficer);
+    let result = process_instruction(&mut context, set_guild_officer(guild, officer, officer.1, true), &[&officer.0]).await;
+    assert_zai_error(result, ZaiError::NotGuildOfficer);
+
+    process_instruction(&mut context, set_guild_officer(guild, &leader, officer.1, true), &[&leader.0]).await.unwrap();
+    process_instruction(&mut context, set_guild_officer(guild, &leader, other_officer.1, true), &[&leader.0]).await.unwrap();
+    let roles: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!(roles.officers, [officer.1, other_officer.1]);
+
+    // Officers kick members, but not the leader or other officers
+    process_instruction(&mut context, kick(guild, officer, member.1), &[&officer.0]).await.unwrap();
+    let kicked: Player = fetch(&mut context, member.1).await.unwrap();
+    assert_eq!(kicked.guild, Pubkey::default());
+    for target in [leader.1, other_officer.1] {
+        let result = process_instruction(&mut context, kick(guild, officer, target), &[&officer.0]).await;
+        assert_zai_error(result, ZaiError::NotGuildOfficer);
+    }
+
+    process_instruction(&mut context, set_guild_officer(guild, &leader, other_officer.1, false), &[&leader.0]).await.unwrap();
+    process_instruction(&mut context, transfer_leadership(guild, &leader, officer.1), &[&leader.0]).await.unwrap();
+    let transferred: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!((transferred.leader, transferred.officers.as_slice(), transferred.member_count), (officer.1, &[][..], 3));
+
+    // The previous leader is a regular member and can leave, the new one has to hand over first
+    let result = process_instruction(&mut context, set_guild_officer(guild, &leader, other_officer.1, true), &[&leader.0]).await;
+    assert_zai_error(result, ZaiError::NotGuildOfficer);
+    let result = process_instruction(&mut context, leave_guild(guild, officer), &[&officer.0]).await;
+    assert_zai_error(result, ZaiError::GuildLeaderCannotLeave);
+    process_instruction(&mut context, leave_guild(guild, &leader), &[&leader.0]).await.unwrap();
+    let remaining: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!(remaining.member_count, 2);
+}
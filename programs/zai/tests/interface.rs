@@ -0,0 +1,45 @@
+// zai_interface hand-copies these program constants, keep them in sync
+use zai::{Guild, GuildInvite, MatchRecord, PauseFlag, Player, ReferralConfig, Session, Username, WalletAccount, XpVoucher};
+
+#[test]
+fn required_xp_table_matches() {
+    assert_eq!(zai::REQUIRED_XP, zai_interface::REQUIRED_XP);
+    for level in [0, 1, 49, u8::MAX] {
+        assert_eq!(Player::required_xp(level), zai_interface::Player::required_xp(level));
+    }
+}
+
+#[test]
+fn seeds_match() {
+    assert_eq!(Player::SEED, zai_interface::PLAYER_SEED);
+    assert_eq!(WalletAccount::SEED, zai_interface::WALLET_ACCOUNT_SEED);
+    assert_eq!(Username::SEED, zai_interface::USERNAME_SEED);
+    assert_eq!(Session::SEED, zai_interface::SESSION_SEED);
+    assert_eq!(ReferralConfig::SEED, zai_interface::REFERRAL_CONFIG_SEED);
+    assert_eq!(Guild::SEED, zai_interface::GUILD_SEED);
+    assert_eq!(GuildInvite::SEED, zai_interface::GUILD_INVITE_SEED);
+    assert_eq!(MatchRecord::SEED, zai_interface::MATCH_RECORD_SEED);
+    assert_eq!(XpVoucher::MESSAGE_PREFIX, zai_interface::XP_VOUCHER_MESSAGE_PREFIX);
+}
+
+#[test]
+fn limits_match() {
+    assert_eq!(Player::LEN, zai_interface::PLAYER_ACCOUNT_LEN);
+    assert_eq!(Player::CURRENT_VERSION, zai_interface::PLAYER_ACCOUNT_VERSION);
+    assert_eq!((Username::MIN_LEN, Username::MAX_LEN), (zai_interface::USERNAME_MIN_LEN, zai_interface::USERNAME_MAX_LEN));
+    assert_eq!(Session::MAX_DURATION_SECONDS, zai_interface::SESSION_MAX_DURATION_SECONDS);
+    assert_eq!(MatchRecord::MAX_PLAYERS, zai_interface::MATCH_MAX_PLAYERS);
+    assert_eq!(Guild::LEVEL_XP, zai_interface::GUILD_LEVEL_XP);
+    for level in [0, 1, 2, Guild::MAX_LEVEL] {
+        assert_eq!(Guild::required_xp(level), zai_interface::guild_required_xp(level));
+    }
+}
+
+#[test]
+fn pause_flags_match() {
+    let program = [PauseFlag::Create, PauseFlag::ClassChange, PauseFlag::Xp, PauseFlag::LevelUp, PauseFlag::Equip];
+    assert_eq!(program.len(), zai_interface::PauseFlag::ALL.len());
+    for (program, interface) in program.into_iter().zip(zai_interface::PauseFlag::ALL) {
+        assert_eq!(program.mask(), interface.mask(), "{interface:?}");
+    }
+}
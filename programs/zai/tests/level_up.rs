@@ -0,0 +1,215 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::{hash::hashv, system_program, sysvar::slot_hashes};
+use common::*;
+use solana_sdk::{account::Account, instruction::Instruction, signature::{Keypair, Signer}};
+use mock_oracle::RandomnessAccount;
+use zai::{find_slot_hash, LevelUpRequest, OracleConfig, OracleFulfiller, Player, RandomnessCommitment, RandomnessLookup, RandomnessSource, ZaiError};
+
+#[tokio::test]
+async fn request_then_settle_level_up_with_slot_hashes() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    add_game_config(&mut program_test, &game_config(Pubkey::new_unique()));
+    let player = add_player(&mut program_test, owner.pubkey(), 1, Player::required_xp(1));
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let mut context = program_test.start_with_context().await;
+
+    let secret = [7u8; 32];
+    let level_up_request = Pubkey::find_program_address(&[LevelUpRequest::SEED, player.as_ref()], &zai::ID).0;
+    let request = request_level_up(player, slot_hashes::ID, owner.pubkey(), hashv(&[&secret]).to_bytes());
+    process_instruction(&mut context, request, &[&owner]).await.unwrap();
+
+    let leveled: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!((leveled.level, leveled.xp, leveled.pending_chest_rolls), (2, 0, 0));
+    let committed: LevelUpRequest = fetch(&mut context, level_up_request).await.unwrap();
+    assert_eq!(committed.rolls, 1);
+
+    let settle = || instruction(
+        zai::accounts::SettleLevelUp {
+            player_account: player,
+            game_config: game_config_address().0,
+            level_up_request,
+            randomness_account: slot_hashes::ID,
+            session: None,
+            signer: owner.pubkey(),
+        },
+        zai::instruction::SettleLevelUp { secret },
+    );
+
+    // The target slot has not been produced yet
+    let result = process_instruction(&mut context, settle(), &[&owner]).await;
+    assert_zai_error(result, ZaiError::RevealTooEarly);
+
+    // Warping past the target records a produced slot at or after it in SlotHashes
+    let target_slot = committed.randomness.target_slot;
+    context.warp_to_slot(target_slot + 1).unwrap();
+    let slot_hashes_data = context.banks_client.get_account(slot_hashes::ID).await.unwrap().unwrap().data;
+    let RandomnessLookup::Found(randomness) = find_slot_hash(&slot_hashes_data, target_slot).unwrap() else {
+        panic!("target slot hash missing after warp");
+    };
+    let expected_chests = u16::from(RandomnessCommitment::roll(&secret, &randomness, &player, 0).is_multiple_of(LevelUpRequest::CHEST_ODDS));
+
+    process_instruction(&mut context, settle(), &[&owner]).await.unwrap();
+
+    let settled: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!(settled.chests, expected_chests);
+    assert!(context.banks_client.get_account(level_up_request).await.unwrap().is_none());
+}
+
+// Config using the mock oracle with the given trusted fulfiller
+fn oracle_game_config(fulfiller: Pubkey) -> zai::GameConfig {
+    zai::GameConfig {
+        randomness_source: RandomnessSource::Oracle,
+        oracle: OracleConfig {
+            program: mock_oracle::ID,
+            discriminator: RandomnessAccount::DISCRIMINATOR,
+            fulfilled_offset: RandomnessAccount::FULFILLED_OFFSET,
+            randomness_offset: RandomnessAccount::RANDOMNESS_OFFSET,
+        },
+        oracle_fulfiller: OracleFulfiller { key: fulfiller, offset: RandomnessAccount::FULFILLER_OFFSET },
+        ..game_config(Pubkey::new_unique())
+    }
+}
+
+fn request_randomness(randomness_account: Pubkey, requester: Pubkey, fulfiller: Pubkey) -> Instruction {
+    program_instruction(
+        mock_oracle::ID,
+        mock_oracle::accounts::RequestRandomness { randomness_account, requester, system_program: system_program::ID },
+        mock_oracle::instruction::RequestRandomness { fulfiller },
+    )
+}
+
+fn request_level_up(player: Pubkey, randomness_account: Pubkey, signer: Pubkey, commitment: [u8; 32]) -> Instruction {
+    instruction(
+        zai::accounts::RequestLevelUp {
+            player_account: player,
+            game_config: game_config_address().0,
+            level_up_request: Pubkey::find_program_address(&[LevelUpRequest::SEED, player.as_ref()], &zai::ID).0,
+            randomness_account,
+            session: None,
+            signer,
+            system_program: system_program::ID,
+        },
+        zai::instruction::RequestLevelUp { commitment },
+    )
+}
+
+#[tokio::test]
+async fn request_fulfil_settle_level_up_with_oracle() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    let fulfiller = Keypair::new();
+    add_game_config(&mut program_test, &oracle_game_config(fulfiller.pubkey()));
+    let player = add_player(&mut program_test, owner.pubkey(), 1, Player::required_xp(1));
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let mut context = program_test.start_with_context().await;
+
+    let randomness_account = Keypair::new();
+    let request = request_randomness(randomness_account.pubkey(), owner.pubkey(), fulfiller.pubkey());
+    process_instruction(&mut context, request, &[&owner, &randomness_account]).await.unwrap();
+
+    let secret = [9u8; 32];
+    let request = request_level_up(player, randomness_account.pubkey(), owner.pubkey(), hashv(&[&secret]).to_bytes());
+    process_instruction(&mut context, request, &[&owner]).await.unwrap();
+
+    let level_up_request = Pubkey::find_program_address(&[LevelUpRequest::SEED, player.as_ref()], &zai::ID).0;
+    let settle = || instruction(
+        zai::accounts::SettleLevelUp {
+            player_account: player,
+            game_config: game_config_address().0,
+            level_up_request,
+            randomness_account: randomness_account.pubkey(),
+            session: None,
+            signer: owner.pubkey(),
+        },
+        zai::instruction::SettleLevelUp { secret },
+    );
+
+    // Not fulfilled yet
+    let result = process_instruction(&mut context, settle(), &[&owner]).await;
+    assert_zai_error(result, ZaiError::RevealTooEarly);
+
+    let randomness = [3u8; 32];
+    let fulfil = program_instruction(
+        mock_oracle::ID,
+        mock_oracle::accounts::FulfilRandomness { randomness_account: randomness_account.pubkey(), fulfiller: fulfiller.pubkey() },
+        mock_oracle::instruction::FulfilRandomness { randomness },
+    );
+    process_instruction(&mut context, fulfil, &[&fulfiller]).await.unwrap();
+
+    let expected_chests = u16::from(RandomnessCommitment::roll(&secret, &randomness, &player, 0).is_multiple_of(LevelUpRequest::CHEST_ODDS));
+    process_instruction(&mut context, settle(), &[&owner]).await.unwrap();
+
+    let settled: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!((settled.level, settled.chests), (2, expected_chests));
+    assert!(context.banks_client.get_account(level_up_request).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn unfulfilled_oracle_request_is_forfeited_after_expiry() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    let fulfiller = Keypair::new();
+    add_game_config(&mut program_test, &oracle_game_config(fulfiller.pubkey()));
+    let player = add_player(&mut program_test, owner.pubkey(), 1, Player::required_xp(1));
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let mut context = program_test.start_with_context().await;
+
+    let randomness_account = Keypair::new();
+    let request = request_randomness(randomness_account.pubkey(), owner.pubkey(), fulfiller.pubkey());
+    process_instruction(&mut context, request, &[&owner, &randomness_account]).await.unwrap();
+
+    let secret = [4u8; 32];
+    let request = request_level_up(player, randomness_account.pubkey(), owner.pubkey(), hashv(&[&secret]).to_bytes());
+    process_instruction(&mut context, request, &[&owner]).await.unwrap();
+
+    let level_up_request = Pubkey::find_program_address(&[LevelUpRequest::SEED, player.as_ref()], &zai::ID).0;
+    let committed: LevelUpRequest = fetch(&mut context, level_up_request).await.unwrap();
+    let settle = || instruction(
+        zai::accounts::SettleLevelUp {
+            player_account: player,
+            game_config: game_config_address().0,
+            level_up_request,
+            randomness_account: randomness_account.pubkey(),
+            session: None,
+            signer: owner.pubkey(),
+        },
+        zai::instruction::SettleLevelUp { secret },
+    );
+
+    // The oracle still has time up to the deadline
+    let deadline = committed.randomness.target_slot + RandomnessCommitment::ORACLE_EXPIRY_SLOTS;
+    context.warp_to_slot(deadline).unwrap();
+    let result = process_instruction(&mut context, settle(), &[&owner]).await;
+    assert_zai_error(result, ZaiError::RevealTooEarly);
+
+    // Past it the rolls are forfeited and the request is closed so the player can request again
+    context.warp_to_slot(deadline + 1).unwrap();
+    process_instruction(&mut context, settle(), &[&owner]).await.unwrap();
+
+    let settled: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!((settled.level, settled.chests), (2, 0));
+    assert!(context.banks_client.get_account(level_up_request).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn oracle_account_naming_another_fulfiller_is_rejected() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    add_game_config(&mut program_test, &oracle_game_config(Pubkey::new_unique()));
+    let player = add_player(&mut program_test, owner.pubkey(), 1, Player::required_xp(1));
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let mut context = program_test.start_with_context().await;
+
+    // The player names themselves as fulfiller so they could pick the randomness
+    let randomness_account = Keypair::new();
+    let request = request_randomness(randomness_account.pubkey(), owner.pubkey(), owner.pubkey());
+    process_instruction(&mut context, request, &[&owner, &randomness_account]).await.unwrap();
+
+    let request = request_level_up(player, randomness_account.pubkey(), owner.pubkey(), [1; 32]);
+    let result = process_instruction(&mut context, request, &[&owner]).await;
+    assert_zai_error(result, ZaiError::InvalidRandomnessAccount);
+}
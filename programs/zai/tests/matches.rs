@@ -0,0 +1,48 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_program;
+use common::*;
+use solana_sdk::{account::Account, instruction::{AccountMeta, Instruction}, signature::{Keypair, Signer}};
+use zai::{GameConfig, Guild, MatchRecord, MatchResult, Player, ZaiError};
+
+fn settle_match(authority_registry: Pubkey, admin: Pubkey, match_id: u64, results: &[(Pubkey, i64)], guilds: &[Pubkey]) -> Instruction {
+    let mut instruction = instruction(
+        zai::accounts::SettleMatch {
+            match_record: Pubkey::find_program_address(&[MatchRecord::SEED, &match_id.to_le_bytes()], &zai::ID).0,
+            game_config: game_config_address().0,
+            authority_registry,
+            admin,
+            system_program: system_program::ID,
+        },
+        zai::instruction::SettleMatch { match_id, results: results.iter().map(|(_, xp_change)| MatchResult { xp_change: *xp_change, chests: 0 }).collect() },
+    );
+    instruction.accounts.extend(results.iter().map(|(player, _)| AccountMeta::new(*player, false)));
+    instruction.accounts.extend(guilds.iter().map(|guild| AccountMeta::new(*guild, false)));
+    instruction
+}
+
+#[tokio::test]
+async fn settle_match_shares_xp_with_guilds() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    add_game_config(&mut program_test, &GameConfig { guild_xp_share_percent: 10, ..game_config(server_key.pubkey()) });
+    let authority_registry = add_authority_registry(&mut program_test);
+    program_test.add_account(server_key.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let guild = add_guild(&mut program_test, 2);
+    let (first, second) = (add_guild_member(&mut program_test, guild), add_guild_member(&mut program_test, guild));
+    let loner = add_player(&mut program_test, Pubkey::new_unique(), 1, 0);
+    let mut context = program_test.start_with_context().await;
+
+    // Missing guild accounts fail the settlement rather than skipping the share
+    let results = [(first, 1_000), (second, 500), (loner, 2_000)];
+    let result = process_instruction(&mut context, settle_match(authority_registry, server_key.pubkey(), 1, &results, &[]), &[&server_key]).await;
+    assert_zai_error(result, ZaiError::GuildAccountRequired);
+
+    process_instruction(&mut context, settle_match(authority_registry, server_key.pubkey(), 1, &results, &[guild]), &[&server_key]).await.unwrap();
+
+    let shared: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!(shared.xp, 150);
+    let player: Player = fetch(&mut context, first).await.unwrap();
+    assert_eq!(player.xp, 1_000);
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::system_program, AccountSerialize};
+use common::*;
+use solana_sdk::{account::Account, instruction::{Instruction, InstructionError}, rent::Rent, signature::Signer, transaction::TransactionError};
+use zai::{Player, Username, ZaiError};
+
+// Account of the given size owned by the program, holding the start of the serialized value
+fn add_truncated<T: AccountSerialize>(program_test: &mut solana_program_test::ProgramTest, value: &T, len: usize) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    data.resize(len, 0);
+    program_test.add_account(address, Account { lamports: Rent::default().minimum_balance(len), data, owner: zai::ID, executable: false, rent_epoch: 0 });
+    address
+}
+
+fn migrate_player(player: Pubkey, payer: Pubkey) -> Instruction {
+    instruction(zai::accounts::MigratePlayer { player_account: player, payer, system_program: system_program::ID }, zai::instruction::MigratePlayer {})
+}
+
+#[tokio::test]
+async fn migrate_player_grows_legacy_account() {
+    let mut program_test = program_test();
+    let owner = Pubkey::new_unique();
+    // The legacy layout ends with the version byte, so the current one cut to 64 bytes matches it
+    let legacy = Player { player_id: owner, level: 7, xp: 123, chests: 2, active_class: 1, joined: 1_700_000_000, ..Default::default() };
+    let player = add_truncated(&mut program_test, &legacy, Player::LEGACY_LEN);
+    let mut context = program_test.start_with_context().await;
+
+    let before = context.banks_client.get_account(player).await.unwrap().unwrap();
+    assert_eq!(before.data[Player::VERSION_OFFSET], 0);
+
+    let payer = context.payer.pubkey();
+    process_instruction(&mut context, migrate_player(player, payer), &[]).await.unwrap();
+
+    let account = context.banks_client.get_account(player).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), Player::LEN);
+    assert!(account.lamports >= Rent::default().minimum_balance(Player::LEN));
+    assert_eq!(account.data[Player::VERSION_OFFSET], Player::CURRENT_VERSION);
+    let migrated: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!((migrated.player_id, migrated.level, migrated.xp, migrated.chests, migrated.joined), (owner, 7, 123, 2, 1_700_000_000));
+    assert_eq!((migrated.guild, migrated.open_sessions), (Pubkey::default(), 0));
+
+    let result = process_instruction(&mut context, migrate_player(player, payer), &[]).await;
+    assert_zai_error(result, ZaiError::PlayerAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn migrate_player_rejects_other_accounts() {
+    let mut program_test = program_test();
+    let username = Username { player: Pubkey::new_unique(), name: [b'a'; Username::MAX_LEN], bump: 255 };
+    let other = add_truncated(&mut program_test, &username, Username::LEN);
+    let mut context = program_test.start_with_context().await;
+
+    let payer = context.payer.pubkey();
+    let result = process_instruction(&mut context, migrate_player(other, payer), &[]).await;
+    let mismatch = anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch as u32;
+    assert_eq!(result.unwrap_err().unwrap(), TransactionError::InstructionError(0, InstructionError::Custom(mismatch)));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_program;
+use common::*;
+use solana_sdk::{account::Account, instruction::Instruction, signature::{Keypair, Signer}};
+use zai::{Player, ReferralConfig, ReferralMilestone};
+
+fn referral_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[ReferralConfig::SEED], &zai::ID).0
+}
+
+fn add_referral_config(program_test: &mut solana_program_test::ProgramTest) {
+    let milestones = vec![ReferralMilestone { level: 5, xp: 100, chests: 1 }];
+    let bump = Pubkey::find_program_address(&[ReferralConfig::SEED], &zai::ID).1;
+    add_account(program_test, referral_config_address(), &ReferralConfig { bump, max_rewarded_referrals: 10, milestones }, ReferralConfig::LEN);
+}
+
+// Referrer and a referee at the given level, returned as (referrer, referee)
+fn add_referral(program_test: &mut solana_program_test::ProgramTest, referee_owner: Pubkey, referee_level: u8, referee_xp: i64) -> (Pubkey, Pubkey) {
+    let referrer = add_player(program_test, Pubkey::new_unique(), 1, 0);
+    let referee = player_address(&referee_owner);
+    let player = Player { player_id: referee_owner, level: referee_level, xp: referee_xp, version: Player::CURRENT_VERSION, referred_by: referrer, ..Default::default() };
+    add_account(program_test, referee, &player, Player::LEN);
+    (referrer, referee)
+}
+
+fn claim_referral_reward(referee: Pubkey, referrer: Pubkey, signer: Pubkey) -> Instruction {
+    instruction(
+        zai::accounts::ClaimReferralReward {
+            player_account: referee,
+            game_config: game_config_address().0,
+            referral_config: referral_config_address(),
+            referrer,
+            signer,
+        },
+        zai::instruction::ClaimReferralReward {},
+    )
+}
+
+#[tokio::test]
+async fn claim_referral_reward_pays_each_milestone_once() {
+    let mut program_test = program_test();
+    add_game_config(&mut program_test, &game_config(Pubkey::new_unique()));
+    add_referral_config(&mut program_test);
+    // Reached level 5 through XP grants, which level up without the referral accounts
+    let (referrer, referee) = add_referral(&mut program_test, Pubkey::new_unique(), 5, 0);
+    let mut context = program_test.start_with_context().await;
+    let cranker = Keypair::new();
+
+    process_instruction(&mut context, claim_referral_reward(referee, referrer, cranker.pubkey()), &[&cranker]).await.unwrap();
+    let rewarded: Player = fetch(&mut context, referrer).await.unwrap();
+    assert_eq!((rewarded.xp, rewarded.chests, rewarded.rewarded_referrals), (100, 1, 1));
+
+    process_instruction(&mut context, claim_referral_reward(referee, referrer, cranker.pubkey()), &[&cranker]).await.unwrap();
+    let rewarded: Player = fetch(&mut context, referrer).await.unwrap();
+    assert_eq!((rewarded.xp, rewarded.chests), (100, 1));
+}
+
+#[tokio::test]
+async fn level_up_all_without_referral_config() {
+    let mut program_test = program_test();
+    add_game_config(&mut program_test, &game_config(Pubkey::new_unique()));
+    let owner = Keypair::new();
+    let (referrer, referee) = add_referral(&mut program_test, owner.pubkey(), 1, Player::required_xp(1));
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let mut context = program_test.start_with_context().await;
+
+    let level_up = instruction(
+        zai::accounts::IncreasePlayerLevel {
+            player_account: referee,
+            game_config: game_config_address().0,
+            referral_config: None,
+            referrer: Some(referrer),
+            session: None,
+            signer: owner.pubkey(),
+        },
+        zai::instruction::LevelUpAll { slot: 0, max_levels: 1 },
+    );
+    process_instruction(&mut context, level_up, &[&owner]).await.unwrap();
+
+    let leveled: Player = fetch(&mut context, referee).await.unwrap();
+    assert_eq!(leveled.level, 2);
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::solana_program::system_program;
+use common::*;
+use solana_sdk::{account::Account, instruction::Instruction, signature::{Keypair, Signer}};
+use zai::{Player, Session, SessionScope, ZaiError};
+
+fn session_address(player: Pubkey, session_key: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Session::SEED, player.as_ref(), session_key.as_ref()], &zai::ID).0
+}
+
+fn create_session(player: Pubkey, owner: Pubkey, session_key: Pubkey, expires_at: i64, scope: u8) -> Instruction {
+    instruction(
+        zai::accounts::CreateSession { player_account: player, session: session_address(player, session_key), session_key, signer: owner, system_program: system_program::ID },
+        zai::instruction::CreateSession { expires_at, scope, top_up: 0 },
+    )
+}
+
+fn increase_player_level(player: Pubkey, session: Option<Pubkey>, signer: Pubkey) -> Instruction {
+    instruction(
+        zai::accounts::IncreasePlayerLevel { player_account: player, game_config: game_config_address().0, referral_config: None, referrer: None, session, signer },
+        zai::instruction::IncreasePlayerLevel { slot: 0 },
+    )
+}
+
+#[tokio::test]
+async fn session_key_signs_within_scope_until_expiry() {
+    let mut program_test = program_test();
+    let owner = Keypair::new();
+    add_game_config(&mut program_test, &game_config(Pubkey::new_unique()));
+    let player = add_player(&mut program_test, owner.pubkey(), 1, Player::required_xp(1) + Player::required_xp(2) + Player::required_xp(3));
+    program_test.add_account(owner.pubkey(), Account::new(1_000_000_000, 0, &system_program::ID));
+    let mut context = program_test.start_with_context().await;
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+
+    // Expiry must be in the future and within the maximum duration, with a non-empty scope
+    let (leveling_key, equip_key) = (Keypair::new(), Keypair::new());
+    let invalid = [
+        (clock.unix_timestamp, SessionScope::LevelUp.mask()),
+        (clock.unix_timestamp + Session::MAX_DURATION_SECONDS + 1, SessionScope::LevelUp.mask()),
+        (clock.unix_timestamp + 60, 0),
+    ];
+    for (expires_at, scope) in invalid {
+        let result = process_instruction(&mut context, create_session(player, owner.pubkey(), leveling_key.pubkey(), expires_at, scope), &[&owner]).await;
+        assert_zai_error(result, ZaiError::InvalidSession);
+    }
+
+    let expires_at = clock.unix_timestamp + 60 * 60;
+    process_instruction(&mut context, create_session(player, owner.pubkey(), leveling_key.pubkey(), expires_at, SessionScope::LevelUp.mask()), &[&owner]).await.unwrap();
+    process_instruction(&mut context, create_session(player, owner.pubkey(), equip_key.pubkey(), expires_at, SessionScope::Equip.mask()), &[&owner]).await.unwrap();
+    let created: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!(created.open_sessions, 2);
+
+    // Any other key needs a session
+    let stranger = Keypair::new();
+    let result = process_instruction(&mut context, increase_player_level(player, None, stranger.pubkey()), &[&stranger]).await;
+    assert_zai_error(result, ZaiError::Unauthorized);
+
+    let leveling_session = session_address(player, leveling_key.pubkey());
+    process_instruction(&mut context, increase_player_level(player, Some(leveling_session), leveling_key.pubkey()), &[&leveling_key]).await.unwrap();
+
+    let equip_session = session_address(player, equip_key.pubkey());
+    let result = process_instruction(&mut context, increase_player_level(player, Some(equip_session), equip_key.pubkey()), &[&equip_key]).await;
+    assert_zai_error(result, ZaiError::SessionScopeMismatch);
+
+    // A session only signs for its own key
+    let result = process_instruction(&mut context, increase_player_level(player, Some(leveling_session), equip_key.pubkey()), &[&equip_key]).await;
+    assert!(result.is_err());
+
+    context.set_sysvar(&Clock { unix_timestamp: expires_at, ..clock });
+    let result = process_instruction(&mut context, increase_player_level(player, Some(leveling_session), leveling_key.pubkey()), &[&leveling_key]).await;
+    assert_zai_error(result, ZaiError::SessionExpired);
+
+    // The owner still signs directly, and can revoke expired sessions
+    process_instruction(&mut context, increase_player_level(player, None, owner.pubkey()), &[&owner]).await.unwrap();
+    let revoke = instruction(zai::accounts::RevokeSession { player_account: player, session: leveling_session, signer: owner.pubkey() }, zai::instruction::RevokeSession {});
+    process_instruction(&mut context, revoke, &[&owner]).await.unwrap();
+
+    let leveled: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!((leveled.level, leveled.open_sessions), (3, 1));
+    assert!(context.banks_client.get_account(leveling_session).await.unwrap().is_none());
+}
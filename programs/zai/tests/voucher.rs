@@ -0,0 +1,104 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use solana_sdk::{signature::{Keypair, Signer}, sysvar};
+use zai::{GameConfig, Guild, Player, ZaiError};
+use zai_interface::{RedeemXpVoucherKeys, SignedXpVoucher, XpVoucher};
+
+fn redeem_keys(player: Pubkey, authority_registry: Pubkey, guild: Option<Pubkey>, owner: Pubkey) -> RedeemXpVoucherKeys {
+    RedeemXpVoucherKeys {
+        player_account: player,
+        game_config: game_config_address().0,
+        authority_registry,
+        guild: guild.unwrap_or(zai::ID),
+        instructions: sysvar::instructions::ID,
+        signer: owner,
+    }
+}
+
+fn signed_voucher(program_id: Pubkey, player: Pubkey, nonce: u64, signer: &Keypair) -> SignedXpVoucher {
+    let voucher = XpVoucher { player, xp: 25, nonce, expires_at: i64::MAX };
+    SignedXpVoucher::sign(program_id, voucher, signer.pubkey(), |message| signer.sign_message(message).into()).unwrap()
+}
+
+#[tokio::test]
+async fn voucher_signed_for_the_program_is_redeemed_once() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    add_game_config(&mut program_test, &game_config(server_key.pubkey()));
+    let authority_registry = add_authority_registry(&mut program_test);
+    let owner = Keypair::new();
+    let player = add_player(&mut program_test, owner.pubkey(), 1, 0);
+    let mut context = program_test.start_with_context().await;
+
+    let voucher = signed_voucher(zai::ID, player, 3, &server_key);
+    let redeem = voucher.redeem_ixs(zai::ID, redeem_keys(player, authority_registry, None, owner.pubkey())).unwrap();
+    process_instructions(&mut context, &redeem, &[&owner]).await.unwrap();
+    let redeemed: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!(redeemed.xp, 25);
+
+    let result = process_instructions(&mut context, &redeem, &[&owner]).await;
+    assert_zai_error_at(result, 1, ZaiError::VoucherAlreadyRedeemed);
+}
+
+#[tokio::test]
+async fn verify_signature_rejects_other_messages_and_missing_verification() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    add_game_config(&mut program_test, &game_config(server_key.pubkey()));
+    let authority_registry = add_authority_registry(&mut program_test);
+    let owner = Keypair::new();
+    let player = add_player(&mut program_test, owner.pubkey(), 1, 0);
+    let mut context = program_test.start_with_context().await;
+    let keys = redeem_keys(player, authority_registry, None, owner.pubkey());
+
+    // Signed for another program id, e.g. the interface's placeholder id
+    let voucher = signed_voucher(zai_interface::ID, player, 0, &server_key);
+    let [ed25519, _] = voucher.redeem_ixs(zai_interface::ID, keys).unwrap();
+    let [_, redeem] = voucher.redeem_ixs(zai::ID, keys).unwrap();
+    let result = process_instructions(&mut context, &[ed25519, redeem.clone()], &[&owner]).await;
+    assert_zai_error_at(result, 1, ZaiError::InvalidVoucher);
+
+    // No Ed25519 instruction before the redeem
+    assert_zai_error(process_instruction(&mut context, redeem, &[&owner]).await, ZaiError::InvalidVoucher);
+
+    // Verification of a different voucher from the same key
+    let [ed25519, _] = signed_voucher(zai::ID, player, 1, &server_key).redeem_ixs(zai::ID, keys).unwrap();
+    let [_, redeem] = signed_voucher(zai::ID, player, 0, &server_key).redeem_ixs(zai::ID, keys).unwrap();
+    let result = process_instructions(&mut context, &[ed25519, redeem], &[&owner]).await;
+    assert_zai_error_at(result, 1, ZaiError::InvalidVoucher);
+
+    // Valid signature from a key without the XP granter role
+    let voucher = signed_voucher(zai::ID, player, 0, &Keypair::new());
+    let result = process_instructions(&mut context, &voucher.redeem_ixs(zai::ID, keys).unwrap(), &[&owner]).await;
+    assert_zai_error_at(result, 1, ZaiError::MissingRole);
+
+    let untouched: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!(untouched.xp, 0);
+}
+
+#[tokio::test]
+async fn voucher_xp_is_shared_with_the_guild() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    add_game_config(&mut program_test, &GameConfig { guild_xp_share_percent: 20, ..game_config(server_key.pubkey()) });
+    let authority_registry = add_authority_registry(&mut program_test);
+    let guild = add_guild(&mut program_test, 1);
+    let owner = Keypair::new();
+    let player = player_address(&owner.pubkey());
+    add_account(&mut program_test, player, &Player { player_id: owner.pubkey(), level: 1, version: Player::CURRENT_VERSION, guild, ..Default::default() }, Player::LEN);
+    let mut context = program_test.start_with_context().await;
+
+    let voucher = signed_voucher(zai::ID, player, 0, &server_key);
+    let result = process_instructions(&mut context, &voucher.redeem_ixs(zai::ID, redeem_keys(player, authority_registry, None, owner.pubkey())).unwrap(), &[&owner]).await;
+    assert_zai_error_at(result, 1, ZaiError::GuildAccountRequired);
+
+    let redeem = voucher.redeem_ixs(zai::ID, redeem_keys(player, authority_registry, Some(guild), owner.pubkey())).unwrap();
+    process_instructions(&mut context, &redeem, &[&owner]).await.unwrap();
+
+    let redeemed: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!(redeemed.xp, 25);
+    let shared: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!(shared.xp, 5);
+}
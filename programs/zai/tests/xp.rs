@@ -0,0 +1,87 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use solana_sdk::{instruction::AccountMeta, signature::{Keypair, Signer}};
+use zai::{GameConfig, Guild, PauseFlag, Player, ZaiError};
+
+fn modify_player_xp(player: Pubkey, authority_registry: Pubkey, admin: Pubkey, xp_change: i64) -> solana_sdk::instruction::Instruction {
+    instruction(
+        zai::accounts::ModifyPlayerXp { player_account: player, game_config: game_config_address().0, authority_registry, guild: None, admin },
+        zai::instruction::ModifyPlayerXp { xp_change },
+    )
+}
+
+#[tokio::test]
+async fn auto_level_waits_while_level_ups_are_paused() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    let game_config = GameConfig { auto_level: true, paused: PauseFlag::LevelUp.mask(), ..game_config(server_key.pubkey()) };
+    add_game_config(&mut program_test, &game_config);
+    let authority_registry = add_authority_registry(&mut program_test);
+    let player = add_player(&mut program_test, Pubkey::new_unique(), 1, 0);
+    let mut context = program_test.start_with_context().await;
+
+    let grant = modify_player_xp(player, authority_registry, server_key.pubkey(), Player::required_xp(1));
+    process_instruction(&mut context, grant, &[&server_key]).await.unwrap();
+
+    let granted: Player = fetch(&mut context, player).await.unwrap();
+    assert_eq!((granted.level, granted.xp, granted.pending_chest_rolls), (1, Player::required_xp(1), 0));
+}
+
+fn modify_player_xp_batch(authority_registry: Pubkey, admin: Pubkey, grants: &[(Pubkey, i64)], guilds: &[Pubkey]) -> solana_sdk::instruction::Instruction {
+    let mut instruction = instruction(
+        zai::accounts::ModifyPlayerXpBatch { game_config: game_config_address().0, authority_registry, admin },
+        zai::instruction::ModifyPlayerXpBatch { xp_changes: grants.iter().map(|(_, xp_change)| *xp_change).collect() },
+    );
+    instruction.accounts.extend(grants.iter().map(|(player, _)| AccountMeta::new(*player, false)));
+    instruction.accounts.extend(guilds.iter().map(|guild| AccountMeta::new(*guild, false)));
+    instruction
+}
+
+#[tokio::test]
+async fn xp_batch_shares_xp_with_guilds() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    add_game_config(&mut program_test, &GameConfig { guild_xp_share_percent: 50, ..game_config(server_key.pubkey()) });
+    let authority_registry = add_authority_registry(&mut program_test);
+    let (guild, other_guild) = (add_guild(&mut program_test, 2), add_guild(&mut program_test, 1));
+    let members = [add_guild_member(&mut program_test, guild), add_guild_member(&mut program_test, guild), add_guild_member(&mut program_test, other_guild)];
+    let mut context = program_test.start_with_context().await;
+
+    let grants = [(members[0], 100), (members[1], -40), (members[2], 300)];
+    let result = process_instruction(&mut context, modify_player_xp_batch(authority_registry, server_key.pubkey(), &grants, &[guild]), &[&server_key]).await;
+    assert_zai_error(result, ZaiError::GuildAccountRequired);
+
+    process_instruction(&mut context, modify_player_xp_batch(authority_registry, server_key.pubkey(), &grants, &[guild, other_guild]), &[&server_key]).await.unwrap();
+
+    // Penalties are not shared
+    let shared: Guild = fetch(&mut context, guild).await.unwrap();
+    assert_eq!(shared.xp, 50);
+    let shared: Guild = fetch(&mut context, other_guild).await.unwrap();
+    assert_eq!(shared.xp, 150);
+}
+
+#[tokio::test]
+async fn packed_xp_batches_run_against_the_program() {
+    let mut program_test = program_test();
+    let server_key = Keypair::new();
+    add_game_config(&mut program_test, &game_config(server_key.pubkey()));
+    let authority_registry = add_authority_registry(&mut program_test);
+    let players: Vec<Pubkey> = (0..12).map(|_| add_player(&mut program_test, Pubkey::new_unique(), 1, 0)).collect();
+    let mut context = program_test.start_with_context().await;
+
+    let keys = zai_interface::ModifyPlayerXpBatchKeys { game_config: game_config_address().0, authority_registry, admin: server_key.pubkey() };
+    let grants: Vec<zai_interface::XpGrant> = players.iter().enumerate().map(|(index, player)| zai_interface::XpGrant { player: *player, guild: None, xp_change: index as i64 + 1 }).collect();
+    let max_compute_units = zai_interface::xp_batch_compute_units(5, 0);
+    let batches = zai_interface::pack_xp_batches(zai::ID, keys, &context.payer.pubkey(), &grants, max_compute_units).unwrap();
+    assert_eq!(batches.len(), 3);
+    for batch in &batches {
+        process_instructions(&mut context, batch, &[&server_key]).await.unwrap();
+    }
+
+    for (index, player) in players.into_iter().enumerate() {
+        let granted: Player = fetch(&mut context, player).await.unwrap();
+        assert_eq!(granted.xp, index as i64 + 1);
+    }
+}
@@ -1,4 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use crate::*;
 use solana_program::pubkey::Pubkey;
 pub const PLAYER_ACCOUNT_DISCM: [u8; 8] = [205, 222, 112, 7, 165, 155, 206, 218];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -11,7 +12,26 @@ pub struct Player {
     pub active_class: u8,
     pub active_weapon: u8,
     pub joined: i64,
+    pub active_cosmetic: u8,
+    pub pending_chest_rolls: u8,
+    pub version: u8,
+    pub in_match: bool,
+    pub slot: u8,
+    pub username: [u8; 16],
+    pub username_changed_at: i64,
+    pub suspended_until: i64,
+    pub suspension_reason: u8,
+    pub referred_by: Pubkey,
+    pub referral_count: u16,
+    pub rewarded_referrals: u16,
+    pub referral_milestones_paid: u8,
+    pub guild: Pubkey,
+    pub voucher_nonce_base: u64,
+    pub voucher_nonce_bitmap: u64,
+    pub open_sessions: u8,
 }
+pub const PLAYER_ACCOUNT_LEN: usize = 256;
+pub const PLAYER_ACCOUNT_VERSION: u8 = 1;
 #[derive(Clone, Debug, PartialEq)]
 pub struct PlayerAccount(pub Player);
 impl PlayerAccount {
@@ -22,8 +42,7 @@ impl PlayerAccount {
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLAYER_ACCOUNT_DISCM {
             return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                std::io::Error::other(
                     format!(
                         "discm does not match. Expected: {:?}. Received: {:?}",
                         PLAYER_ACCOUNT_DISCM, maybe_discm
@@ -31,7 +50,19 @@ impl PlayerAccount {
                 ),
             );
         }
-        Ok(Self(Player::deserialize(&mut reader)?))
+        let mut data = reader.to_vec();
+        if data.len() < PLAYER_ACCOUNT_LEN - 8 {
+            data.resize(PLAYER_ACCOUNT_LEN - 8, 0);
+        }
+        let account = Player::deserialize(&mut data.as_slice())?;
+        if account.version > PLAYER_ACCOUNT_VERSION {
+            return Err(
+                std::io::Error::other(
+                    format!("unsupported player version {}", account.version),
+                ),
+            );
+        }
+        Ok(Self(account))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&PLAYER_ACCOUNT_DISCM)?;
@@ -43,3 +74,607 @@ impl PlayerAccount {
         Ok(data)
     }
 }
+pub const GAME_CONFIG_ACCOUNT_DISCM: [u8; 8] = [45, 146, 146, 33, 170, 69, 96, 133];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameConfig {
+    pub authority: Pubkey,
+    pub server_key: Pubkey,
+    pub level_cap: u8,
+    pub classes: [u8; 8],
+    pub bump: u8,
+    pub pending_server_key: Pubkey,
+    pub previous_server_key: Pubkey,
+    pub previous_key_expiry_slot: u64,
+    pub key_overlap_slots: u64,
+    pub loot_table_version: u16,
+    pub randomness_source: RandomnessSource,
+    pub oracle: OracleConfig,
+    pub auto_level: bool,
+    pub close_guards: u8,
+    pub username_cooldown: i64,
+    pub username_change_fee: u64,
+    pub paused: u8,
+    pub suspended_xp_penalties: bool,
+    pub sponsorship_daily_budget: u64,
+    pub sponsorship_day: i64,
+    pub sponsorship_spent: u64,
+    pub guild_xp_share_percent: u8,
+    pub latest_loot_table_version: u16,
+    pub oracle_fulfiller: OracleFulfiller,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameConfigAccount(pub GameConfig);
+impl GameConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GAME_CONFIG_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        GAME_CONFIG_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(GameConfig::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GAME_CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const LOOT_TABLE_ACCOUNT_DISCM: [u8; 8] = [193, 86, 38, 107, 189, 131, 211, 157];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LootTable {
+    pub version: u16,
+    pub total_weight: u32,
+    pub bump: u8,
+    pub entries: Vec<LootEntry>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LootTableAccount(pub LootTable);
+impl LootTableAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != LOOT_TABLE_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        LOOT_TABLE_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(LootTable::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&LOOT_TABLE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const LEVEL_UP_REQUEST_ACCOUNT_DISCM: [u8; 8] = [
+    245,
+    173,
+    53,
+    21,
+    140,
+    136,
+    106,
+    202,
+];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelUpRequest {
+    pub player: Pubkey,
+    pub randomness: RandomnessCommitment,
+    pub rolls: u8,
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelUpRequestAccount(pub LevelUpRequest);
+impl LevelUpRequestAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != LEVEL_UP_REQUEST_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        LEVEL_UP_REQUEST_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(LevelUpRequest::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&LEVEL_UP_REQUEST_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const CHEST_OPEN_REQUEST_ACCOUNT_DISCM: [u8; 8] = [
+    250,
+    21,
+    86,
+    94,
+    64,
+    178,
+    112,
+    11,
+];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChestOpenRequest {
+    pub player: Pubkey,
+    pub randomness: RandomnessCommitment,
+    pub loot_table_version: u16,
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChestOpenRequestAccount(pub ChestOpenRequest);
+impl ChestOpenRequestAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CHEST_OPEN_REQUEST_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CHEST_OPEN_REQUEST_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ChestOpenRequest::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CHEST_OPEN_REQUEST_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const AUTHORITY_REGISTRY_ACCOUNT_DISCM: [u8; 8] = [
+    239,
+    214,
+    161,
+    141,
+    212,
+    86,
+    122,
+    109,
+];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthorityRegistry {
+    pub bump: u8,
+    pub authorities: Vec<ServerAuthority>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthorityRegistryAccount(pub AuthorityRegistry);
+impl AuthorityRegistryAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != AUTHORITY_REGISTRY_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        AUTHORITY_REGISTRY_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(AuthorityRegistry::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&AUTHORITY_REGISTRY_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const INVENTORY_ACCOUNT_DISCM: [u8; 8] = [97, 218, 167, 233, 216, 170, 49, 27];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inventory {
+    pub player: Pubkey,
+    pub bump: u8,
+    pub classes: Vec<u8>,
+    pub weapons: Vec<u8>,
+    pub cosmetics: Vec<u8>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InventoryAccount(pub Inventory);
+impl InventoryAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INVENTORY_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INVENTORY_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(Inventory::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INVENTORY_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const WALLET_ACCOUNT_ACCOUNT_DISCM: [u8; 8] = [158, 98, 171, 153, 212, 64, 242, 213];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WalletAccount {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub slots: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WalletAccountAccount(pub WalletAccount);
+impl WalletAccountAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != WALLET_ACCOUNT_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        WALLET_ACCOUNT_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(WalletAccount::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&WALLET_ACCOUNT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const USERNAME_ACCOUNT_DISCM: [u8; 8] = [149, 84, 41, 80, 177, 203, 232, 168];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Username {
+    pub player: Pubkey,
+    pub name: [u8; 16],
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsernameAccount(pub Username);
+impl UsernameAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != USERNAME_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        USERNAME_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(Username::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&USERNAME_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const GUILD_ACCOUNT_DISCM: [u8; 8] = [74, 176, 57, 164, 195, 188, 156, 237];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Guild {
+    pub name: [u8; 16],
+    pub leader: Pubkey,
+    pub officers: Vec<Pubkey>,
+    pub member_count: u16,
+    pub member_cap: u16,
+    pub join_policy: GuildJoinPolicy,
+    pub xp: u64,
+    pub level: u8,
+    pub created_at: i64,
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuildAccount(pub Guild);
+impl GuildAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GUILD_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        GUILD_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(Guild::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GUILD_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const GUILD_INVITE_ACCOUNT_DISCM: [u8; 8] = [108, 46, 237, 245, 44, 155, 221, 233];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuildInvite {
+    pub guild: Pubkey,
+    pub player: Pubkey,
+    pub invited: bool,
+    pub applied: bool,
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuildInviteAccount(pub GuildInvite);
+impl GuildInviteAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GUILD_INVITE_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        GUILD_INVITE_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(GuildInvite::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GUILD_INVITE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const MATCH_RECORD_ACCOUNT_DISCM: [u8; 8] = [114, 83, 48, 236, 239, 237, 21, 85];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchRecord {
+    pub match_id: u64,
+    pub settled_at: i64,
+    pub player_count: u8,
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchRecordAccount(pub MatchRecord);
+impl MatchRecordAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != MATCH_RECORD_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MATCH_RECORD_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(MatchRecord::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&MATCH_RECORD_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const REFERRAL_CONFIG_ACCOUNT_DISCM: [u8; 8] = [
+    102,
+    148,
+    171,
+    235,
+    148,
+    83,
+    250,
+    140,
+];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferralConfig {
+    pub bump: u8,
+    pub max_rewarded_referrals: u16,
+    pub milestones: Vec<ReferralMilestone>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferralConfigAccount(pub ReferralConfig);
+impl ReferralConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REFERRAL_CONFIG_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REFERRAL_CONFIG_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ReferralConfig::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REFERRAL_CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const SESSION_ACCOUNT_DISCM: [u8; 8] = [243, 81, 72, 115, 214, 188, 72, 144];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Session {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub scope: u8,
+    pub bump: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionAccount(pub Session);
+impl SessionAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SESSION_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SESSION_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(Session::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SESSION_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const ITEM_REGISTRY_ACCOUNT_DISCM: [u8; 8] = [105, 0, 243, 110, 41, 184, 118, 81];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemRegistry {
+    pub bump: u8,
+    pub items: Vec<ItemDefinition>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemRegistryAccount(pub ItemRegistry);
+impl ItemRegistryAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ITEM_REGISTRY_ACCOUNT_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ITEM_REGISTRY_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ItemRegistry::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ITEM_REGISTRY_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
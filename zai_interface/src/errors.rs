@@ -4,7 +4,7 @@ use solana_program::{
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum ZaiError {
-    #[error("Invalid class selected. Valid options are 101, 102, or 103.")]
+    #[error("Invalid class selected. Class is not in the configured class list.")]
     InvalidClass = 6000,
     #[error("Active weapon must match the active class.")]
     WeaponClassMismatch = 6001,
@@ -22,6 +22,114 @@ pub enum ZaiError {
     InvalidPremiumClass = 6007,
     #[error("Attempted to switch to default weapon.")]
     InvalidPremiumWeapon = 6008,
+    #[error("Invalid game config parameters.")]
+    InvalidConfig = 6009,
+    #[error("Signer does not hold the required server role.")]
+    MissingRole = 6010,
+    #[error("Authority registry is full.")]
+    AuthorityRegistryFull = 6011,
+    #[error("Loot table entries are invalid.")]
+    InvalidLootTable = 6012,
+    #[error("Player has no chests to open.")]
+    NoChestsToOpen = 6013,
+    #[error("Revealed secret does not match the request commitment.")]
+    InvalidReveal = 6014,
+    #[error("Randomness for this request is not available yet.")]
+    RevealTooEarly = 6015,
+    #[error("Randomness account does not match the configured source.")]
+    InvalidRandomnessAccount = 6016,
+    #[error("Player account is already on the latest version.")]
+    PlayerAlreadyMigrated = 6017,
+    #[error("Player does not own this item.")]
+    ItemNotOwned = 6018,
+    #[error("Inventory is full for this item type.")]
+    InventoryFull = 6019,
+    #[error("Item is not in the item registry.")]
+    ItemNotRegistered = 6020,
+    #[error("Player level is too low for this item.")]
+    ItemLevelTooLow = 6021,
+    #[error("Item cannot be used by the active class.")]
+    ItemClassMismatch = 6022,
+    #[error("Item registry is full.")]
+    ItemRegistryFull = 6023,
+    #[error("Player still has unopened chests.")]
+    UnopenedChests = 6024,
+    #[error("Player is currently in a match.")]
+    PlayerInMatch = 6025,
+    #[error("Character slot is not owned by this wallet.")]
+    SlotNotOwned = 6026,
+    #[error("Wallet already owns the maximum number of character slots.")]
+    TooManySlots = 6027,
+    #[error("Username must be 3 to 16 characters of a-z, 0-9 or _.")]
+    InvalidUsername = 6028,
+    #[error("Player already has a username, use change_username.")]
+    UsernameAlreadySet = 6029,
+    #[error("Username was changed too recently.")]
+    UsernameCooldown = 6030,
+    #[error("The player's username account must be passed to release it.")]
+    UsernameNotReleased = 6031,
+    #[error("This instruction is paused.")]
+    ProgramPaused = 6032,
+    #[error("Player account is suspended.")]
+    PlayerSuspended = 6033,
+    #[error(
+        "Session expiry must be in the future and within the maximum duration, with a non-empty scope."
+    )]
+    InvalidSession = 6034,
+    #[error("Session key has expired.")]
+    SessionExpired = 6035,
+    #[error("Session key is not allowed to sign this instruction.")]
+    SessionScopeMismatch = 6036,
+    #[error("Sponsored rent would exceed today's sponsorship budget.")]
+    SponsorshipBudgetExceeded = 6037,
+    #[error(
+        "Referrer must be another wallet's player and match the referee's referred_by."
+    )]
+    InvalidReferrer = 6038,
+    #[error("Guild name must be 3 to 16 characters of a-z, 0-9 or _.")]
+    InvalidGuildName = 6039,
+    #[error("Player is already in a guild.")]
+    AlreadyInGuild = 6040,
+    #[error("Player is not a member of this guild.")]
+    NotInGuild = 6041,
+    #[error("Only guild officers and the leader can do this.")]
+    NotGuildOfficer = 6042,
+    #[error("Guild has reached its member cap.")]
+    GuildFull = 6043,
+    #[error("This guild requires an invite to join.")]
+    GuildInviteRequired = 6044,
+    #[error("Guild leader must transfer leadership before leaving.")]
+    GuildLeaderCannotLeave = 6045,
+    #[error("Guild already has the maximum number of officers.")]
+    TooManyOfficers = 6046,
+    #[error("The player's guild account must be passed to share XP.")]
+    GuildAccountRequired = 6047,
+    #[error(
+        "Match results must list one writable, unique player account per result, then unique guild accounts."
+    )]
+    InvalidMatchResults = 6048,
+    #[error(
+        "XP batch must list one writable, unique player account per delta, then unique guild accounts."
+    )]
+    InvalidXpBatch = 6049,
+    #[error(
+        "Voucher must be signed by an XP granter in the preceding Ed25519 instruction."
+    )]
+    InvalidVoucher = 6050,
+    #[error("XP voucher has expired.")]
+    VoucherExpired = 6051,
+    #[error("XP voucher nonce was already redeemed or is too old.")]
+    VoucherAlreadyRedeemed = 6052,
+    #[error("max_levels must be at least 1.")]
+    InvalidMaxLevels = 6053,
+    #[error("Every open session of the player must be passed to close_player.")]
+    OpenSessions = 6054,
+    #[error("Player must leave their guild before closing.")]
+    PlayerInGuild = 6055,
+    #[error(
+        "Existing referral milestones can only change their reward, new milestones are appended."
+    )]
+    ReferralMilestonesChanged = 6056,
 }
 impl From<ZaiError> for ProgramError {
     fn from(e: ZaiError) -> Self {
@@ -0,0 +1,201 @@
+use base64::Engine;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use crate::*;
+pub const PLAYER_CREATED_EVENT_DISCM: [u8; 8] = [254, 9, 74, 81, 92, 5, 189, 220];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerCreated {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+}
+pub const CLASS_CHANGED_EVENT_DISCM: [u8; 8] = [194, 165, 124, 135, 104, 236, 157, 249];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassChanged {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+}
+pub const XP_MODIFIED_EVENT_DISCM: [u8; 8] = [202, 55, 155, 251, 89, 188, 172, 53];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XpModified {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub xp_change: i64,
+    pub xp: i64,
+}
+pub const LEVELED_UP_EVENT_DISCM: [u8; 8] = [128, 120, 53, 80, 103, 138, 251, 156];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeveledUp {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub levels_gained: u8,
+    pub level: u8,
+    pub xp: i64,
+    pub pending_chest_rolls: u8,
+}
+pub const CHEST_AWARDED_EVENT_DISCM: [u8; 8] = [74, 140, 90, 62, 251, 135, 243, 101];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChestAwarded {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub rolls: u8,
+    pub chests_awarded: u8,
+    pub chests: u16,
+}
+pub const PREMIUM_EQUIPPED_EVENT_DISCM: [u8; 8] = [224, 138, 118, 2, 113, 69, 30, 194];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PremiumEquipped {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+    pub active_cosmetic: u8,
+}
+pub const PLAYER_SUSPENDED_EVENT_DISCM: [u8; 8] = [136, 244, 123, 120, 168, 225, 188, 6];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerSuspended {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub suspended_until: i64,
+    pub reason: u8,
+}
+pub const GUILD_MEMBERSHIP_CHANGED_EVENT_DISCM: [u8; 8] = [
+    118,
+    202,
+    111,
+    104,
+    215,
+    71,
+    108,
+    204,
+];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuildMembershipChanged {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub guild: Pubkey,
+    pub joined: bool,
+}
+pub const REFERRAL_REWARDED_EVENT_DISCM: [u8; 8] = [9, 69, 68, 66, 160, 28, 147, 12];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferralRewarded {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub referee: Pubkey,
+    pub referee_level: u8,
+    pub xp: u64,
+    pub chests: u16,
+}
+pub const CHEST_OPENED_EVENT_DISCM: [u8; 8] = [102, 3, 231, 141, 157, 137, 52, 246];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChestOpened {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub loot_table_version: u16,
+    pub reward: LootReward,
+    pub chests_remaining: u16,
+}
+pub const ITEM_REVOKED_EVENT_DISCM: [u8; 8] = [111, 148, 36, 102, 214, 235, 128, 166];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemRevoked {
+    pub player: Pubkey,
+    pub slot: u8,
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+    pub active_cosmetic: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub enum ZaiEvent {
+    PlayerCreated(PlayerCreated),
+    ClassChanged(ClassChanged),
+    XpModified(XpModified),
+    LeveledUp(LeveledUp),
+    ChestAwarded(ChestAwarded),
+    PremiumEquipped(PremiumEquipped),
+    PlayerSuspended(PlayerSuspended),
+    GuildMembershipChanged(GuildMembershipChanged),
+    ReferralRewarded(ReferralRewarded),
+    ChestOpened(ChestOpened),
+    ItemRevoked(ItemRevoked),
+}
+impl ZaiEvent {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            PLAYER_CREATED_EVENT_DISCM => {
+                Ok(Self::PlayerCreated(PlayerCreated::deserialize(&mut reader)?))
+            }
+            CLASS_CHANGED_EVENT_DISCM => {
+                Ok(Self::ClassChanged(ClassChanged::deserialize(&mut reader)?))
+            }
+            XP_MODIFIED_EVENT_DISCM => {
+                Ok(Self::XpModified(XpModified::deserialize(&mut reader)?))
+            }
+            LEVELED_UP_EVENT_DISCM => {
+                Ok(Self::LeveledUp(LeveledUp::deserialize(&mut reader)?))
+            }
+            CHEST_AWARDED_EVENT_DISCM => {
+                Ok(Self::ChestAwarded(ChestAwarded::deserialize(&mut reader)?))
+            }
+            PREMIUM_EQUIPPED_EVENT_DISCM => {
+                Ok(Self::PremiumEquipped(PremiumEquipped::deserialize(&mut reader)?))
+            }
+            PLAYER_SUSPENDED_EVENT_DISCM => {
+                Ok(Self::PlayerSuspended(PlayerSuspended::deserialize(&mut reader)?))
+            }
+            GUILD_MEMBERSHIP_CHANGED_EVENT_DISCM => {
+                Ok(
+                    Self::GuildMembershipChanged(
+                        GuildMembershipChanged::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            REFERRAL_REWARDED_EVENT_DISCM => {
+                Ok(Self::ReferralRewarded(ReferralRewarded::deserialize(&mut reader)?))
+            }
+            CHEST_OPENED_EVENT_DISCM => {
+                Ok(Self::ChestOpened(ChestOpened::deserialize(&mut reader)?))
+            }
+            ITEM_REVOKED_EVENT_DISCM => {
+                Ok(Self::ItemRevoked(ItemRevoked::deserialize(&mut reader)?))
+            }
+            _ => {
+                Err(
+                    std::io::Error::other(
+                        format!("event discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
+        }
+    }
+}
+pub const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+pub fn decode_log(line: &str) -> Option<ZaiEvent> {
+    let data = line.strip_prefix(PROGRAM_DATA_LOG_PREFIX)?;
+    let bytes = base64::engine::general_purpose::STANDARD.decode(data.trim()).ok()?;
+    ZaiEvent::deserialize(&bytes).ok()
+}
+pub fn decode_logs<S: AsRef<str>>(logs: &[S]) -> Vec<ZaiEvent> {
+    logs.iter().filter_map(|line| decode_log(line.as_ref())).collect()
+}
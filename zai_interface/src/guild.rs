@@ -0,0 +1,37 @@
+use solana_program::pubkey::Pubkey;
+use crate::{Guild, Player};
+pub const GUILD_SEED: &[u8] = b"guild";
+pub const GUILD_INVITE_SEED: &[u8] = b"guild_invite";
+pub const GUILD_LEVEL_XP: u64 = 10_000;
+// Guild PDA for a name, names follow the same rules as usernames.
+pub fn find_guild_address_with_program_id(program_id: Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GUILD_SEED, name.to_ascii_lowercase().as_bytes()], &program_id)
+}
+pub fn find_guild_address(name: &str) -> (Pubkey, u8) {
+    find_guild_address_with_program_id(crate::ID, name)
+}
+// Invite or application PDA passed to invite and join_guild.
+pub fn find_guild_invite_address_with_program_id(program_id: Pubkey, guild: &Pubkey, player: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GUILD_INVITE_SEED, guild.as_ref(), player.as_ref()], &program_id)
+}
+pub fn find_guild_invite_address(guild: &Pubkey, player: &Pubkey) -> (Pubkey, u8) {
+    find_guild_invite_address_with_program_id(crate::ID, guild, player)
+}
+// Total guild XP needed to reach a level, matching the program.
+pub fn guild_required_xp(level: u8) -> u64 {
+    let level = level.saturating_sub(1) as u64;
+    GUILD_LEVEL_XP * level * level
+}
+impl Guild {
+    pub fn name(&self) -> String {
+        let len = self.name.iter().position(|byte| *byte == 0).unwrap_or(self.name.len());
+        String::from_utf8_lossy(&self.name[..len]).into_owned()
+    }
+}
+impl Player {
+    // Guild PDA, None if not in a guild.
+    // modify_player_xp and redeem_xp_voucher take it as guild while guild XP sharing is on, otherwise the program id they are built for.
+    pub fn guild(&self) -> Option<Pubkey> {
+        (self.guild != Pubkey::default()).then_some(self.guild)
+    }
+}
@@ -12,8 +12,50 @@ pub enum ZaiProgramIx {
     CreatePlayer(CreatePlayerIxArgs),
     ChangeDefaultClass(ChangeDefaultClassIxArgs),
     ModifyPlayerXp(ModifyPlayerXpIxArgs),
-    IncreasePlayerLevel,
+    IncreasePlayerLevel(IncreasePlayerLevelIxArgs),
     EquipPremiumItem(EquipPremiumItemIxArgs),
+    InitializeConfig(InitializeConfigIxArgs),
+    UpdateConfig(UpdateConfigIxArgs),
+    GrantRole(GrantRoleIxArgs),
+    RevokeRole(RevokeRoleIxArgs),
+    ProposeServerKey(ProposeServerKeyIxArgs),
+    AcceptServerKey,
+    CreateLootTable(CreateLootTableIxArgs),
+    SetLootTableVersion,
+    OpenChest(OpenChestIxArgs),
+    RequestLevelUp(RequestLevelUpIxArgs),
+    SettleLevelUp(SettleLevelUpIxArgs),
+    SettleChestOpen(SettleChestOpenIxArgs),
+    LevelUpAll(LevelUpAllIxArgs),
+    MigratePlayer,
+    GrantItem(GrantItemIxArgs),
+    RevokeItem(RevokeItemIxArgs),
+    EquipItem(EquipItemIxArgs),
+    SetItemDefinition(SetItemDefinitionIxArgs),
+    RemoveItemDefinition(RemoveItemDefinitionIxArgs),
+    ClosePlayer,
+    SetInMatch(SetInMatchIxArgs),
+    GrantCharacterSlots(GrantCharacterSlotsIxArgs),
+    SetUsername(SetUsernameIxArgs),
+    ChangeUsername(ChangeUsernameIxArgs),
+    SetPaused(SetPausedIxArgs),
+    SuspendPlayer(SuspendPlayerIxArgs),
+    UnsuspendPlayer,
+    CreateSession(CreateSessionIxArgs),
+    RevokeSession,
+    CreatePlayerSponsored(CreatePlayerSponsoredIxArgs),
+    SetReferralConfig(SetReferralConfigIxArgs),
+    CreateGuild(CreateGuildIxArgs),
+    Invite,
+    JoinGuild,
+    LeaveGuild,
+    Kick,
+    TransferLeadership,
+    SetGuildOfficer(SetGuildOfficerIxArgs),
+    SettleMatch(SettleMatchIxArgs),
+    ModifyPlayerXpBatch(ModifyPlayerXpBatchIxArgs),
+    RedeemXpVoucher(RedeemXpVoucherIxArgs),
+    ClaimReferralReward,
 }
 impl ZaiProgramIx {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -34,7 +76,13 @@ impl ZaiProgramIx {
             MODIFY_PLAYER_XP_IX_DISCM => {
                 Ok(Self::ModifyPlayerXp(ModifyPlayerXpIxArgs::deserialize(&mut reader)?))
             }
-            INCREASE_PLAYER_LEVEL_IX_DISCM => Ok(Self::IncreasePlayerLevel),
+            INCREASE_PLAYER_LEVEL_IX_DISCM => {
+                Ok(
+                    Self::IncreasePlayerLevel(
+                        IncreasePlayerLevelIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             EQUIP_PREMIUM_ITEM_IX_DISCM => {
                 Ok(
                     Self::EquipPremiumItem(
@@ -42,14 +90,157 @@ impl ZaiProgramIx {
                     ),
                 )
             }
-            _ => {
-                Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!("discm {:?} not found", maybe_discm),
+            INITIALIZE_CONFIG_IX_DISCM => {
+                Ok(
+                    Self::InitializeConfig(
+                        InitializeConfigIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            UPDATE_CONFIG_IX_DISCM => {
+                Ok(Self::UpdateConfig(UpdateConfigIxArgs::deserialize(&mut reader)?))
+            }
+            GRANT_ROLE_IX_DISCM => {
+                Ok(Self::GrantRole(GrantRoleIxArgs::deserialize(&mut reader)?))
+            }
+            REVOKE_ROLE_IX_DISCM => {
+                Ok(Self::RevokeRole(RevokeRoleIxArgs::deserialize(&mut reader)?))
+            }
+            PROPOSE_SERVER_KEY_IX_DISCM => {
+                Ok(
+                    Self::ProposeServerKey(
+                        ProposeServerKeyIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            ACCEPT_SERVER_KEY_IX_DISCM => Ok(Self::AcceptServerKey),
+            CREATE_LOOT_TABLE_IX_DISCM => {
+                Ok(
+                    Self::CreateLootTable(
+                        CreateLootTableIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_LOOT_TABLE_VERSION_IX_DISCM => Ok(Self::SetLootTableVersion),
+            OPEN_CHEST_IX_DISCM => {
+                Ok(Self::OpenChest(OpenChestIxArgs::deserialize(&mut reader)?))
+            }
+            REQUEST_LEVEL_UP_IX_DISCM => {
+                Ok(Self::RequestLevelUp(RequestLevelUpIxArgs::deserialize(&mut reader)?))
+            }
+            SETTLE_LEVEL_UP_IX_DISCM => {
+                Ok(Self::SettleLevelUp(SettleLevelUpIxArgs::deserialize(&mut reader)?))
+            }
+            SETTLE_CHEST_OPEN_IX_DISCM => {
+                Ok(
+                    Self::SettleChestOpen(
+                        SettleChestOpenIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            LEVEL_UP_ALL_IX_DISCM => {
+                Ok(Self::LevelUpAll(LevelUpAllIxArgs::deserialize(&mut reader)?))
+            }
+            MIGRATE_PLAYER_IX_DISCM => Ok(Self::MigratePlayer),
+            GRANT_ITEM_IX_DISCM => {
+                Ok(Self::GrantItem(GrantItemIxArgs::deserialize(&mut reader)?))
+            }
+            REVOKE_ITEM_IX_DISCM => {
+                Ok(Self::RevokeItem(RevokeItemIxArgs::deserialize(&mut reader)?))
+            }
+            EQUIP_ITEM_IX_DISCM => {
+                Ok(Self::EquipItem(EquipItemIxArgs::deserialize(&mut reader)?))
+            }
+            SET_ITEM_DEFINITION_IX_DISCM => {
+                Ok(
+                    Self::SetItemDefinition(
+                        SetItemDefinitionIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            REMOVE_ITEM_DEFINITION_IX_DISCM => {
+                Ok(
+                    Self::RemoveItemDefinition(
+                        RemoveItemDefinitionIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CLOSE_PLAYER_IX_DISCM => Ok(Self::ClosePlayer),
+            SET_IN_MATCH_IX_DISCM => {
+                Ok(Self::SetInMatch(SetInMatchIxArgs::deserialize(&mut reader)?))
+            }
+            GRANT_CHARACTER_SLOTS_IX_DISCM => {
+                Ok(
+                    Self::GrantCharacterSlots(
+                        GrantCharacterSlotsIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_USERNAME_IX_DISCM => {
+                Ok(Self::SetUsername(SetUsernameIxArgs::deserialize(&mut reader)?))
+            }
+            CHANGE_USERNAME_IX_DISCM => {
+                Ok(Self::ChangeUsername(ChangeUsernameIxArgs::deserialize(&mut reader)?))
+            }
+            SET_PAUSED_IX_DISCM => {
+                Ok(Self::SetPaused(SetPausedIxArgs::deserialize(&mut reader)?))
+            }
+            SUSPEND_PLAYER_IX_DISCM => {
+                Ok(Self::SuspendPlayer(SuspendPlayerIxArgs::deserialize(&mut reader)?))
+            }
+            UNSUSPEND_PLAYER_IX_DISCM => Ok(Self::UnsuspendPlayer),
+            CREATE_SESSION_IX_DISCM => {
+                Ok(Self::CreateSession(CreateSessionIxArgs::deserialize(&mut reader)?))
+            }
+            REVOKE_SESSION_IX_DISCM => Ok(Self::RevokeSession),
+            CREATE_PLAYER_SPONSORED_IX_DISCM => {
+                Ok(
+                    Self::CreatePlayerSponsored(
+                        CreatePlayerSponsoredIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_REFERRAL_CONFIG_IX_DISCM => {
+                Ok(
+                    Self::SetReferralConfig(
+                        SetReferralConfigIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_GUILD_IX_DISCM => {
+                Ok(Self::CreateGuild(CreateGuildIxArgs::deserialize(&mut reader)?))
+            }
+            INVITE_IX_DISCM => Ok(Self::Invite),
+            JOIN_GUILD_IX_DISCM => Ok(Self::JoinGuild),
+            LEAVE_GUILD_IX_DISCM => Ok(Self::LeaveGuild),
+            KICK_IX_DISCM => Ok(Self::Kick),
+            TRANSFER_LEADERSHIP_IX_DISCM => Ok(Self::TransferLeadership),
+            SET_GUILD_OFFICER_IX_DISCM => {
+                Ok(
+                    Self::SetGuildOfficer(
+                        SetGuildOfficerIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SETTLE_MATCH_IX_DISCM => {
+                Ok(Self::SettleMatch(SettleMatchIxArgs::deserialize(&mut reader)?))
+            }
+            MODIFY_PLAYER_XP_BATCH_IX_DISCM => {
+                Ok(
+                    Self::ModifyPlayerXpBatch(
+                        ModifyPlayerXpBatchIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            REDEEM_XP_VOUCHER_IX_DISCM => {
+                Ok(
+                    Self::RedeemXpVoucher(
+                        RedeemXpVoucherIxArgs::deserialize(&mut reader)?,
                     ),
                 )
             }
+            CLAIM_REFERRAL_REWARD_IX_DISCM => Ok(Self::ClaimReferralReward),
+            _ => Err(std::io::Error::other(format!("discm {:?} not found", maybe_discm))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -66,13 +257,150 @@ impl ZaiProgramIx {
                 writer.write_all(&MODIFY_PLAYER_XP_IX_DISCM)?;
                 args.serialize(&mut writer)
             }
-            Self::IncreasePlayerLevel => {
-                writer.write_all(&INCREASE_PLAYER_LEVEL_IX_DISCM)
+            Self::IncreasePlayerLevel(args) => {
+                writer.write_all(&INCREASE_PLAYER_LEVEL_IX_DISCM)?;
+                args.serialize(&mut writer)
             }
             Self::EquipPremiumItem(args) => {
                 writer.write_all(&EQUIP_PREMIUM_ITEM_IX_DISCM)?;
                 args.serialize(&mut writer)
             }
+            Self::InitializeConfig(args) => {
+                writer.write_all(&INITIALIZE_CONFIG_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::UpdateConfig(args) => {
+                writer.write_all(&UPDATE_CONFIG_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::GrantRole(args) => {
+                writer.write_all(&GRANT_ROLE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RevokeRole(args) => {
+                writer.write_all(&REVOKE_ROLE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::ProposeServerKey(args) => {
+                writer.write_all(&PROPOSE_SERVER_KEY_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::AcceptServerKey => writer.write_all(&ACCEPT_SERVER_KEY_IX_DISCM),
+            Self::CreateLootTable(args) => {
+                writer.write_all(&CREATE_LOOT_TABLE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLootTableVersion => {
+                writer.write_all(&SET_LOOT_TABLE_VERSION_IX_DISCM)
+            }
+            Self::OpenChest(args) => {
+                writer.write_all(&OPEN_CHEST_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RequestLevelUp(args) => {
+                writer.write_all(&REQUEST_LEVEL_UP_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SettleLevelUp(args) => {
+                writer.write_all(&SETTLE_LEVEL_UP_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SettleChestOpen(args) => {
+                writer.write_all(&SETTLE_CHEST_OPEN_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::LevelUpAll(args) => {
+                writer.write_all(&LEVEL_UP_ALL_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::MigratePlayer => writer.write_all(&MIGRATE_PLAYER_IX_DISCM),
+            Self::GrantItem(args) => {
+                writer.write_all(&GRANT_ITEM_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RevokeItem(args) => {
+                writer.write_all(&REVOKE_ITEM_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::EquipItem(args) => {
+                writer.write_all(&EQUIP_ITEM_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetItemDefinition(args) => {
+                writer.write_all(&SET_ITEM_DEFINITION_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveItemDefinition(args) => {
+                writer.write_all(&REMOVE_ITEM_DEFINITION_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::ClosePlayer => writer.write_all(&CLOSE_PLAYER_IX_DISCM),
+            Self::SetInMatch(args) => {
+                writer.write_all(&SET_IN_MATCH_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::GrantCharacterSlots(args) => {
+                writer.write_all(&GRANT_CHARACTER_SLOTS_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetUsername(args) => {
+                writer.write_all(&SET_USERNAME_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::ChangeUsername(args) => {
+                writer.write_all(&CHANGE_USERNAME_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetPaused(args) => {
+                writer.write_all(&SET_PAUSED_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SuspendPlayer(args) => {
+                writer.write_all(&SUSPEND_PLAYER_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::UnsuspendPlayer => writer.write_all(&UNSUSPEND_PLAYER_IX_DISCM),
+            Self::CreateSession(args) => {
+                writer.write_all(&CREATE_SESSION_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RevokeSession => writer.write_all(&REVOKE_SESSION_IX_DISCM),
+            Self::CreatePlayerSponsored(args) => {
+                writer.write_all(&CREATE_PLAYER_SPONSORED_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetReferralConfig(args) => {
+                writer.write_all(&SET_REFERRAL_CONFIG_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::CreateGuild(args) => {
+                writer.write_all(&CREATE_GUILD_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Invite => writer.write_all(&INVITE_IX_DISCM),
+            Self::JoinGuild => writer.write_all(&JOIN_GUILD_IX_DISCM),
+            Self::LeaveGuild => writer.write_all(&LEAVE_GUILD_IX_DISCM),
+            Self::Kick => writer.write_all(&KICK_IX_DISCM),
+            Self::TransferLeadership => writer.write_all(&TRANSFER_LEADERSHIP_IX_DISCM),
+            Self::SetGuildOfficer(args) => {
+                writer.write_all(&SET_GUILD_OFFICER_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SettleMatch(args) => {
+                writer.write_all(&SETTLE_MATCH_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::ModifyPlayerXpBatch(args) => {
+                writer.write_all(&MODIFY_PLAYER_XP_BATCH_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RedeemXpVoucher(args) => {
+                writer.write_all(&REDEEM_XP_VOUCHER_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::ClaimReferralReward => {
+                writer.write_all(&CLAIM_REFERRAL_REWARD_IX_DISCM)
+            }
         }
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -96,16 +424,24 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke_signed(ix, &account_info, seeds)
 }
-pub const CREATE_PLAYER_IX_ACCOUNTS_LEN: usize = 3;
+pub const CREATE_PLAYER_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct CreatePlayerAccounts<'me, 'info> {
     pub player_account: &'me AccountInfo<'info>,
+    pub wallet_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub referrer: &'me AccountInfo<'info>,
     pub signer: &'me AccountInfo<'info>,
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreatePlayerKeys {
     pub player_account: Pubkey,
+    pub wallet_account: Pubkey,
+    pub game_config: Pubkey,
+    pub item_registry: Pubkey,
+    pub referrer: Pubkey,
     pub signer: Pubkey,
     pub system_program: Pubkey,
 }
@@ -113,6 +449,10 @@ impl From<CreatePlayerAccounts<'_, '_>> for CreatePlayerKeys {
     fn from(accounts: CreatePlayerAccounts) -> Self {
         Self {
             player_account: *accounts.player_account.key,
+            wallet_account: *accounts.wallet_account.key,
+            game_config: *accounts.game_config.key,
+            item_registry: *accounts.item_registry.key,
+            referrer: *accounts.referrer.key,
             signer: *accounts.signer.key,
             system_program: *accounts.system_program.key,
         }
@@ -126,6 +466,26 @@ impl From<CreatePlayerKeys> for [AccountMeta; CREATE_PLAYER_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.wallet_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer,
+                is_signer: false,
+                is_writable: true,
+            },
             AccountMeta {
                 pubkey: keys.signer,
                 is_signer: true,
@@ -143,8 +503,12 @@ impl From<[Pubkey; CREATE_PLAYER_IX_ACCOUNTS_LEN]> for CreatePlayerKeys {
     fn from(pubkeys: [Pubkey; CREATE_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: pubkeys[0],
-            signer: pubkeys[1],
-            system_program: pubkeys[2],
+            wallet_account: pubkeys[1],
+            game_config: pubkeys[2],
+            item_registry: pubkeys[3],
+            referrer: pubkeys[4],
+            signer: pubkeys[5],
+            system_program: pubkeys[6],
         }
     }
 }
@@ -153,6 +517,10 @@ for [AccountInfo<'info>; CREATE_PLAYER_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreatePlayerAccounts<'_, 'info>) -> Self {
         [
             accounts.player_account.clone(),
+            accounts.wallet_account.clone(),
+            accounts.game_config.clone(),
+            accounts.item_registry.clone(),
+            accounts.referrer.clone(),
             accounts.signer.clone(),
             accounts.system_program.clone(),
         ]
@@ -163,8 +531,12 @@ for CreatePlayerAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CREATE_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: &arr[0],
-            signer: &arr[1],
-            system_program: &arr[2],
+            wallet_account: &arr[1],
+            game_config: &arr[2],
+            item_registry: &arr[3],
+            referrer: &arr[4],
+            signer: &arr[5],
+            system_program: &arr[6],
         }
     }
 }
@@ -172,6 +544,7 @@ pub const CREATE_PLAYER_IX_DISCM: [u8; 8] = [19, 178, 189, 216, 159, 134, 0, 192
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreatePlayerIxArgs {
+    pub slot: u8,
     pub active_class: u8,
     pub active_weapon: u8,
 }
@@ -189,8 +562,7 @@ impl CreatePlayerIxData {
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CREATE_PLAYER_IX_DISCM {
             return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                std::io::Error::other(
                     format!(
                         "discm does not match. Expected: {:?}. Received: {:?}",
                         CREATE_PLAYER_IX_DISCM, maybe_discm
@@ -267,6 +639,10 @@ pub fn create_player_verify_account_keys(
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.player_account.key, keys.player_account),
+        (*accounts.wallet_account.key, keys.wallet_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.referrer.key, keys.referrer),
         (*accounts.signer.key, keys.signer),
         (*accounts.system_program.key, keys.system_program),
     ] {
@@ -279,7 +655,12 @@ pub fn create_player_verify_account_keys(
 pub fn create_player_verify_writable_privileges<'me, 'info>(
     accounts: CreatePlayerAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.player_account, accounts.signer] {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.wallet_account,
+        accounts.referrer,
+        accounts.signer,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -303,21 +684,30 @@ pub fn create_player_verify_account_privileges<'me, 'info>(
     create_player_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN: usize = 2;
+pub const CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct ChangeDefaultClassAccounts<'me, 'info> {
     pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
     pub signer: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ChangeDefaultClassKeys {
     pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub item_registry: Pubkey,
+    pub session: Pubkey,
     pub signer: Pubkey,
 }
 impl From<ChangeDefaultClassAccounts<'_, '_>> for ChangeDefaultClassKeys {
     fn from(accounts: ChangeDefaultClassAccounts) -> Self {
         Self {
             player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            item_registry: *accounts.item_registry.key,
+            session: *accounts.session.key,
             signer: *accounts.signer.key,
         }
     }
@@ -331,6 +721,21 @@ for [AccountMeta; CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.signer,
                 is_signer: true,
@@ -343,14 +748,23 @@ impl From<[Pubkey; CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN]> for ChangeDefaultClass
     fn from(pubkeys: [Pubkey; CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: pubkeys[0],
-            signer: pubkeys[1],
+            game_config: pubkeys[1],
+            item_registry: pubkeys[2],
+            session: pubkeys[3],
+            signer: pubkeys[4],
         }
     }
 }
 impl<'info> From<ChangeDefaultClassAccounts<'_, 'info>>
 for [AccountInfo<'info>; CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN] {
     fn from(accounts: ChangeDefaultClassAccounts<'_, 'info>) -> Self {
-        [accounts.player_account.clone(), accounts.signer.clone()]
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.item_registry.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_DEFAULT_CLASS_IX_ACCOUNTS_LEN]>
@@ -360,7 +774,10 @@ for ChangeDefaultClassAccounts<'me, 'info> {
     ) -> Self {
         Self {
             player_account: &arr[0],
-            signer: &arr[1],
+            game_config: &arr[1],
+            item_registry: &arr[2],
+            session: &arr[3],
+            signer: &arr[4],
         }
     }
 }
@@ -377,6 +794,7 @@ pub const CHANGE_DEFAULT_CLASS_IX_DISCM: [u8; 8] = [
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeDefaultClassIxArgs {
+    pub slot: u8,
     pub new_class: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -393,8 +811,7 @@ impl ChangeDefaultClassIxData {
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CHANGE_DEFAULT_CLASS_IX_DISCM {
             return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                std::io::Error::other(
                     format!(
                         "discm does not match. Expected: {:?}. Received: {:?}",
                         CHANGE_DEFAULT_CLASS_IX_DISCM, maybe_discm
@@ -471,6 +888,9 @@ pub fn change_default_class_verify_account_keys(
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.session.key, keys.session),
         (*accounts.signer.key, keys.signer),
     ] {
         if actual != expected {
@@ -506,21 +926,30 @@ pub fn change_default_class_verify_account_privileges<'me, 'info>(
     change_default_class_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN: usize = 2;
+pub const MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct ModifyPlayerXpAccounts<'me, 'info> {
     pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub guild: &'me AccountInfo<'info>,
     pub admin: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ModifyPlayerXpKeys {
     pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub guild: Pubkey,
     pub admin: Pubkey,
 }
 impl From<ModifyPlayerXpAccounts<'_, '_>> for ModifyPlayerXpKeys {
     fn from(accounts: ModifyPlayerXpAccounts) -> Self {
         Self {
             player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            guild: *accounts.guild.key,
             admin: *accounts.admin.key,
         }
     }
@@ -533,6 +962,21 @@ impl From<ModifyPlayerXpKeys> for [AccountMeta; MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
             AccountMeta {
                 pubkey: keys.admin,
                 is_signer: true,
@@ -545,14 +989,23 @@ impl From<[Pubkey; MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN]> for ModifyPlayerXpKeys {
     fn from(pubkeys: [Pubkey; MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: pubkeys[0],
-            admin: pubkeys[1],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            guild: pubkeys[3],
+            admin: pubkeys[4],
         }
     }
 }
 impl<'info> From<ModifyPlayerXpAccounts<'_, 'info>>
 for [AccountInfo<'info>; MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN] {
     fn from(accounts: ModifyPlayerXpAccounts<'_, 'info>) -> Self {
-        [accounts.player_account.clone(), accounts.admin.clone()]
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.guild.clone(),
+            accounts.admin.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN]>
@@ -560,7 +1013,10 @@ for ModifyPlayerXpAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; MODIFY_PLAYER_XP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: &arr[0],
-            admin: &arr[1],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            guild: &arr[3],
+            admin: &arr[4],
         }
     }
 }
@@ -584,8 +1040,7 @@ impl ModifyPlayerXpIxData {
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != MODIFY_PLAYER_XP_IX_DISCM {
             return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                std::io::Error::other(
                     format!(
                         "discm does not match. Expected: {:?}. Received: {:?}",
                         MODIFY_PLAYER_XP_IX_DISCM, maybe_discm
@@ -662,6 +1117,9 @@ pub fn modify_player_xp_verify_account_keys(
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.guild.key, keys.guild),
         (*accounts.admin.key, keys.admin),
     ] {
         if actual != expected {
@@ -673,7 +1131,7 @@ pub fn modify_player_xp_verify_account_keys(
 pub fn modify_player_xp_verify_writable_privileges<'me, 'info>(
     accounts: ModifyPlayerXpAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.player_account] {
+    for should_be_writable in [accounts.player_account, accounts.guild] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -697,21 +1155,33 @@ pub fn modify_player_xp_verify_account_privileges<'me, 'info>(
     modify_player_xp_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN: usize = 2;
+pub const INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct IncreasePlayerLevelAccounts<'me, 'info> {
     pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub referral_config: &'me AccountInfo<'info>,
+    pub referrer: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
     pub signer: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct IncreasePlayerLevelKeys {
     pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub referral_config: Pubkey,
+    pub referrer: Pubkey,
+    pub session: Pubkey,
     pub signer: Pubkey,
 }
 impl From<IncreasePlayerLevelAccounts<'_, '_>> for IncreasePlayerLevelKeys {
     fn from(accounts: IncreasePlayerLevelAccounts) -> Self {
         Self {
             player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            referral_config: *accounts.referral_config.key,
+            referrer: *accounts.referrer.key,
+            session: *accounts.session.key,
             signer: *accounts.signer.key,
         }
     }
@@ -725,6 +1195,26 @@ for [AccountMeta; INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referral_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.signer,
                 is_signer: true,
@@ -737,14 +1227,25 @@ impl From<[Pubkey; INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN]> for IncreasePlayerLev
     fn from(pubkeys: [Pubkey; INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: pubkeys[0],
-            signer: pubkeys[1],
+            game_config: pubkeys[1],
+            referral_config: pubkeys[2],
+            referrer: pubkeys[3],
+            session: pubkeys[4],
+            signer: pubkeys[5],
         }
     }
 }
 impl<'info> From<IncreasePlayerLevelAccounts<'_, 'info>>
 for [AccountInfo<'info>; INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN] {
     fn from(accounts: IncreasePlayerLevelAccounts<'_, 'info>) -> Self {
-        [accounts.player_account.clone(), accounts.signer.clone()]
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.referral_config.clone(),
+            accounts.referrer.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN]>
@@ -754,7 +1255,11 @@ for IncreasePlayerLevelAccounts<'me, 'info> {
     ) -> Self {
         Self {
             player_account: &arr[0],
-            signer: &arr[1],
+            game_config: &arr[1],
+            referral_config: &arr[2],
+            referrer: &arr[3],
+            session: &arr[4],
+            signer: &arr[5],
         }
     }
 }
@@ -768,8 +1273,18 @@ pub const INCREASE_PLAYER_LEVEL_IX_DISCM: [u8; 8] = [
     58,
     224,
 ];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncreasePlayerLevelIxArgs {
+    pub slot: u8,
+}
 #[derive(Clone, Debug, PartialEq)]
-pub struct IncreasePlayerLevelIxData;
+pub struct IncreasePlayerLevelIxData(pub IncreasePlayerLevelIxArgs);
+impl From<IncreasePlayerLevelIxArgs> for IncreasePlayerLevelIxData {
+    fn from(args: IncreasePlayerLevelIxArgs) -> Self {
+        Self(args)
+    }
+}
 impl IncreasePlayerLevelIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
@@ -777,8 +1292,7 @@ impl IncreasePlayerLevelIxData {
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INCREASE_PLAYER_LEVEL_IX_DISCM {
             return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                std::io::Error::other(
                     format!(
                         "discm does not match. Expected: {:?}. Received: {:?}",
                         INCREASE_PLAYER_LEVEL_IX_DISCM, maybe_discm
@@ -786,10 +1300,11 @@ impl IncreasePlayerLevelIxData {
                 ),
             );
         }
-        Ok(Self)
+        Ok(Self(IncreasePlayerLevelIxArgs::deserialize(&mut reader)?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&INCREASE_PLAYER_LEVEL_IX_DISCM)
+        writer.write_all(&INCREASE_PLAYER_LEVEL_IX_DISCM)?;
+        self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
         let mut data = Vec::new();
@@ -800,46 +1315,53 @@ impl IncreasePlayerLevelIxData {
 pub fn increase_player_level_ix_with_program_id(
     program_id: Pubkey,
     keys: IncreasePlayerLevelKeys,
+    args: IncreasePlayerLevelIxArgs,
 ) -> std::io::Result<Instruction> {
     let metas: [AccountMeta; INCREASE_PLAYER_LEVEL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: IncreasePlayerLevelIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: IncreasePlayerLevelIxData.try_to_vec()?,
+        data: data.try_to_vec()?,
     })
 }
 pub fn increase_player_level_ix(
     keys: IncreasePlayerLevelKeys,
+    args: IncreasePlayerLevelIxArgs,
 ) -> std::io::Result<Instruction> {
-    increase_player_level_ix_with_program_id(crate::ID, keys)
+    increase_player_level_ix_with_program_id(crate::ID, keys, args)
 }
 pub fn increase_player_level_invoke_with_program_id(
     program_id: Pubkey,
     accounts: IncreasePlayerLevelAccounts<'_, '_>,
+    args: IncreasePlayerLevelIxArgs,
 ) -> ProgramResult {
     let keys: IncreasePlayerLevelKeys = accounts.into();
-    let ix = increase_player_level_ix_with_program_id(program_id, keys)?;
+    let ix = increase_player_level_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
 pub fn increase_player_level_invoke(
     accounts: IncreasePlayerLevelAccounts<'_, '_>,
+    args: IncreasePlayerLevelIxArgs,
 ) -> ProgramResult {
-    increase_player_level_invoke_with_program_id(crate::ID, accounts)
+    increase_player_level_invoke_with_program_id(crate::ID, accounts, args)
 }
 pub fn increase_player_level_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: IncreasePlayerLevelAccounts<'_, '_>,
+    args: IncreasePlayerLevelIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: IncreasePlayerLevelKeys = accounts.into();
-    let ix = increase_player_level_ix_with_program_id(program_id, keys)?;
+    let ix = increase_player_level_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
 pub fn increase_player_level_invoke_signed(
     accounts: IncreasePlayerLevelAccounts<'_, '_>,
+    args: IncreasePlayerLevelIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    increase_player_level_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    increase_player_level_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
 pub fn increase_player_level_verify_account_keys(
     accounts: IncreasePlayerLevelAccounts<'_, '_>,
@@ -847,6 +1369,10 @@ pub fn increase_player_level_verify_account_keys(
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.referral_config.key, keys.referral_config),
+        (*accounts.referrer.key, keys.referrer),
+        (*accounts.session.key, keys.session),
         (*accounts.signer.key, keys.signer),
     ] {
         if actual != expected {
@@ -858,7 +1384,11 @@ pub fn increase_player_level_verify_account_keys(
 pub fn increase_player_level_verify_writable_privileges<'me, 'info>(
     accounts: IncreasePlayerLevelAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.player_account, accounts.signer] {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.referrer,
+        accounts.signer,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -882,21 +1412,33 @@ pub fn increase_player_level_verify_account_privileges<'me, 'info>(
     increase_player_level_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN: usize = 2;
+pub const EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct EquipPremiumItemAccounts<'me, 'info> {
     pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub inventory: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
     pub admin: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct EquipPremiumItemKeys {
     pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub inventory: Pubkey,
+    pub item_registry: Pubkey,
+    pub authority_registry: Pubkey,
     pub admin: Pubkey,
 }
 impl From<EquipPremiumItemAccounts<'_, '_>> for EquipPremiumItemKeys {
     fn from(accounts: EquipPremiumItemAccounts) -> Self {
         Self {
             player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            inventory: *accounts.inventory.key,
+            item_registry: *accounts.item_registry.key,
+            authority_registry: *accounts.authority_registry.key,
             admin: *accounts.admin.key,
         }
     }
@@ -909,6 +1451,26 @@ impl From<EquipPremiumItemKeys> for [AccountMeta; EQUIP_PREMIUM_ITEM_IX_ACCOUNTS
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.inventory,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.admin,
                 is_signer: true,
@@ -921,14 +1483,25 @@ impl From<[Pubkey; EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN]> for EquipPremiumItemKeys
     fn from(pubkeys: [Pubkey; EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: pubkeys[0],
-            admin: pubkeys[1],
+            game_config: pubkeys[1],
+            inventory: pubkeys[2],
+            item_registry: pubkeys[3],
+            authority_registry: pubkeys[4],
+            admin: pubkeys[5],
         }
     }
 }
 impl<'info> From<EquipPremiumItemAccounts<'_, 'info>>
 for [AccountInfo<'info>; EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN] {
     fn from(accounts: EquipPremiumItemAccounts<'_, 'info>) -> Self {
-        [accounts.player_account.clone(), accounts.admin.clone()]
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.inventory.clone(),
+            accounts.item_registry.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN]>
@@ -936,7 +1509,11 @@ for EquipPremiumItemAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; EQUIP_PREMIUM_ITEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             player_account: &arr[0],
-            admin: &arr[1],
+            game_config: &arr[1],
+            inventory: &arr[2],
+            item_registry: &arr[3],
+            authority_registry: &arr[4],
+            admin: &arr[5],
         }
     }
 }
@@ -961,8 +1538,7 @@ impl EquipPremiumItemIxData {
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != EQUIP_PREMIUM_ITEM_IX_DISCM {
             return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                std::io::Error::other(
                     format!(
                         "discm does not match. Expected: {:?}. Received: {:?}",
                         EQUIP_PREMIUM_ITEM_IX_DISCM, maybe_discm
@@ -1039,6 +1615,10 @@ pub fn equip_premium_item_verify_account_keys(
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.inventory.key, keys.inventory),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.authority_registry.key, keys.authority_registry),
         (*accounts.admin.key, keys.admin),
     ] {
         if actual != expected {
@@ -1074,3 +1654,9471 @@ pub fn equip_premium_item_verify_account_privileges<'me, 'info>(
     equip_premium_item_verify_signer_privileges(accounts)?;
     Ok(())
 }
+pub const INITIALIZE_CONFIG_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeConfigAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+    pub program: &'me AccountInfo<'info>,
+    pub program_data: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitializeConfigKeys {
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub authority: Pubkey,
+    pub program: Pubkey,
+    pub program_data: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<InitializeConfigAccounts<'_, '_>> for InitializeConfigKeys {
+    fn from(accounts: InitializeConfigAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            authority: *accounts.authority.key,
+            program: *accounts.program.key,
+            program_data: *accounts.program_data.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitializeConfigKeys> for [AccountMeta; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeConfigKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.program_data,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN]> for InitializeConfigKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority_registry: pubkeys[1],
+            authority: pubkeys[2],
+            program: pubkeys[3],
+            program_data: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<InitializeConfigAccounts<'_, 'info>>
+for [AccountInfo<'info>; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(accounts: InitializeConfigAccounts<'_, 'info>) -> Self {
+        [
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.authority.clone(),
+            accounts.program.clone(),
+            accounts.program_data.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN]>
+for InitializeConfigAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority_registry: &arr[1],
+            authority: &arr[2],
+            program: &arr[3],
+            program_data: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const INITIALIZE_CONFIG_IX_DISCM: [u8; 8] = [208, 127, 21, 1, 194, 190, 196, 70];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeConfigIxArgs {
+    pub server_key: Pubkey,
+    pub level_cap: u8,
+    pub classes: Vec<u8>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeConfigIxData(pub InitializeConfigIxArgs);
+impl From<InitializeConfigIxArgs> for InitializeConfigIxData {
+    fn from(args: InitializeConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INITIALIZE_CONFIG_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INITIALIZE_CONFIG_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(InitializeConfigIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_CONFIG_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeConfigKeys,
+    args: InitializeConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeConfigIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_config_ix(
+    keys: InitializeConfigKeys,
+    args: InitializeConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    initialize_config_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn initialize_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeConfigAccounts<'_, '_>,
+    args: InitializeConfigIxArgs,
+) -> ProgramResult {
+    let keys: InitializeConfigKeys = accounts.into();
+    let ix = initialize_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_config_invoke(
+    accounts: InitializeConfigAccounts<'_, '_>,
+    args: InitializeConfigIxArgs,
+) -> ProgramResult {
+    initialize_config_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeConfigAccounts<'_, '_>,
+    args: InitializeConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeConfigKeys = accounts.into();
+    let ix = initialize_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_config_invoke_signed(
+    accounts: InitializeConfigAccounts<'_, '_>,
+    args: InitializeConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_config_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn initialize_config_verify_account_keys(
+    accounts: InitializeConfigAccounts<'_, '_>,
+    keys: InitializeConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.authority.key, keys.authority),
+        (*accounts.program.key, keys.program),
+        (*accounts.program_data.key, keys.program_data),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_config_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.game_config,
+        accounts.authority_registry,
+        accounts.authority,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_config_verify_signer_privileges<'me, 'info>(
+    accounts: InitializeConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_config_verify_account_privileges<'me, 'info>(
+    accounts: InitializeConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_config_verify_writable_privileges(accounts)?;
+    initialize_config_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_CONFIG_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateConfigAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UpdateConfigKeys {
+    pub game_config: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<UpdateConfigAccounts<'_, '_>> for UpdateConfigKeys {
+    fn from(accounts: UpdateConfigAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<UpdateConfigKeys> for [AccountMeta; UPDATE_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateConfigKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_CONFIG_IX_ACCOUNTS_LEN]> for UpdateConfigKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<UpdateConfigAccounts<'_, 'info>>
+for [AccountInfo<'info>; UPDATE_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdateConfigAccounts<'_, 'info>) -> Self {
+        [accounts.game_config.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_CONFIG_IX_ACCOUNTS_LEN]>
+for UpdateConfigAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const UPDATE_CONFIG_IX_DISCM: [u8; 8] = [29, 158, 252, 191, 10, 83, 219, 99];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateConfigIxArgs {
+    pub update: ConfigUpdate,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateConfigIxData(pub UpdateConfigIxArgs);
+impl From<UpdateConfigIxArgs> for UpdateConfigIxData {
+    fn from(args: UpdateConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != UPDATE_CONFIG_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_CONFIG_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(UpdateConfigIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&UPDATE_CONFIG_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn update_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateConfigKeys,
+    args: UpdateConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateConfigIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn update_config_ix(
+    keys: UpdateConfigKeys,
+    args: UpdateConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    update_config_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn update_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateConfigAccounts<'_, '_>,
+    args: UpdateConfigIxArgs,
+) -> ProgramResult {
+    let keys: UpdateConfigKeys = accounts.into();
+    let ix = update_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_config_invoke(
+    accounts: UpdateConfigAccounts<'_, '_>,
+    args: UpdateConfigIxArgs,
+) -> ProgramResult {
+    update_config_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateConfigAccounts<'_, '_>,
+    args: UpdateConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateConfigKeys = accounts.into();
+    let ix = update_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_config_invoke_signed(
+    accounts: UpdateConfigAccounts<'_, '_>,
+    args: UpdateConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_config_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn update_config_verify_account_keys(
+    accounts: UpdateConfigAccounts<'_, '_>,
+    keys: UpdateConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_config_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.game_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_config_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_config_verify_account_privileges<'me, 'info>(
+    accounts: UpdateConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_config_verify_writable_privileges(accounts)?;
+    update_config_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const GRANT_ROLE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct GrantRoleAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GrantRoleKeys {
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<GrantRoleAccounts<'_, '_>> for GrantRoleKeys {
+    fn from(accounts: GrantRoleAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<GrantRoleKeys> for [AccountMeta; GRANT_ROLE_IX_ACCOUNTS_LEN] {
+    fn from(keys: GrantRoleKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; GRANT_ROLE_IX_ACCOUNTS_LEN]> for GrantRoleKeys {
+    fn from(pubkeys: [Pubkey; GRANT_ROLE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority_registry: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<GrantRoleAccounts<'_, 'info>>
+for [AccountInfo<'info>; GRANT_ROLE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: GrantRoleAccounts<'_, 'info>) -> Self {
+        [
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; GRANT_ROLE_IX_ACCOUNTS_LEN]>
+for GrantRoleAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; GRANT_ROLE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority_registry: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+pub const GRANT_ROLE_IX_DISCM: [u8; 8] = [218, 234, 128, 15, 82, 33, 236, 253];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantRoleIxArgs {
+    pub key: Pubkey,
+    pub role: Role,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrantRoleIxData(pub GrantRoleIxArgs);
+impl From<GrantRoleIxArgs> for GrantRoleIxData {
+    fn from(args: GrantRoleIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl GrantRoleIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GRANT_ROLE_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        GRANT_ROLE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(GrantRoleIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GRANT_ROLE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn grant_role_ix_with_program_id(
+    program_id: Pubkey,
+    keys: GrantRoleKeys,
+    args: GrantRoleIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; GRANT_ROLE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: GrantRoleIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn grant_role_ix(
+    keys: GrantRoleKeys,
+    args: GrantRoleIxArgs,
+) -> std::io::Result<Instruction> {
+    grant_role_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn grant_role_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: GrantRoleAccounts<'_, '_>,
+    args: GrantRoleIxArgs,
+) -> ProgramResult {
+    let keys: GrantRoleKeys = accounts.into();
+    let ix = grant_role_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn grant_role_invoke(
+    accounts: GrantRoleAccounts<'_, '_>,
+    args: GrantRoleIxArgs,
+) -> ProgramResult {
+    grant_role_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn grant_role_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: GrantRoleAccounts<'_, '_>,
+    args: GrantRoleIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: GrantRoleKeys = accounts.into();
+    let ix = grant_role_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn grant_role_invoke_signed(
+    accounts: GrantRoleAccounts<'_, '_>,
+    args: GrantRoleIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    grant_role_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn grant_role_verify_account_keys(
+    accounts: GrantRoleAccounts<'_, '_>,
+    keys: GrantRoleKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn grant_role_verify_writable_privileges<'me, 'info>(
+    accounts: GrantRoleAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.authority_registry] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn grant_role_verify_signer_privileges<'me, 'info>(
+    accounts: GrantRoleAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn grant_role_verify_account_privileges<'me, 'info>(
+    accounts: GrantRoleAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    grant_role_verify_writable_privileges(accounts)?;
+    grant_role_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REVOKE_ROLE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct RevokeRoleAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RevokeRoleKeys {
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<RevokeRoleAccounts<'_, '_>> for RevokeRoleKeys {
+    fn from(accounts: RevokeRoleAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<RevokeRoleKeys> for [AccountMeta; REVOKE_ROLE_IX_ACCOUNTS_LEN] {
+    fn from(keys: RevokeRoleKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REVOKE_ROLE_IX_ACCOUNTS_LEN]> for RevokeRoleKeys {
+    fn from(pubkeys: [Pubkey; REVOKE_ROLE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority_registry: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<RevokeRoleAccounts<'_, 'info>>
+for [AccountInfo<'info>; REVOKE_ROLE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeRoleAccounts<'_, 'info>) -> Self {
+        [
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_ROLE_IX_ACCOUNTS_LEN]>
+for RevokeRoleAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; REVOKE_ROLE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority_registry: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+pub const REVOKE_ROLE_IX_DISCM: [u8; 8] = [179, 232, 2, 180, 48, 227, 82, 7];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevokeRoleIxArgs {
+    pub key: Pubkey,
+    pub role: Role,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevokeRoleIxData(pub RevokeRoleIxArgs);
+impl From<RevokeRoleIxArgs> for RevokeRoleIxData {
+    fn from(args: RevokeRoleIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RevokeRoleIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REVOKE_ROLE_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_ROLE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(RevokeRoleIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REVOKE_ROLE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn revoke_role_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RevokeRoleKeys,
+    args: RevokeRoleIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REVOKE_ROLE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RevokeRoleIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn revoke_role_ix(
+    keys: RevokeRoleKeys,
+    args: RevokeRoleIxArgs,
+) -> std::io::Result<Instruction> {
+    revoke_role_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn revoke_role_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeRoleAccounts<'_, '_>,
+    args: RevokeRoleIxArgs,
+) -> ProgramResult {
+    let keys: RevokeRoleKeys = accounts.into();
+    let ix = revoke_role_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn revoke_role_invoke(
+    accounts: RevokeRoleAccounts<'_, '_>,
+    args: RevokeRoleIxArgs,
+) -> ProgramResult {
+    revoke_role_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn revoke_role_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeRoleAccounts<'_, '_>,
+    args: RevokeRoleIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RevokeRoleKeys = accounts.into();
+    let ix = revoke_role_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn revoke_role_invoke_signed(
+    accounts: RevokeRoleAccounts<'_, '_>,
+    args: RevokeRoleIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    revoke_role_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn revoke_role_verify_account_keys(
+    accounts: RevokeRoleAccounts<'_, '_>,
+    keys: RevokeRoleKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_role_verify_writable_privileges<'me, 'info>(
+    accounts: RevokeRoleAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.authority_registry] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_role_verify_signer_privileges<'me, 'info>(
+    accounts: RevokeRoleAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_role_verify_account_privileges<'me, 'info>(
+    accounts: RevokeRoleAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    revoke_role_verify_writable_privileges(accounts)?;
+    revoke_role_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeServerKeyAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProposeServerKeyKeys {
+    pub game_config: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<ProposeServerKeyAccounts<'_, '_>> for ProposeServerKeyKeys {
+    fn from(accounts: ProposeServerKeyAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<ProposeServerKeyKeys> for [AccountMeta; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN] {
+    fn from(keys: ProposeServerKeyKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN]> for ProposeServerKeyKeys {
+    fn from(pubkeys: [Pubkey; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<ProposeServerKeyAccounts<'_, 'info>>
+for [AccountInfo<'info>; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ProposeServerKeyAccounts<'_, 'info>) -> Self {
+        [accounts.game_config.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN]>
+for ProposeServerKeyAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const PROPOSE_SERVER_KEY_IX_DISCM: [u8; 8] = [40, 168, 239, 76, 218, 248, 63, 219];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposeServerKeyIxArgs {
+    pub new_server_key: Pubkey,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposeServerKeyIxData(pub ProposeServerKeyIxArgs);
+impl From<ProposeServerKeyIxArgs> for ProposeServerKeyIxData {
+    fn from(args: ProposeServerKeyIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ProposeServerKeyIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != PROPOSE_SERVER_KEY_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PROPOSE_SERVER_KEY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ProposeServerKeyIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&PROPOSE_SERVER_KEY_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn propose_server_key_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ProposeServerKeyKeys,
+    args: ProposeServerKeyIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; PROPOSE_SERVER_KEY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ProposeServerKeyIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn propose_server_key_ix(
+    keys: ProposeServerKeyKeys,
+    args: ProposeServerKeyIxArgs,
+) -> std::io::Result<Instruction> {
+    propose_server_key_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn propose_server_key_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ProposeServerKeyAccounts<'_, '_>,
+    args: ProposeServerKeyIxArgs,
+) -> ProgramResult {
+    let keys: ProposeServerKeyKeys = accounts.into();
+    let ix = propose_server_key_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn propose_server_key_invoke(
+    accounts: ProposeServerKeyAccounts<'_, '_>,
+    args: ProposeServerKeyIxArgs,
+) -> ProgramResult {
+    propose_server_key_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn propose_server_key_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ProposeServerKeyAccounts<'_, '_>,
+    args: ProposeServerKeyIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ProposeServerKeyKeys = accounts.into();
+    let ix = propose_server_key_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn propose_server_key_invoke_signed(
+    accounts: ProposeServerKeyAccounts<'_, '_>,
+    args: ProposeServerKeyIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    propose_server_key_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn propose_server_key_verify_account_keys(
+    accounts: ProposeServerKeyAccounts<'_, '_>,
+    keys: ProposeServerKeyKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn propose_server_key_verify_writable_privileges<'me, 'info>(
+    accounts: ProposeServerKeyAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.game_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn propose_server_key_verify_signer_privileges<'me, 'info>(
+    accounts: ProposeServerKeyAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn propose_server_key_verify_account_privileges<'me, 'info>(
+    accounts: ProposeServerKeyAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    propose_server_key_verify_writable_privileges(accounts)?;
+    propose_server_key_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptServerKeyAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub new_server_key: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AcceptServerKeyKeys {
+    pub game_config: Pubkey,
+    pub new_server_key: Pubkey,
+}
+impl From<AcceptServerKeyAccounts<'_, '_>> for AcceptServerKeyKeys {
+    fn from(accounts: AcceptServerKeyAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            new_server_key: *accounts.new_server_key.key,
+        }
+    }
+}
+impl From<AcceptServerKeyKeys> for [AccountMeta; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN] {
+    fn from(keys: AcceptServerKeyKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_server_key,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN]> for AcceptServerKeyKeys {
+    fn from(pubkeys: [Pubkey; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            new_server_key: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<AcceptServerKeyAccounts<'_, 'info>>
+for [AccountInfo<'info>; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AcceptServerKeyAccounts<'_, 'info>) -> Self {
+        [accounts.game_config.clone(), accounts.new_server_key.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN]>
+for AcceptServerKeyAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            new_server_key: &arr[1],
+        }
+    }
+}
+pub const ACCEPT_SERVER_KEY_IX_DISCM: [u8; 8] = [249, 255, 153, 134, 87, 127, 157, 150];
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptServerKeyIxData;
+impl AcceptServerKeyIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ACCEPT_SERVER_KEY_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ACCEPT_SERVER_KEY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ACCEPT_SERVER_KEY_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn accept_server_key_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AcceptServerKeyKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ACCEPT_SERVER_KEY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AcceptServerKeyIxData.try_to_vec()?,
+    })
+}
+pub fn accept_server_key_ix(keys: AcceptServerKeyKeys) -> std::io::Result<Instruction> {
+    accept_server_key_ix_with_program_id(crate::ID, keys)
+}
+pub fn accept_server_key_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptServerKeyAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AcceptServerKeyKeys = accounts.into();
+    let ix = accept_server_key_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn accept_server_key_invoke(
+    accounts: AcceptServerKeyAccounts<'_, '_>,
+) -> ProgramResult {
+    accept_server_key_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn accept_server_key_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptServerKeyAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AcceptServerKeyKeys = accounts.into();
+    let ix = accept_server_key_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn accept_server_key_invoke_signed(
+    accounts: AcceptServerKeyAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    accept_server_key_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn accept_server_key_verify_account_keys(
+    accounts: AcceptServerKeyAccounts<'_, '_>,
+    keys: AcceptServerKeyKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.new_server_key.key, keys.new_server_key),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn accept_server_key_verify_writable_privileges<'me, 'info>(
+    accounts: AcceptServerKeyAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.game_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn accept_server_key_verify_signer_privileges<'me, 'info>(
+    accounts: AcceptServerKeyAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.new_server_key] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn accept_server_key_verify_account_privileges<'me, 'info>(
+    accounts: AcceptServerKeyAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    accept_server_key_verify_writable_privileges(accounts)?;
+    accept_server_key_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct CreateLootTableAccounts<'me, 'info> {
+    pub loot_table: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CreateLootTableKeys {
+    pub loot_table: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<CreateLootTableAccounts<'_, '_>> for CreateLootTableKeys {
+    fn from(accounts: CreateLootTableAccounts) -> Self {
+        Self {
+            loot_table: *accounts.loot_table.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<CreateLootTableKeys> for [AccountMeta; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateLootTableKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.loot_table,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN]> for CreateLootTableKeys {
+    fn from(pubkeys: [Pubkey; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            loot_table: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<CreateLootTableAccounts<'_, 'info>>
+for [AccountInfo<'info>; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateLootTableAccounts<'_, 'info>) -> Self {
+        [
+            accounts.loot_table.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN]>
+for CreateLootTableAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            loot_table: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const CREATE_LOOT_TABLE_IX_DISCM: [u8; 8] = [196, 32, 172, 22, 164, 41, 59, 143];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateLootTableIxArgs {
+    pub entries: Vec<LootEntry>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateLootTableIxData(pub CreateLootTableIxArgs);
+impl From<CreateLootTableIxArgs> for CreateLootTableIxData {
+    fn from(args: CreateLootTableIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateLootTableIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CREATE_LOOT_TABLE_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_LOOT_TABLE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateLootTableIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CREATE_LOOT_TABLE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn create_loot_table_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateLootTableKeys,
+    args: CreateLootTableIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_LOOT_TABLE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateLootTableIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_loot_table_ix(
+    keys: CreateLootTableKeys,
+    args: CreateLootTableIxArgs,
+) -> std::io::Result<Instruction> {
+    create_loot_table_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn create_loot_table_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateLootTableAccounts<'_, '_>,
+    args: CreateLootTableIxArgs,
+) -> ProgramResult {
+    let keys: CreateLootTableKeys = accounts.into();
+    let ix = create_loot_table_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn create_loot_table_invoke(
+    accounts: CreateLootTableAccounts<'_, '_>,
+    args: CreateLootTableIxArgs,
+) -> ProgramResult {
+    create_loot_table_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn create_loot_table_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateLootTableAccounts<'_, '_>,
+    args: CreateLootTableIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CreateLootTableKeys = accounts.into();
+    let ix = create_loot_table_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn create_loot_table_invoke_signed(
+    accounts: CreateLootTableAccounts<'_, '_>,
+    args: CreateLootTableIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    create_loot_table_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn create_loot_table_verify_account_keys(
+    accounts: CreateLootTableAccounts<'_, '_>,
+    keys: CreateLootTableKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.loot_table.key, keys.loot_table),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn create_loot_table_verify_writable_privileges<'me, 'info>(
+    accounts: CreateLootTableAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.loot_table,
+        accounts.game_config,
+        accounts.admin,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn create_loot_table_verify_signer_privileges<'me, 'info>(
+    accounts: CreateLootTableAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn create_loot_table_verify_account_privileges<'me, 'info>(
+    accounts: CreateLootTableAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    create_loot_table_verify_writable_privileges(accounts)?;
+    create_loot_table_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLootTableVersionAccounts<'me, 'info> {
+    pub loot_table: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetLootTableVersionKeys {
+    pub loot_table: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<SetLootTableVersionAccounts<'_, '_>> for SetLootTableVersionKeys {
+    fn from(accounts: SetLootTableVersionAccounts) -> Self {
+        Self {
+            loot_table: *accounts.loot_table.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<SetLootTableVersionKeys>
+for [AccountMeta; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLootTableVersionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.loot_table,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN]> for SetLootTableVersionKeys {
+    fn from(pubkeys: [Pubkey; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            loot_table: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetLootTableVersionAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetLootTableVersionAccounts<'_, 'info>) -> Self {
+        [
+            accounts.loot_table.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN]>
+for SetLootTableVersionAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            loot_table: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+        }
+    }
+}
+pub const SET_LOOT_TABLE_VERSION_IX_DISCM: [u8; 8] = [
+    160,
+    161,
+    162,
+    90,
+    12,
+    173,
+    153,
+    36,
+];
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLootTableVersionIxData;
+impl SetLootTableVersionIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_LOOT_TABLE_VERSION_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_LOOT_TABLE_VERSION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_LOOT_TABLE_VERSION_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_loot_table_version_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLootTableVersionKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LOOT_TABLE_VERSION_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetLootTableVersionIxData.try_to_vec()?,
+    })
+}
+pub fn set_loot_table_version_ix(
+    keys: SetLootTableVersionKeys,
+) -> std::io::Result<Instruction> {
+    set_loot_table_version_ix_with_program_id(crate::ID, keys)
+}
+pub fn set_loot_table_version_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLootTableVersionAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SetLootTableVersionKeys = accounts.into();
+    let ix = set_loot_table_version_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_loot_table_version_invoke(
+    accounts: SetLootTableVersionAccounts<'_, '_>,
+) -> ProgramResult {
+    set_loot_table_version_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn set_loot_table_version_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLootTableVersionAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLootTableVersionKeys = accounts.into();
+    let ix = set_loot_table_version_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_loot_table_version_invoke_signed(
+    accounts: SetLootTableVersionAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_loot_table_version_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn set_loot_table_version_verify_account_keys(
+    accounts: SetLootTableVersionAccounts<'_, '_>,
+    keys: SetLootTableVersionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.loot_table.key, keys.loot_table),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_loot_table_version_verify_writable_privileges<'me, 'info>(
+    accounts: SetLootTableVersionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.game_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_loot_table_version_verify_signer_privileges<'me, 'info>(
+    accounts: SetLootTableVersionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_loot_table_version_verify_account_privileges<'me, 'info>(
+    accounts: SetLootTableVersionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_loot_table_version_verify_writable_privileges(accounts)?;
+    set_loot_table_version_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const OPEN_CHEST_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct OpenChestAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub loot_table: &'me AccountInfo<'info>,
+    pub chest_open_request: &'me AccountInfo<'info>,
+    pub randomness_account: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OpenChestKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub loot_table: Pubkey,
+    pub chest_open_request: Pubkey,
+    pub randomness_account: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<OpenChestAccounts<'_, '_>> for OpenChestKeys {
+    fn from(accounts: OpenChestAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            loot_table: *accounts.loot_table.key,
+            chest_open_request: *accounts.chest_open_request.key,
+            randomness_account: *accounts.randomness_account.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<OpenChestKeys> for [AccountMeta; OPEN_CHEST_IX_ACCOUNTS_LEN] {
+    fn from(keys: OpenChestKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.loot_table,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.chest_open_request,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.randomness_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; OPEN_CHEST_IX_ACCOUNTS_LEN]> for OpenChestKeys {
+    fn from(pubkeys: [Pubkey; OPEN_CHEST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            loot_table: pubkeys[2],
+            chest_open_request: pubkeys[3],
+            randomness_account: pubkeys[4],
+            session: pubkeys[5],
+            signer: pubkeys[6],
+            system_program: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<OpenChestAccounts<'_, 'info>>
+for [AccountInfo<'info>; OPEN_CHEST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: OpenChestAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.loot_table.clone(),
+            accounts.chest_open_request.clone(),
+            accounts.randomness_account.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; OPEN_CHEST_IX_ACCOUNTS_LEN]>
+for OpenChestAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; OPEN_CHEST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            loot_table: &arr[2],
+            chest_open_request: &arr[3],
+            randomness_account: &arr[4],
+            session: &arr[5],
+            signer: &arr[6],
+            system_program: &arr[7],
+        }
+    }
+}
+pub const OPEN_CHEST_IX_DISCM: [u8; 8] = [143, 8, 253, 123, 197, 30, 173, 15];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpenChestIxArgs {
+    pub commitment: [u8; 32],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpenChestIxData(pub OpenChestIxArgs);
+impl From<OpenChestIxArgs> for OpenChestIxData {
+    fn from(args: OpenChestIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl OpenChestIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != OPEN_CHEST_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        OPEN_CHEST_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(OpenChestIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&OPEN_CHEST_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn open_chest_ix_with_program_id(
+    program_id: Pubkey,
+    keys: OpenChestKeys,
+    args: OpenChestIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; OPEN_CHEST_IX_ACCOUNTS_LEN] = keys.into();
+    let data: OpenChestIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn open_chest_ix(
+    keys: OpenChestKeys,
+    args: OpenChestIxArgs,
+) -> std::io::Result<Instruction> {
+    open_chest_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn open_chest_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: OpenChestAccounts<'_, '_>,
+    args: OpenChestIxArgs,
+) -> ProgramResult {
+    let keys: OpenChestKeys = accounts.into();
+    let ix = open_chest_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn open_chest_invoke(
+    accounts: OpenChestAccounts<'_, '_>,
+    args: OpenChestIxArgs,
+) -> ProgramResult {
+    open_chest_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn open_chest_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: OpenChestAccounts<'_, '_>,
+    args: OpenChestIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: OpenChestKeys = accounts.into();
+    let ix = open_chest_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn open_chest_invoke_signed(
+    accounts: OpenChestAccounts<'_, '_>,
+    args: OpenChestIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    open_chest_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn open_chest_verify_account_keys(
+    accounts: OpenChestAccounts<'_, '_>,
+    keys: OpenChestKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.loot_table.key, keys.loot_table),
+        (*accounts.chest_open_request.key, keys.chest_open_request),
+        (*accounts.randomness_account.key, keys.randomness_account),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn open_chest_verify_writable_privileges<'me, 'info>(
+    accounts: OpenChestAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.chest_open_request,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn open_chest_verify_signer_privileges<'me, 'info>(
+    accounts: OpenChestAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn open_chest_verify_account_privileges<'me, 'info>(
+    accounts: OpenChestAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    open_chest_verify_writable_privileges(accounts)?;
+    open_chest_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct RequestLevelUpAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub level_up_request: &'me AccountInfo<'info>,
+    pub randomness_account: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RequestLevelUpKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub level_up_request: Pubkey,
+    pub randomness_account: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<RequestLevelUpAccounts<'_, '_>> for RequestLevelUpKeys {
+    fn from(accounts: RequestLevelUpAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            level_up_request: *accounts.level_up_request.key,
+            randomness_account: *accounts.randomness_account.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<RequestLevelUpKeys> for [AccountMeta; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN] {
+    fn from(keys: RequestLevelUpKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.level_up_request,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.randomness_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN]> for RequestLevelUpKeys {
+    fn from(pubkeys: [Pubkey; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            level_up_request: pubkeys[2],
+            randomness_account: pubkeys[3],
+            session: pubkeys[4],
+            signer: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<RequestLevelUpAccounts<'_, 'info>>
+for [AccountInfo<'info>; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RequestLevelUpAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.level_up_request.clone(),
+            accounts.randomness_account.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN]>
+for RequestLevelUpAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            level_up_request: &arr[2],
+            randomness_account: &arr[3],
+            session: &arr[4],
+            signer: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const REQUEST_LEVEL_UP_IX_DISCM: [u8; 8] = [232, 171, 107, 252, 94, 116, 122, 39];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequestLevelUpIxArgs {
+    pub commitment: [u8; 32],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestLevelUpIxData(pub RequestLevelUpIxArgs);
+impl From<RequestLevelUpIxArgs> for RequestLevelUpIxData {
+    fn from(args: RequestLevelUpIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RequestLevelUpIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REQUEST_LEVEL_UP_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REQUEST_LEVEL_UP_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(RequestLevelUpIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REQUEST_LEVEL_UP_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn request_level_up_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RequestLevelUpKeys,
+    args: RequestLevelUpIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REQUEST_LEVEL_UP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RequestLevelUpIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn request_level_up_ix(
+    keys: RequestLevelUpKeys,
+    args: RequestLevelUpIxArgs,
+) -> std::io::Result<Instruction> {
+    request_level_up_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn request_level_up_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RequestLevelUpAccounts<'_, '_>,
+    args: RequestLevelUpIxArgs,
+) -> ProgramResult {
+    let keys: RequestLevelUpKeys = accounts.into();
+    let ix = request_level_up_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn request_level_up_invoke(
+    accounts: RequestLevelUpAccounts<'_, '_>,
+    args: RequestLevelUpIxArgs,
+) -> ProgramResult {
+    request_level_up_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn request_level_up_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RequestLevelUpAccounts<'_, '_>,
+    args: RequestLevelUpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RequestLevelUpKeys = accounts.into();
+    let ix = request_level_up_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn request_level_up_invoke_signed(
+    accounts: RequestLevelUpAccounts<'_, '_>,
+    args: RequestLevelUpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    request_level_up_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn request_level_up_verify_account_keys(
+    accounts: RequestLevelUpAccounts<'_, '_>,
+    keys: RequestLevelUpKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.level_up_request.key, keys.level_up_request),
+        (*accounts.randomness_account.key, keys.randomness_account),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn request_level_up_verify_writable_privileges<'me, 'info>(
+    accounts: RequestLevelUpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.level_up_request,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn request_level_up_verify_signer_privileges<'me, 'info>(
+    accounts: RequestLevelUpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn request_level_up_verify_account_privileges<'me, 'info>(
+    accounts: RequestLevelUpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    request_level_up_verify_writable_privileges(accounts)?;
+    request_level_up_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct SettleLevelUpAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub level_up_request: &'me AccountInfo<'info>,
+    pub randomness_account: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SettleLevelUpKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub level_up_request: Pubkey,
+    pub randomness_account: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<SettleLevelUpAccounts<'_, '_>> for SettleLevelUpKeys {
+    fn from(accounts: SettleLevelUpAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            level_up_request: *accounts.level_up_request.key,
+            randomness_account: *accounts.randomness_account.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<SettleLevelUpKeys> for [AccountMeta; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN] {
+    fn from(keys: SettleLevelUpKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.level_up_request,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.randomness_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN]> for SettleLevelUpKeys {
+    fn from(pubkeys: [Pubkey; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            level_up_request: pubkeys[2],
+            randomness_account: pubkeys[3],
+            session: pubkeys[4],
+            signer: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<SettleLevelUpAccounts<'_, 'info>>
+for [AccountInfo<'info>; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SettleLevelUpAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.level_up_request.clone(),
+            accounts.randomness_account.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN]>
+for SettleLevelUpAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            level_up_request: &arr[2],
+            randomness_account: &arr[3],
+            session: &arr[4],
+            signer: &arr[5],
+        }
+    }
+}
+pub const SETTLE_LEVEL_UP_IX_DISCM: [u8; 8] = [220, 239, 195, 87, 106, 83, 25, 81];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettleLevelUpIxArgs {
+    pub secret: [u8; 32],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettleLevelUpIxData(pub SettleLevelUpIxArgs);
+impl From<SettleLevelUpIxArgs> for SettleLevelUpIxData {
+    fn from(args: SettleLevelUpIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SettleLevelUpIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SETTLE_LEVEL_UP_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SETTLE_LEVEL_UP_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SettleLevelUpIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SETTLE_LEVEL_UP_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn settle_level_up_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SettleLevelUpKeys,
+    args: SettleLevelUpIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SETTLE_LEVEL_UP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SettleLevelUpIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn settle_level_up_ix(
+    keys: SettleLevelUpKeys,
+    args: SettleLevelUpIxArgs,
+) -> std::io::Result<Instruction> {
+    settle_level_up_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn settle_level_up_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleLevelUpAccounts<'_, '_>,
+    args: SettleLevelUpIxArgs,
+) -> ProgramResult {
+    let keys: SettleLevelUpKeys = accounts.into();
+    let ix = settle_level_up_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn settle_level_up_invoke(
+    accounts: SettleLevelUpAccounts<'_, '_>,
+    args: SettleLevelUpIxArgs,
+) -> ProgramResult {
+    settle_level_up_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn settle_level_up_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleLevelUpAccounts<'_, '_>,
+    args: SettleLevelUpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SettleLevelUpKeys = accounts.into();
+    let ix = settle_level_up_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn settle_level_up_invoke_signed(
+    accounts: SettleLevelUpAccounts<'_, '_>,
+    args: SettleLevelUpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    settle_level_up_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn settle_level_up_verify_account_keys(
+    accounts: SettleLevelUpAccounts<'_, '_>,
+    keys: SettleLevelUpKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.level_up_request.key, keys.level_up_request),
+        (*accounts.randomness_account.key, keys.randomness_account),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn settle_level_up_verify_writable_privileges<'me, 'info>(
+    accounts: SettleLevelUpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.level_up_request,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn settle_level_up_verify_signer_privileges<'me, 'info>(
+    accounts: SettleLevelUpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn settle_level_up_verify_account_privileges<'me, 'info>(
+    accounts: SettleLevelUpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    settle_level_up_verify_writable_privileges(accounts)?;
+    settle_level_up_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN: usize = 10;
+#[derive(Copy, Clone, Debug)]
+pub struct SettleChestOpenAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub loot_table: &'me AccountInfo<'info>,
+    pub chest_open_request: &'me AccountInfo<'info>,
+    pub inventory: &'me AccountInfo<'info>,
+    pub randomness_account: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SettleChestOpenKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub item_registry: Pubkey,
+    pub loot_table: Pubkey,
+    pub chest_open_request: Pubkey,
+    pub inventory: Pubkey,
+    pub randomness_account: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<SettleChestOpenAccounts<'_, '_>> for SettleChestOpenKeys {
+    fn from(accounts: SettleChestOpenAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            item_registry: *accounts.item_registry.key,
+            loot_table: *accounts.loot_table.key,
+            chest_open_request: *accounts.chest_open_request.key,
+            inventory: *accounts.inventory.key,
+            randomness_account: *accounts.randomness_account.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SettleChestOpenKeys> for [AccountMeta; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN] {
+    fn from(keys: SettleChestOpenKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.loot_table,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.chest_open_request,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.inventory,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.randomness_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN]> for SettleChestOpenKeys {
+    fn from(pubkeys: [Pubkey; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            item_registry: pubkeys[2],
+            loot_table: pubkeys[3],
+            chest_open_request: pubkeys[4],
+            inventory: pubkeys[5],
+            randomness_account: pubkeys[6],
+            session: pubkeys[7],
+            signer: pubkeys[8],
+            system_program: pubkeys[9],
+        }
+    }
+}
+impl<'info> From<SettleChestOpenAccounts<'_, 'info>>
+for [AccountInfo<'info>; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SettleChestOpenAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.item_registry.clone(),
+            accounts.loot_table.clone(),
+            accounts.chest_open_request.clone(),
+            accounts.inventory.clone(),
+            accounts.randomness_account.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN]>
+for SettleChestOpenAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            item_registry: &arr[2],
+            loot_table: &arr[3],
+            chest_open_request: &arr[4],
+            inventory: &arr[5],
+            randomness_account: &arr[6],
+            session: &arr[7],
+            signer: &arr[8],
+            system_program: &arr[9],
+        }
+    }
+}
+pub const SETTLE_CHEST_OPEN_IX_DISCM: [u8; 8] = [100, 109, 184, 44, 93, 227, 213, 146];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettleChestOpenIxArgs {
+    pub secret: [u8; 32],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettleChestOpenIxData(pub SettleChestOpenIxArgs);
+impl From<SettleChestOpenIxArgs> for SettleChestOpenIxData {
+    fn from(args: SettleChestOpenIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SettleChestOpenIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SETTLE_CHEST_OPEN_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SETTLE_CHEST_OPEN_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SettleChestOpenIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SETTLE_CHEST_OPEN_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn settle_chest_open_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SettleChestOpenKeys,
+    args: SettleChestOpenIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SETTLE_CHEST_OPEN_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SettleChestOpenIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn settle_chest_open_ix(
+    keys: SettleChestOpenKeys,
+    args: SettleChestOpenIxArgs,
+) -> std::io::Result<Instruction> {
+    settle_chest_open_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn settle_chest_open_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleChestOpenAccounts<'_, '_>,
+    args: SettleChestOpenIxArgs,
+) -> ProgramResult {
+    let keys: SettleChestOpenKeys = accounts.into();
+    let ix = settle_chest_open_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn settle_chest_open_invoke(
+    accounts: SettleChestOpenAccounts<'_, '_>,
+    args: SettleChestOpenIxArgs,
+) -> ProgramResult {
+    settle_chest_open_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn settle_chest_open_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleChestOpenAccounts<'_, '_>,
+    args: SettleChestOpenIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SettleChestOpenKeys = accounts.into();
+    let ix = settle_chest_open_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn settle_chest_open_invoke_signed(
+    accounts: SettleChestOpenAccounts<'_, '_>,
+    args: SettleChestOpenIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    settle_chest_open_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn settle_chest_open_verify_account_keys(
+    accounts: SettleChestOpenAccounts<'_, '_>,
+    keys: SettleChestOpenKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.loot_table.key, keys.loot_table),
+        (*accounts.chest_open_request.key, keys.chest_open_request),
+        (*accounts.inventory.key, keys.inventory),
+        (*accounts.randomness_account.key, keys.randomness_account),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn settle_chest_open_verify_writable_privileges<'me, 'info>(
+    accounts: SettleChestOpenAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.chest_open_request,
+        accounts.inventory,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn settle_chest_open_verify_signer_privileges<'me, 'info>(
+    accounts: SettleChestOpenAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn settle_chest_open_verify_account_privileges<'me, 'info>(
+    accounts: SettleChestOpenAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    settle_chest_open_verify_writable_privileges(accounts)?;
+    settle_chest_open_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const LEVEL_UP_ALL_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct LevelUpAllAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub referral_config: &'me AccountInfo<'info>,
+    pub referrer: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LevelUpAllKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub referral_config: Pubkey,
+    pub referrer: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<LevelUpAllAccounts<'_, '_>> for LevelUpAllKeys {
+    fn from(accounts: LevelUpAllAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            referral_config: *accounts.referral_config.key,
+            referrer: *accounts.referrer.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<LevelUpAllKeys> for [AccountMeta; LEVEL_UP_ALL_IX_ACCOUNTS_LEN] {
+    fn from(keys: LevelUpAllKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referral_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; LEVEL_UP_ALL_IX_ACCOUNTS_LEN]> for LevelUpAllKeys {
+    fn from(pubkeys: [Pubkey; LEVEL_UP_ALL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            referral_config: pubkeys[2],
+            referrer: pubkeys[3],
+            session: pubkeys[4],
+            signer: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<LevelUpAllAccounts<'_, 'info>>
+for [AccountInfo<'info>; LEVEL_UP_ALL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: LevelUpAllAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.referral_config.clone(),
+            accounts.referrer.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; LEVEL_UP_ALL_IX_ACCOUNTS_LEN]>
+for LevelUpAllAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; LEVEL_UP_ALL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            referral_config: &arr[2],
+            referrer: &arr[3],
+            session: &arr[4],
+            signer: &arr[5],
+        }
+    }
+}
+pub const LEVEL_UP_ALL_IX_DISCM: [u8; 8] = [41, 183, 62, 104, 1, 174, 163, 250];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelUpAllIxArgs {
+    pub slot: u8,
+    pub max_levels: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelUpAllIxData(pub LevelUpAllIxArgs);
+impl From<LevelUpAllIxArgs> for LevelUpAllIxData {
+    fn from(args: LevelUpAllIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl LevelUpAllIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != LEVEL_UP_ALL_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        LEVEL_UP_ALL_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(LevelUpAllIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&LEVEL_UP_ALL_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn level_up_all_ix_with_program_id(
+    program_id: Pubkey,
+    keys: LevelUpAllKeys,
+    args: LevelUpAllIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; LEVEL_UP_ALL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: LevelUpAllIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn level_up_all_ix(
+    keys: LevelUpAllKeys,
+    args: LevelUpAllIxArgs,
+) -> std::io::Result<Instruction> {
+    level_up_all_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn level_up_all_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: LevelUpAllAccounts<'_, '_>,
+    args: LevelUpAllIxArgs,
+) -> ProgramResult {
+    let keys: LevelUpAllKeys = accounts.into();
+    let ix = level_up_all_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn level_up_all_invoke(
+    accounts: LevelUpAllAccounts<'_, '_>,
+    args: LevelUpAllIxArgs,
+) -> ProgramResult {
+    level_up_all_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn level_up_all_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: LevelUpAllAccounts<'_, '_>,
+    args: LevelUpAllIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: LevelUpAllKeys = accounts.into();
+    let ix = level_up_all_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn level_up_all_invoke_signed(
+    accounts: LevelUpAllAccounts<'_, '_>,
+    args: LevelUpAllIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    level_up_all_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn level_up_all_verify_account_keys(
+    accounts: LevelUpAllAccounts<'_, '_>,
+    keys: LevelUpAllKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.referral_config.key, keys.referral_config),
+        (*accounts.referrer.key, keys.referrer),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn level_up_all_verify_writable_privileges<'me, 'info>(
+    accounts: LevelUpAllAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.referrer,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn level_up_all_verify_signer_privileges<'me, 'info>(
+    accounts: LevelUpAllAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn level_up_all_verify_account_privileges<'me, 'info>(
+    accounts: LevelUpAllAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    level_up_all_verify_writable_privileges(accounts)?;
+    level_up_all_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MIGRATE_PLAYER_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct MigratePlayerAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MigratePlayerKeys {
+    pub player_account: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<MigratePlayerAccounts<'_, '_>> for MigratePlayerKeys {
+    fn from(accounts: MigratePlayerAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            payer: *accounts.payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigratePlayerKeys> for [AccountMeta; MIGRATE_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigratePlayerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_PLAYER_IX_ACCOUNTS_LEN]> for MigratePlayerKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            payer: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<MigratePlayerAccounts<'_, 'info>>
+for [AccountInfo<'info>; MIGRATE_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: MigratePlayerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_PLAYER_IX_ACCOUNTS_LEN]>
+for MigratePlayerAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            payer: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+pub const MIGRATE_PLAYER_IX_DISCM: [u8; 8] = [194, 21, 151, 9, 90, 168, 177, 54];
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigratePlayerIxData;
+impl MigratePlayerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != MIGRATE_PLAYER_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MIGRATE_PLAYER_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&MIGRATE_PLAYER_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_player_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigratePlayerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_PLAYER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigratePlayerIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_player_ix(keys: MigratePlayerKeys) -> std::io::Result<Instruction> {
+    migrate_player_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_player_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigratePlayerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigratePlayerKeys = accounts.into();
+    let ix = migrate_player_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_player_invoke(accounts: MigratePlayerAccounts<'_, '_>) -> ProgramResult {
+    migrate_player_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_player_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigratePlayerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigratePlayerKeys = accounts.into();
+    let ix = migrate_player_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_player_invoke_signed(
+    accounts: MigratePlayerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_player_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_player_verify_account_keys(
+    accounts: MigratePlayerAccounts<'_, '_>,
+    keys: MigratePlayerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.payer.key, keys.payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_player_verify_writable_privileges<'me, 'info>(
+    accounts: MigratePlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account, accounts.payer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_player_verify_signer_privileges<'me, 'info>(
+    accounts: MigratePlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_player_verify_account_privileges<'me, 'info>(
+    accounts: MigratePlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_player_verify_writable_privileges(accounts)?;
+    migrate_player_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const GRANT_ITEM_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct GrantItemAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub inventory: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GrantItemKeys {
+    pub player_account: Pubkey,
+    pub inventory: Pubkey,
+    pub game_config: Pubkey,
+    pub item_registry: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<GrantItemAccounts<'_, '_>> for GrantItemKeys {
+    fn from(accounts: GrantItemAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            inventory: *accounts.inventory.key,
+            game_config: *accounts.game_config.key,
+            item_registry: *accounts.item_registry.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<GrantItemKeys> for [AccountMeta; GRANT_ITEM_IX_ACCOUNTS_LEN] {
+    fn from(keys: GrantItemKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.inventory,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; GRANT_ITEM_IX_ACCOUNTS_LEN]> for GrantItemKeys {
+    fn from(pubkeys: [Pubkey; GRANT_ITEM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            inventory: pubkeys[1],
+            game_config: pubkeys[2],
+            item_registry: pubkeys[3],
+            authority_registry: pubkeys[4],
+            admin: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<GrantItemAccounts<'_, 'info>>
+for [AccountInfo<'info>; GRANT_ITEM_IX_ACCOUNTS_LEN] {
+    fn from(accounts: GrantItemAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.inventory.clone(),
+            accounts.game_config.clone(),
+            accounts.item_registry.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; GRANT_ITEM_IX_ACCOUNTS_LEN]>
+for GrantItemAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; GRANT_ITEM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            inventory: &arr[1],
+            game_config: &arr[2],
+            item_registry: &arr[3],
+            authority_registry: &arr[4],
+            admin: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const GRANT_ITEM_IX_DISCM: [u8; 8] = [147, 67, 169, 136, 213, 53, 88, 153];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantItemIxArgs {
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrantItemIxData(pub GrantItemIxArgs);
+impl From<GrantItemIxArgs> for GrantItemIxData {
+    fn from(args: GrantItemIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl GrantItemIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GRANT_ITEM_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        GRANT_ITEM_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(GrantItemIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GRANT_ITEM_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn grant_item_ix_with_program_id(
+    program_id: Pubkey,
+    keys: GrantItemKeys,
+    args: GrantItemIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; GRANT_ITEM_IX_ACCOUNTS_LEN] = keys.into();
+    let data: GrantItemIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn grant_item_ix(
+    keys: GrantItemKeys,
+    args: GrantItemIxArgs,
+) -> std::io::Result<Instruction> {
+    grant_item_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn grant_item_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: GrantItemAccounts<'_, '_>,
+    args: GrantItemIxArgs,
+) -> ProgramResult {
+    let keys: GrantItemKeys = accounts.into();
+    let ix = grant_item_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn grant_item_invoke(
+    accounts: GrantItemAccounts<'_, '_>,
+    args: GrantItemIxArgs,
+) -> ProgramResult {
+    grant_item_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn grant_item_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: GrantItemAccounts<'_, '_>,
+    args: GrantItemIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: GrantItemKeys = accounts.into();
+    let ix = grant_item_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn grant_item_invoke_signed(
+    accounts: GrantItemAccounts<'_, '_>,
+    args: GrantItemIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    grant_item_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn grant_item_verify_account_keys(
+    accounts: GrantItemAccounts<'_, '_>,
+    keys: GrantItemKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.inventory.key, keys.inventory),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn grant_item_verify_writable_privileges<'me, 'info>(
+    accounts: GrantItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.inventory, accounts.admin] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn grant_item_verify_signer_privileges<'me, 'info>(
+    accounts: GrantItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn grant_item_verify_account_privileges<'me, 'info>(
+    accounts: GrantItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    grant_item_verify_writable_privileges(accounts)?;
+    grant_item_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REVOKE_ITEM_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct RevokeItemAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub inventory: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RevokeItemKeys {
+    pub player_account: Pubkey,
+    pub inventory: Pubkey,
+    pub game_config: Pubkey,
+    pub item_registry: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<RevokeItemAccounts<'_, '_>> for RevokeItemKeys {
+    fn from(accounts: RevokeItemAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            inventory: *accounts.inventory.key,
+            game_config: *accounts.game_config.key,
+            item_registry: *accounts.item_registry.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<RevokeItemKeys> for [AccountMeta; REVOKE_ITEM_IX_ACCOUNTS_LEN] {
+    fn from(keys: RevokeItemKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.inventory,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REVOKE_ITEM_IX_ACCOUNTS_LEN]> for RevokeItemKeys {
+    fn from(pubkeys: [Pubkey; REVOKE_ITEM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            inventory: pubkeys[1],
+            game_config: pubkeys[2],
+            item_registry: pubkeys[3],
+            authority_registry: pubkeys[4],
+            admin: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<RevokeItemAccounts<'_, 'info>>
+for [AccountInfo<'info>; REVOKE_ITEM_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeItemAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.inventory.clone(),
+            accounts.game_config.clone(),
+            accounts.item_registry.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_ITEM_IX_ACCOUNTS_LEN]>
+for RevokeItemAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; REVOKE_ITEM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            inventory: &arr[1],
+            game_config: &arr[2],
+            item_registry: &arr[3],
+            authority_registry: &arr[4],
+            admin: &arr[5],
+        }
+    }
+}
+pub const REVOKE_ITEM_IX_DISCM: [u8; 8] = [175, 1, 228, 133, 101, 226, 3, 39];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevokeItemIxArgs {
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevokeItemIxData(pub RevokeItemIxArgs);
+impl From<RevokeItemIxArgs> for RevokeItemIxData {
+    fn from(args: RevokeItemIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RevokeItemIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REVOKE_ITEM_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_ITEM_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(RevokeItemIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REVOKE_ITEM_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn revoke_item_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RevokeItemKeys,
+    args: RevokeItemIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REVOKE_ITEM_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RevokeItemIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn revoke_item_ix(
+    keys: RevokeItemKeys,
+    args: RevokeItemIxArgs,
+) -> std::io::Result<Instruction> {
+    revoke_item_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn revoke_item_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeItemAccounts<'_, '_>,
+    args: RevokeItemIxArgs,
+) -> ProgramResult {
+    let keys: RevokeItemKeys = accounts.into();
+    let ix = revoke_item_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn revoke_item_invoke(
+    accounts: RevokeItemAccounts<'_, '_>,
+    args: RevokeItemIxArgs,
+) -> ProgramResult {
+    revoke_item_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn revoke_item_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeItemAccounts<'_, '_>,
+    args: RevokeItemIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RevokeItemKeys = accounts.into();
+    let ix = revoke_item_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn revoke_item_invoke_signed(
+    accounts: RevokeItemAccounts<'_, '_>,
+    args: RevokeItemIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    revoke_item_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn revoke_item_verify_account_keys(
+    accounts: RevokeItemAccounts<'_, '_>,
+    keys: RevokeItemKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.inventory.key, keys.inventory),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_item_verify_writable_privileges<'me, 'info>(
+    accounts: RevokeItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account, accounts.inventory] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_item_verify_signer_privileges<'me, 'info>(
+    accounts: RevokeItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_item_verify_account_privileges<'me, 'info>(
+    accounts: RevokeItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    revoke_item_verify_writable_privileges(accounts)?;
+    revoke_item_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const EQUIP_ITEM_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct EquipItemAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub inventory: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EquipItemKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub inventory: Pubkey,
+    pub item_registry: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<EquipItemAccounts<'_, '_>> for EquipItemKeys {
+    fn from(accounts: EquipItemAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            inventory: *accounts.inventory.key,
+            item_registry: *accounts.item_registry.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<EquipItemKeys> for [AccountMeta; EQUIP_ITEM_IX_ACCOUNTS_LEN] {
+    fn from(keys: EquipItemKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.inventory,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; EQUIP_ITEM_IX_ACCOUNTS_LEN]> for EquipItemKeys {
+    fn from(pubkeys: [Pubkey; EQUIP_ITEM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            inventory: pubkeys[2],
+            item_registry: pubkeys[3],
+            session: pubkeys[4],
+            signer: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<EquipItemAccounts<'_, 'info>>
+for [AccountInfo<'info>; EQUIP_ITEM_IX_ACCOUNTS_LEN] {
+    fn from(accounts: EquipItemAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.inventory.clone(),
+            accounts.item_registry.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; EQUIP_ITEM_IX_ACCOUNTS_LEN]>
+for EquipItemAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; EQUIP_ITEM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            inventory: &arr[2],
+            item_registry: &arr[3],
+            session: &arr[4],
+            signer: &arr[5],
+        }
+    }
+}
+pub const EQUIP_ITEM_IX_DISCM: [u8; 8] = [38, 155, 16, 165, 146, 31, 4, 255];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EquipItemIxArgs {
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquipItemIxData(pub EquipItemIxArgs);
+impl From<EquipItemIxArgs> for EquipItemIxData {
+    fn from(args: EquipItemIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl EquipItemIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != EQUIP_ITEM_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        EQUIP_ITEM_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(EquipItemIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&EQUIP_ITEM_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn equip_item_ix_with_program_id(
+    program_id: Pubkey,
+    keys: EquipItemKeys,
+    args: EquipItemIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; EQUIP_ITEM_IX_ACCOUNTS_LEN] = keys.into();
+    let data: EquipItemIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn equip_item_ix(
+    keys: EquipItemKeys,
+    args: EquipItemIxArgs,
+) -> std::io::Result<Instruction> {
+    equip_item_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn equip_item_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: EquipItemAccounts<'_, '_>,
+    args: EquipItemIxArgs,
+) -> ProgramResult {
+    let keys: EquipItemKeys = accounts.into();
+    let ix = equip_item_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn equip_item_invoke(
+    accounts: EquipItemAccounts<'_, '_>,
+    args: EquipItemIxArgs,
+) -> ProgramResult {
+    equip_item_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn equip_item_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: EquipItemAccounts<'_, '_>,
+    args: EquipItemIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: EquipItemKeys = accounts.into();
+    let ix = equip_item_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn equip_item_invoke_signed(
+    accounts: EquipItemAccounts<'_, '_>,
+    args: EquipItemIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    equip_item_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn equip_item_verify_account_keys(
+    accounts: EquipItemAccounts<'_, '_>,
+    keys: EquipItemKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.inventory.key, keys.inventory),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn equip_item_verify_writable_privileges<'me, 'info>(
+    accounts: EquipItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn equip_item_verify_signer_privileges<'me, 'info>(
+    accounts: EquipItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn equip_item_verify_account_privileges<'me, 'info>(
+    accounts: EquipItemAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    equip_item_verify_writable_privileges(accounts)?;
+    equip_item_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetItemDefinitionAccounts<'me, 'info> {
+    pub item_registry: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetItemDefinitionKeys {
+    pub item_registry: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<SetItemDefinitionAccounts<'_, '_>> for SetItemDefinitionKeys {
+    fn from(accounts: SetItemDefinitionAccounts) -> Self {
+        Self {
+            item_registry: *accounts.item_registry.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetItemDefinitionKeys> for [AccountMeta; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetItemDefinitionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN]> for SetItemDefinitionKeys {
+    fn from(pubkeys: [Pubkey; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            item_registry: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetItemDefinitionAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetItemDefinitionAccounts<'_, 'info>) -> Self {
+        [
+            accounts.item_registry.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN]>
+for SetItemDefinitionAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            item_registry: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_ITEM_DEFINITION_IX_DISCM: [u8; 8] = [26, 57, 232, 12, 33, 70, 186, 8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetItemDefinitionIxArgs {
+    pub item: ItemDefinition,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetItemDefinitionIxData(pub SetItemDefinitionIxArgs);
+impl From<SetItemDefinitionIxArgs> for SetItemDefinitionIxData {
+    fn from(args: SetItemDefinitionIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetItemDefinitionIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_ITEM_DEFINITION_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_ITEM_DEFINITION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetItemDefinitionIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_ITEM_DEFINITION_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_item_definition_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetItemDefinitionKeys,
+    args: SetItemDefinitionIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_ITEM_DEFINITION_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetItemDefinitionIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_item_definition_ix(
+    keys: SetItemDefinitionKeys,
+    args: SetItemDefinitionIxArgs,
+) -> std::io::Result<Instruction> {
+    set_item_definition_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_item_definition_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetItemDefinitionAccounts<'_, '_>,
+    args: SetItemDefinitionIxArgs,
+) -> ProgramResult {
+    let keys: SetItemDefinitionKeys = accounts.into();
+    let ix = set_item_definition_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_item_definition_invoke(
+    accounts: SetItemDefinitionAccounts<'_, '_>,
+    args: SetItemDefinitionIxArgs,
+) -> ProgramResult {
+    set_item_definition_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_item_definition_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetItemDefinitionAccounts<'_, '_>,
+    args: SetItemDefinitionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetItemDefinitionKeys = accounts.into();
+    let ix = set_item_definition_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_item_definition_invoke_signed(
+    accounts: SetItemDefinitionAccounts<'_, '_>,
+    args: SetItemDefinitionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_item_definition_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_item_definition_verify_account_keys(
+    accounts: SetItemDefinitionAccounts<'_, '_>,
+    keys: SetItemDefinitionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_item_definition_verify_writable_privileges<'me, 'info>(
+    accounts: SetItemDefinitionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.item_registry, accounts.admin] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_item_definition_verify_signer_privileges<'me, 'info>(
+    accounts: SetItemDefinitionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_item_definition_verify_account_privileges<'me, 'info>(
+    accounts: SetItemDefinitionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_item_definition_verify_writable_privileges(accounts)?;
+    set_item_definition_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveItemDefinitionAccounts<'me, 'info> {
+    pub item_registry: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RemoveItemDefinitionKeys {
+    pub item_registry: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<RemoveItemDefinitionAccounts<'_, '_>> for RemoveItemDefinitionKeys {
+    fn from(accounts: RemoveItemDefinitionAccounts) -> Self {
+        Self {
+            item_registry: *accounts.item_registry.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<RemoveItemDefinitionKeys>
+for [AccountMeta; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveItemDefinitionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN]>
+for RemoveItemDefinitionKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            item_registry: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<RemoveItemDefinitionAccounts<'_, 'info>>
+for [AccountInfo<'info>; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RemoveItemDefinitionAccounts<'_, 'info>) -> Self {
+        [
+            accounts.item_registry.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN]>
+for RemoveItemDefinitionAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            item_registry: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+        }
+    }
+}
+pub const REMOVE_ITEM_DEFINITION_IX_DISCM: [u8; 8] = [56, 29, 16, 171, 49, 244, 171, 92];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveItemDefinitionIxArgs {
+    pub item_type: PremiumItemType,
+    pub item_id: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveItemDefinitionIxData(pub RemoveItemDefinitionIxArgs);
+impl From<RemoveItemDefinitionIxArgs> for RemoveItemDefinitionIxData {
+    fn from(args: RemoveItemDefinitionIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveItemDefinitionIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REMOVE_ITEM_DEFINITION_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_ITEM_DEFINITION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(RemoveItemDefinitionIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REMOVE_ITEM_DEFINITION_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_item_definition_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveItemDefinitionKeys,
+    args: RemoveItemDefinitionIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_ITEM_DEFINITION_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveItemDefinitionIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_item_definition_ix(
+    keys: RemoveItemDefinitionKeys,
+    args: RemoveItemDefinitionIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_item_definition_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_item_definition_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveItemDefinitionAccounts<'_, '_>,
+    args: RemoveItemDefinitionIxArgs,
+) -> ProgramResult {
+    let keys: RemoveItemDefinitionKeys = accounts.into();
+    let ix = remove_item_definition_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_item_definition_invoke(
+    accounts: RemoveItemDefinitionAccounts<'_, '_>,
+    args: RemoveItemDefinitionIxArgs,
+) -> ProgramResult {
+    remove_item_definition_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_item_definition_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveItemDefinitionAccounts<'_, '_>,
+    args: RemoveItemDefinitionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveItemDefinitionKeys = accounts.into();
+    let ix = remove_item_definition_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_item_definition_invoke_signed(
+    accounts: RemoveItemDefinitionAccounts<'_, '_>,
+    args: RemoveItemDefinitionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_item_definition_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn remove_item_definition_verify_account_keys(
+    accounts: RemoveItemDefinitionAccounts<'_, '_>,
+    keys: RemoveItemDefinitionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_item_definition_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveItemDefinitionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.item_registry] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_item_definition_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveItemDefinitionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_item_definition_verify_account_privileges<'me, 'info>(
+    accounts: RemoveItemDefinitionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_item_definition_verify_writable_privileges(accounts)?;
+    remove_item_definition_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CLOSE_PLAYER_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct ClosePlayerAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub username_account: &'me AccountInfo<'info>,
+    pub inventory: &'me AccountInfo<'info>,
+    pub level_up_request: &'me AccountInfo<'info>,
+    pub chest_open_request: &'me AccountInfo<'info>,
+    pub recipient: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClosePlayerKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub username_account: Pubkey,
+    pub inventory: Pubkey,
+    pub level_up_request: Pubkey,
+    pub chest_open_request: Pubkey,
+    pub recipient: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<ClosePlayerAccounts<'_, '_>> for ClosePlayerKeys {
+    fn from(accounts: ClosePlayerAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            username_account: *accounts.username_account.key,
+            inventory: *accounts.inventory.key,
+            level_up_request: *accounts.level_up_request.key,
+            chest_open_request: *accounts.chest_open_request.key,
+            recipient: *accounts.recipient.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<ClosePlayerKeys> for [AccountMeta; CLOSE_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(keys: ClosePlayerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.username_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.inventory,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.level_up_request,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.chest_open_request,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.recipient,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CLOSE_PLAYER_IX_ACCOUNTS_LEN]> for ClosePlayerKeys {
+    fn from(pubkeys: [Pubkey; CLOSE_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            username_account: pubkeys[2],
+            inventory: pubkeys[3],
+            level_up_request: pubkeys[4],
+            chest_open_request: pubkeys[5],
+            recipient: pubkeys[6],
+            signer: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<ClosePlayerAccounts<'_, 'info>>
+for [AccountInfo<'info>; CLOSE_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ClosePlayerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.username_account.clone(),
+            accounts.inventory.clone(),
+            accounts.level_up_request.clone(),
+            accounts.chest_open_request.clone(),
+            accounts.recipient.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLOSE_PLAYER_IX_ACCOUNTS_LEN]>
+for ClosePlayerAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; CLOSE_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            username_account: &arr[2],
+            inventory: &arr[3],
+            level_up_request: &arr[4],
+            chest_open_request: &arr[5],
+            recipient: &arr[6],
+            signer: &arr[7],
+        }
+    }
+}
+pub const CLOSE_PLAYER_IX_DISCM: [u8; 8] = [225, 227, 204, 63, 32, 122, 58, 227];
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClosePlayerIxData;
+impl ClosePlayerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CLOSE_PLAYER_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CLOSE_PLAYER_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CLOSE_PLAYER_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn close_player_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ClosePlayerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CLOSE_PLAYER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ClosePlayerIxData.try_to_vec()?,
+    })
+}
+pub fn close_player_ix(keys: ClosePlayerKeys) -> std::io::Result<Instruction> {
+    close_player_ix_with_program_id(crate::ID, keys)
+}
+pub fn close_player_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ClosePlayerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: ClosePlayerKeys = accounts.into();
+    let ix = close_player_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn close_player_invoke(accounts: ClosePlayerAccounts<'_, '_>) -> ProgramResult {
+    close_player_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn close_player_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ClosePlayerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ClosePlayerKeys = accounts.into();
+    let ix = close_player_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn close_player_invoke_signed(
+    accounts: ClosePlayerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    close_player_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn close_player_verify_account_keys(
+    accounts: ClosePlayerAccounts<'_, '_>,
+    keys: ClosePlayerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.username_account.key, keys.username_account),
+        (*accounts.inventory.key, keys.inventory),
+        (*accounts.level_up_request.key, keys.level_up_request),
+        (*accounts.chest_open_request.key, keys.chest_open_request),
+        (*accounts.recipient.key, keys.recipient),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn close_player_verify_writable_privileges<'me, 'info>(
+    accounts: ClosePlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.username_account,
+        accounts.inventory,
+        accounts.level_up_request,
+        accounts.chest_open_request,
+        accounts.recipient,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn close_player_verify_signer_privileges<'me, 'info>(
+    accounts: ClosePlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn close_player_verify_account_privileges<'me, 'info>(
+    accounts: ClosePlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    close_player_verify_writable_privileges(accounts)?;
+    close_player_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_IN_MATCH_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetInMatchAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetInMatchKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<SetInMatchAccounts<'_, '_>> for SetInMatchKeys {
+    fn from(accounts: SetInMatchAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<SetInMatchKeys> for [AccountMeta; SET_IN_MATCH_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetInMatchKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_IN_MATCH_IX_ACCOUNTS_LEN]> for SetInMatchKeys {
+    fn from(pubkeys: [Pubkey; SET_IN_MATCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetInMatchAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_IN_MATCH_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetInMatchAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_IN_MATCH_IX_ACCOUNTS_LEN]>
+for SetInMatchAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SET_IN_MATCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+        }
+    }
+}
+pub const SET_IN_MATCH_IX_DISCM: [u8; 8] = [139, 165, 87, 165, 115, 31, 68, 97];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetInMatchIxArgs {
+    pub in_match: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetInMatchIxData(pub SetInMatchIxArgs);
+impl From<SetInMatchIxArgs> for SetInMatchIxData {
+    fn from(args: SetInMatchIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetInMatchIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_IN_MATCH_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_IN_MATCH_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetInMatchIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_IN_MATCH_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_in_match_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetInMatchKeys,
+    args: SetInMatchIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_IN_MATCH_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetInMatchIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_in_match_ix(
+    keys: SetInMatchKeys,
+    args: SetInMatchIxArgs,
+) -> std::io::Result<Instruction> {
+    set_in_match_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_in_match_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetInMatchAccounts<'_, '_>,
+    args: SetInMatchIxArgs,
+) -> ProgramResult {
+    let keys: SetInMatchKeys = accounts.into();
+    let ix = set_in_match_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_in_match_invoke(
+    accounts: SetInMatchAccounts<'_, '_>,
+    args: SetInMatchIxArgs,
+) -> ProgramResult {
+    set_in_match_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_in_match_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetInMatchAccounts<'_, '_>,
+    args: SetInMatchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetInMatchKeys = accounts.into();
+    let ix = set_in_match_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_in_match_invoke_signed(
+    accounts: SetInMatchAccounts<'_, '_>,
+    args: SetInMatchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_in_match_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_in_match_verify_account_keys(
+    accounts: SetInMatchAccounts<'_, '_>,
+    keys: SetInMatchKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_in_match_verify_writable_privileges<'me, 'info>(
+    accounts: SetInMatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_in_match_verify_signer_privileges<'me, 'info>(
+    accounts: SetInMatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_in_match_verify_account_privileges<'me, 'info>(
+    accounts: SetInMatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_in_match_verify_writable_privileges(accounts)?;
+    set_in_match_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct GrantCharacterSlotsAccounts<'me, 'info> {
+    pub wallet_account: &'me AccountInfo<'info>,
+    pub owner: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GrantCharacterSlotsKeys {
+    pub wallet_account: Pubkey,
+    pub owner: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<GrantCharacterSlotsAccounts<'_, '_>> for GrantCharacterSlotsKeys {
+    fn from(accounts: GrantCharacterSlotsAccounts) -> Self {
+        Self {
+            wallet_account: *accounts.wallet_account.key,
+            owner: *accounts.owner.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<GrantCharacterSlotsKeys>
+for [AccountMeta; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN] {
+    fn from(keys: GrantCharacterSlotsKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.wallet_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN]> for GrantCharacterSlotsKeys {
+    fn from(pubkeys: [Pubkey; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            wallet_account: pubkeys[0],
+            owner: pubkeys[1],
+            game_config: pubkeys[2],
+            authority_registry: pubkeys[3],
+            admin: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<GrantCharacterSlotsAccounts<'_, 'info>>
+for [AccountInfo<'info>; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN] {
+    fn from(accounts: GrantCharacterSlotsAccounts<'_, 'info>) -> Self {
+        [
+            accounts.wallet_account.clone(),
+            accounts.owner.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN]>
+for GrantCharacterSlotsAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            wallet_account: &arr[0],
+            owner: &arr[1],
+            game_config: &arr[2],
+            authority_registry: &arr[3],
+            admin: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const GRANT_CHARACTER_SLOTS_IX_DISCM: [u8; 8] = [83, 57, 34, 20, 135, 34, 219, 58];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantCharacterSlotsIxArgs {
+    pub count: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrantCharacterSlotsIxData(pub GrantCharacterSlotsIxArgs);
+impl From<GrantCharacterSlotsIxArgs> for GrantCharacterSlotsIxData {
+    fn from(args: GrantCharacterSlotsIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl GrantCharacterSlotsIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GRANT_CHARACTER_SLOTS_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        GRANT_CHARACTER_SLOTS_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(GrantCharacterSlotsIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GRANT_CHARACTER_SLOTS_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn grant_character_slots_ix_with_program_id(
+    program_id: Pubkey,
+    keys: GrantCharacterSlotsKeys,
+    args: GrantCharacterSlotsIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; GRANT_CHARACTER_SLOTS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: GrantCharacterSlotsIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn grant_character_slots_ix(
+    keys: GrantCharacterSlotsKeys,
+    args: GrantCharacterSlotsIxArgs,
+) -> std::io::Result<Instruction> {
+    grant_character_slots_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn grant_character_slots_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: GrantCharacterSlotsAccounts<'_, '_>,
+    args: GrantCharacterSlotsIxArgs,
+) -> ProgramResult {
+    let keys: GrantCharacterSlotsKeys = accounts.into();
+    let ix = grant_character_slots_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn grant_character_slots_invoke(
+    accounts: GrantCharacterSlotsAccounts<'_, '_>,
+    args: GrantCharacterSlotsIxArgs,
+) -> ProgramResult {
+    grant_character_slots_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn grant_character_slots_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: GrantCharacterSlotsAccounts<'_, '_>,
+    args: GrantCharacterSlotsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: GrantCharacterSlotsKeys = accounts.into();
+    let ix = grant_character_slots_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn grant_character_slots_invoke_signed(
+    accounts: GrantCharacterSlotsAccounts<'_, '_>,
+    args: GrantCharacterSlotsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    grant_character_slots_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn grant_character_slots_verify_account_keys(
+    accounts: GrantCharacterSlotsAccounts<'_, '_>,
+    keys: GrantCharacterSlotsKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.wallet_account.key, keys.wallet_account),
+        (*accounts.owner.key, keys.owner),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn grant_character_slots_verify_writable_privileges<'me, 'info>(
+    accounts: GrantCharacterSlotsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.wallet_account, accounts.admin] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn grant_character_slots_verify_signer_privileges<'me, 'info>(
+    accounts: GrantCharacterSlotsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn grant_character_slots_verify_account_privileges<'me, 'info>(
+    accounts: GrantCharacterSlotsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    grant_character_slots_verify_writable_privileges(accounts)?;
+    grant_character_slots_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_USERNAME_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetUsernameAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub username_account: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetUsernameKeys {
+    pub player_account: Pubkey,
+    pub username_account: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<SetUsernameAccounts<'_, '_>> for SetUsernameKeys {
+    fn from(accounts: SetUsernameAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            username_account: *accounts.username_account.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetUsernameKeys> for [AccountMeta; SET_USERNAME_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetUsernameKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.username_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_USERNAME_IX_ACCOUNTS_LEN]> for SetUsernameKeys {
+    fn from(pubkeys: [Pubkey; SET_USERNAME_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            username_account: pubkeys[1],
+            signer: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetUsernameAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_USERNAME_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetUsernameAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.username_account.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_USERNAME_IX_ACCOUNTS_LEN]>
+for SetUsernameAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SET_USERNAME_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            username_account: &arr[1],
+            signer: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const SET_USERNAME_IX_DISCM: [u8; 8] = [103, 131, 39, 36, 30, 228, 75, 132];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetUsernameIxArgs {
+    pub name: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetUsernameIxData(pub SetUsernameIxArgs);
+impl From<SetUsernameIxArgs> for SetUsernameIxData {
+    fn from(args: SetUsernameIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetUsernameIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_USERNAME_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_USERNAME_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetUsernameIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_USERNAME_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_username_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetUsernameKeys,
+    args: SetUsernameIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_USERNAME_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetUsernameIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_username_ix(
+    keys: SetUsernameKeys,
+    args: SetUsernameIxArgs,
+) -> std::io::Result<Instruction> {
+    set_username_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_username_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetUsernameAccounts<'_, '_>,
+    args: SetUsernameIxArgs,
+) -> ProgramResult {
+    let keys: SetUsernameKeys = accounts.into();
+    let ix = set_username_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_username_invoke(
+    accounts: SetUsernameAccounts<'_, '_>,
+    args: SetUsernameIxArgs,
+) -> ProgramResult {
+    set_username_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_username_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetUsernameAccounts<'_, '_>,
+    args: SetUsernameIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetUsernameKeys = accounts.into();
+    let ix = set_username_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_username_invoke_signed(
+    accounts: SetUsernameAccounts<'_, '_>,
+    args: SetUsernameIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_username_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_username_verify_account_keys(
+    accounts: SetUsernameAccounts<'_, '_>,
+    keys: SetUsernameKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.username_account.key, keys.username_account),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_username_verify_writable_privileges<'me, 'info>(
+    accounts: SetUsernameAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.username_account,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_username_verify_signer_privileges<'me, 'info>(
+    accounts: SetUsernameAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_username_verify_account_privileges<'me, 'info>(
+    accounts: SetUsernameAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_username_verify_writable_privileges(accounts)?;
+    set_username_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CHANGE_USERNAME_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct ChangeUsernameAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub old_username_account: &'me AccountInfo<'info>,
+    pub new_username_account: &'me AccountInfo<'info>,
+    pub fee_recipient: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChangeUsernameKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub old_username_account: Pubkey,
+    pub new_username_account: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<ChangeUsernameAccounts<'_, '_>> for ChangeUsernameKeys {
+    fn from(accounts: ChangeUsernameAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            old_username_account: *accounts.old_username_account.key,
+            new_username_account: *accounts.new_username_account.key,
+            fee_recipient: *accounts.fee_recipient.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<ChangeUsernameKeys> for [AccountMeta; CHANGE_USERNAME_IX_ACCOUNTS_LEN] {
+    fn from(keys: ChangeUsernameKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.old_username_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_username_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fee_recipient,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CHANGE_USERNAME_IX_ACCOUNTS_LEN]> for ChangeUsernameKeys {
+    fn from(pubkeys: [Pubkey; CHANGE_USERNAME_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            old_username_account: pubkeys[2],
+            new_username_account: pubkeys[3],
+            fee_recipient: pubkeys[4],
+            signer: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<ChangeUsernameAccounts<'_, 'info>>
+for [AccountInfo<'info>; CHANGE_USERNAME_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ChangeUsernameAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.old_username_account.clone(),
+            accounts.new_username_account.clone(),
+            accounts.fee_recipient.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_USERNAME_IX_ACCOUNTS_LEN]>
+for ChangeUsernameAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; CHANGE_USERNAME_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            old_username_account: &arr[2],
+            new_username_account: &arr[3],
+            fee_recipient: &arr[4],
+            signer: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const CHANGE_USERNAME_IX_DISCM: [u8; 8] = [122, 228, 203, 143, 188, 195, 189, 130];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeUsernameIxArgs {
+    pub name: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeUsernameIxData(pub ChangeUsernameIxArgs);
+impl From<ChangeUsernameIxArgs> for ChangeUsernameIxData {
+    fn from(args: ChangeUsernameIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ChangeUsernameIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CHANGE_USERNAME_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CHANGE_USERNAME_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ChangeUsernameIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CHANGE_USERNAME_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn change_username_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ChangeUsernameKeys,
+    args: ChangeUsernameIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CHANGE_USERNAME_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ChangeUsernameIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn change_username_ix(
+    keys: ChangeUsernameKeys,
+    args: ChangeUsernameIxArgs,
+) -> std::io::Result<Instruction> {
+    change_username_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn change_username_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ChangeUsernameAccounts<'_, '_>,
+    args: ChangeUsernameIxArgs,
+) -> ProgramResult {
+    let keys: ChangeUsernameKeys = accounts.into();
+    let ix = change_username_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn change_username_invoke(
+    accounts: ChangeUsernameAccounts<'_, '_>,
+    args: ChangeUsernameIxArgs,
+) -> ProgramResult {
+    change_username_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn change_username_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ChangeUsernameAccounts<'_, '_>,
+    args: ChangeUsernameIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ChangeUsernameKeys = accounts.into();
+    let ix = change_username_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn change_username_invoke_signed(
+    accounts: ChangeUsernameAccounts<'_, '_>,
+    args: ChangeUsernameIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    change_username_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn change_username_verify_account_keys(
+    accounts: ChangeUsernameAccounts<'_, '_>,
+    keys: ChangeUsernameKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.old_username_account.key, keys.old_username_account),
+        (*accounts.new_username_account.key, keys.new_username_account),
+        (*accounts.fee_recipient.key, keys.fee_recipient),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn change_username_verify_writable_privileges<'me, 'info>(
+    accounts: ChangeUsernameAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.old_username_account,
+        accounts.new_username_account,
+        accounts.fee_recipient,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn change_username_verify_signer_privileges<'me, 'info>(
+    accounts: ChangeUsernameAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn change_username_verify_account_privileges<'me, 'info>(
+    accounts: ChangeUsernameAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    change_username_verify_writable_privileges(accounts)?;
+    change_username_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PAUSED_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPausedAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetPausedKeys {
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<SetPausedAccounts<'_, '_>> for SetPausedKeys {
+    fn from(accounts: SetPausedAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<SetPausedKeys> for [AccountMeta; SET_PAUSED_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetPausedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PAUSED_IX_ACCOUNTS_LEN]> for SetPausedKeys {
+    fn from(pubkeys: [Pubkey; SET_PAUSED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority_registry: pubkeys[1],
+            admin: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetPausedAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_PAUSED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetPausedAccounts<'_, 'info>) -> Self {
+        [
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PAUSED_IX_ACCOUNTS_LEN]>
+for SetPausedAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SET_PAUSED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority_registry: &arr[1],
+            admin: &arr[2],
+        }
+    }
+}
+pub const SET_PAUSED_IX_DISCM: [u8; 8] = [91, 60, 125, 192, 176, 225, 166, 218];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPausedIxArgs {
+    pub paused: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPausedIxData(pub SetPausedIxArgs);
+impl From<SetPausedIxArgs> for SetPausedIxData {
+    fn from(args: SetPausedIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetPausedIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_PAUSED_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_PAUSED_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetPausedIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_PAUSED_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_paused_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetPausedKeys,
+    args: SetPausedIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PAUSED_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetPausedIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_paused_ix(
+    keys: SetPausedKeys,
+    args: SetPausedIxArgs,
+) -> std::io::Result<Instruction> {
+    set_paused_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_paused_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPausedAccounts<'_, '_>,
+    args: SetPausedIxArgs,
+) -> ProgramResult {
+    let keys: SetPausedKeys = accounts.into();
+    let ix = set_paused_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_paused_invoke(
+    accounts: SetPausedAccounts<'_, '_>,
+    args: SetPausedIxArgs,
+) -> ProgramResult {
+    set_paused_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_paused_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPausedAccounts<'_, '_>,
+    args: SetPausedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetPausedKeys = accounts.into();
+    let ix = set_paused_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_paused_invoke_signed(
+    accounts: SetPausedAccounts<'_, '_>,
+    args: SetPausedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_paused_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_paused_verify_account_keys(
+    accounts: SetPausedAccounts<'_, '_>,
+    keys: SetPausedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_paused_verify_writable_privileges<'me, 'info>(
+    accounts: SetPausedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.game_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_paused_verify_signer_privileges<'me, 'info>(
+    accounts: SetPausedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_paused_verify_account_privileges<'me, 'info>(
+    accounts: SetPausedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_paused_verify_writable_privileges(accounts)?;
+    set_paused_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SUSPEND_PLAYER_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SuspendPlayerAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SuspendPlayerKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<SuspendPlayerAccounts<'_, '_>> for SuspendPlayerKeys {
+    fn from(accounts: SuspendPlayerAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<SuspendPlayerKeys> for [AccountMeta; SUSPEND_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(keys: SuspendPlayerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SUSPEND_PLAYER_IX_ACCOUNTS_LEN]> for SuspendPlayerKeys {
+    fn from(pubkeys: [Pubkey; SUSPEND_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SuspendPlayerAccounts<'_, 'info>>
+for [AccountInfo<'info>; SUSPEND_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SuspendPlayerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SUSPEND_PLAYER_IX_ACCOUNTS_LEN]>
+for SuspendPlayerAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SUSPEND_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+        }
+    }
+}
+pub const SUSPEND_PLAYER_IX_DISCM: [u8; 8] = [115, 44, 15, 162, 148, 136, 0, 151];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuspendPlayerIxArgs {
+    pub suspended_until: i64,
+    pub reason: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuspendPlayerIxData(pub SuspendPlayerIxArgs);
+impl From<SuspendPlayerIxArgs> for SuspendPlayerIxData {
+    fn from(args: SuspendPlayerIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SuspendPlayerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SUSPEND_PLAYER_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SUSPEND_PLAYER_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SuspendPlayerIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SUSPEND_PLAYER_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn suspend_player_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SuspendPlayerKeys,
+    args: SuspendPlayerIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SUSPEND_PLAYER_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SuspendPlayerIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn suspend_player_ix(
+    keys: SuspendPlayerKeys,
+    args: SuspendPlayerIxArgs,
+) -> std::io::Result<Instruction> {
+    suspend_player_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn suspend_player_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SuspendPlayerAccounts<'_, '_>,
+    args: SuspendPlayerIxArgs,
+) -> ProgramResult {
+    let keys: SuspendPlayerKeys = accounts.into();
+    let ix = suspend_player_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn suspend_player_invoke(
+    accounts: SuspendPlayerAccounts<'_, '_>,
+    args: SuspendPlayerIxArgs,
+) -> ProgramResult {
+    suspend_player_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn suspend_player_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SuspendPlayerAccounts<'_, '_>,
+    args: SuspendPlayerIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SuspendPlayerKeys = accounts.into();
+    let ix = suspend_player_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn suspend_player_invoke_signed(
+    accounts: SuspendPlayerAccounts<'_, '_>,
+    args: SuspendPlayerIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    suspend_player_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn suspend_player_verify_account_keys(
+    accounts: SuspendPlayerAccounts<'_, '_>,
+    keys: SuspendPlayerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn suspend_player_verify_writable_privileges<'me, 'info>(
+    accounts: SuspendPlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn suspend_player_verify_signer_privileges<'me, 'info>(
+    accounts: SuspendPlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn suspend_player_verify_account_privileges<'me, 'info>(
+    accounts: SuspendPlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    suspend_player_verify_writable_privileges(accounts)?;
+    suspend_player_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct UnsuspendPlayerAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnsuspendPlayerKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<UnsuspendPlayerAccounts<'_, '_>> for UnsuspendPlayerKeys {
+    fn from(accounts: UnsuspendPlayerAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<UnsuspendPlayerKeys> for [AccountMeta; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(keys: UnsuspendPlayerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN]> for UnsuspendPlayerKeys {
+    fn from(pubkeys: [Pubkey; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<UnsuspendPlayerAccounts<'_, 'info>>
+for [AccountInfo<'info>; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnsuspendPlayerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN]>
+for UnsuspendPlayerAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+        }
+    }
+}
+pub const UNSUSPEND_PLAYER_IX_DISCM: [u8; 8] = [99, 247, 42, 249, 117, 131, 195, 56];
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsuspendPlayerIxData;
+impl UnsuspendPlayerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != UNSUSPEND_PLAYER_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNSUSPEND_PLAYER_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&UNSUSPEND_PLAYER_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn unsuspend_player_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UnsuspendPlayerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; UNSUSPEND_PLAYER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: UnsuspendPlayerIxData.try_to_vec()?,
+    })
+}
+pub fn unsuspend_player_ix(keys: UnsuspendPlayerKeys) -> std::io::Result<Instruction> {
+    unsuspend_player_ix_with_program_id(crate::ID, keys)
+}
+pub fn unsuspend_player_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UnsuspendPlayerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: UnsuspendPlayerKeys = accounts.into();
+    let ix = unsuspend_player_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn unsuspend_player_invoke(
+    accounts: UnsuspendPlayerAccounts<'_, '_>,
+) -> ProgramResult {
+    unsuspend_player_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn unsuspend_player_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UnsuspendPlayerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UnsuspendPlayerKeys = accounts.into();
+    let ix = unsuspend_player_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn unsuspend_player_invoke_signed(
+    accounts: UnsuspendPlayerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    unsuspend_player_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn unsuspend_player_verify_account_keys(
+    accounts: UnsuspendPlayerAccounts<'_, '_>,
+    keys: UnsuspendPlayerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn unsuspend_player_verify_writable_privileges<'me, 'info>(
+    accounts: UnsuspendPlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn unsuspend_player_verify_signer_privileges<'me, 'info>(
+    accounts: UnsuspendPlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn unsuspend_player_verify_account_privileges<'me, 'info>(
+    accounts: UnsuspendPlayerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    unsuspend_player_verify_writable_privileges(accounts)?;
+    unsuspend_player_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_SESSION_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct CreateSessionAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub session_key: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CreateSessionKeys {
+    pub player_account: Pubkey,
+    pub session: Pubkey,
+    pub session_key: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<CreateSessionAccounts<'_, '_>> for CreateSessionKeys {
+    fn from(accounts: CreateSessionAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            session: *accounts.session.key,
+            session_key: *accounts.session_key.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<CreateSessionKeys> for [AccountMeta; CREATE_SESSION_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateSessionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.session_key,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_SESSION_IX_ACCOUNTS_LEN]> for CreateSessionKeys {
+    fn from(pubkeys: [Pubkey; CREATE_SESSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            session: pubkeys[1],
+            session_key: pubkeys[2],
+            signer: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<CreateSessionAccounts<'_, 'info>>
+for [AccountInfo<'info>; CREATE_SESSION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateSessionAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.session.clone(),
+            accounts.session_key.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_SESSION_IX_ACCOUNTS_LEN]>
+for CreateSessionAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; CREATE_SESSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            session: &arr[1],
+            session_key: &arr[2],
+            signer: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const CREATE_SESSION_IX_DISCM: [u8; 8] = [242, 193, 143, 179, 150, 25, 122, 227];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateSessionIxArgs {
+    pub expires_at: i64,
+    pub scope: u8,
+    pub top_up: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateSessionIxData(pub CreateSessionIxArgs);
+impl From<CreateSessionIxArgs> for CreateSessionIxData {
+    fn from(args: CreateSessionIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateSessionIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CREATE_SESSION_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_SESSION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateSessionIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CREATE_SESSION_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn create_session_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateSessionKeys,
+    args: CreateSessionIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_SESSION_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateSessionIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_session_ix(
+    keys: CreateSessionKeys,
+    args: CreateSessionIxArgs,
+) -> std::io::Result<Instruction> {
+    create_session_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn create_session_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateSessionAccounts<'_, '_>,
+    args: CreateSessionIxArgs,
+) -> ProgramResult {
+    let keys: CreateSessionKeys = accounts.into();
+    let ix = create_session_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn create_session_invoke(
+    accounts: CreateSessionAccounts<'_, '_>,
+    args: CreateSessionIxArgs,
+) -> ProgramResult {
+    create_session_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn create_session_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateSessionAccounts<'_, '_>,
+    args: CreateSessionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CreateSessionKeys = accounts.into();
+    let ix = create_session_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn create_session_invoke_signed(
+    accounts: CreateSessionAccounts<'_, '_>,
+    args: CreateSessionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    create_session_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn create_session_verify_account_keys(
+    accounts: CreateSessionAccounts<'_, '_>,
+    keys: CreateSessionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.session.key, keys.session),
+        (*accounts.session_key.key, keys.session_key),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn create_session_verify_writable_privileges<'me, 'info>(
+    accounts: CreateSessionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.session,
+        accounts.session_key,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn create_session_verify_signer_privileges<'me, 'info>(
+    accounts: CreateSessionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn create_session_verify_account_privileges<'me, 'info>(
+    accounts: CreateSessionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    create_session_verify_writable_privileges(accounts)?;
+    create_session_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REVOKE_SESSION_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct RevokeSessionAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub session: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RevokeSessionKeys {
+    pub player_account: Pubkey,
+    pub session: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<RevokeSessionAccounts<'_, '_>> for RevokeSessionKeys {
+    fn from(accounts: RevokeSessionAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            session: *accounts.session.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<RevokeSessionKeys> for [AccountMeta; REVOKE_SESSION_IX_ACCOUNTS_LEN] {
+    fn from(keys: RevokeSessionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.session,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REVOKE_SESSION_IX_ACCOUNTS_LEN]> for RevokeSessionKeys {
+    fn from(pubkeys: [Pubkey; REVOKE_SESSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            session: pubkeys[1],
+            signer: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<RevokeSessionAccounts<'_, 'info>>
+for [AccountInfo<'info>; REVOKE_SESSION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeSessionAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.session.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_SESSION_IX_ACCOUNTS_LEN]>
+for RevokeSessionAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; REVOKE_SESSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            session: &arr[1],
+            signer: &arr[2],
+        }
+    }
+}
+pub const REVOKE_SESSION_IX_DISCM: [u8; 8] = [86, 92, 198, 120, 144, 2, 7, 194];
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevokeSessionIxData;
+impl RevokeSessionIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REVOKE_SESSION_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_SESSION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REVOKE_SESSION_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn revoke_session_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RevokeSessionKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REVOKE_SESSION_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RevokeSessionIxData.try_to_vec()?,
+    })
+}
+pub fn revoke_session_ix(keys: RevokeSessionKeys) -> std::io::Result<Instruction> {
+    revoke_session_ix_with_program_id(crate::ID, keys)
+}
+pub fn revoke_session_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeSessionAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: RevokeSessionKeys = accounts.into();
+    let ix = revoke_session_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn revoke_session_invoke(accounts: RevokeSessionAccounts<'_, '_>) -> ProgramResult {
+    revoke_session_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn revoke_session_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeSessionAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RevokeSessionKeys = accounts.into();
+    let ix = revoke_session_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn revoke_session_invoke_signed(
+    accounts: RevokeSessionAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    revoke_session_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn revoke_session_verify_account_keys(
+    accounts: RevokeSessionAccounts<'_, '_>,
+    keys: RevokeSessionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.session.key, keys.session),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_session_verify_writable_privileges<'me, 'info>(
+    accounts: RevokeSessionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.session,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_session_verify_signer_privileges<'me, 'info>(
+    accounts: RevokeSessionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn revoke_session_verify_account_privileges<'me, 'info>(
+    accounts: RevokeSessionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    revoke_session_verify_writable_privileges(accounts)?;
+    revoke_session_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePlayerSponsoredAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub wallet_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub item_registry: &'me AccountInfo<'info>,
+    pub referrer: &'me AccountInfo<'info>,
+    pub player: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CreatePlayerSponsoredKeys {
+    pub player_account: Pubkey,
+    pub wallet_account: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub item_registry: Pubkey,
+    pub referrer: Pubkey,
+    pub player: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<CreatePlayerSponsoredAccounts<'_, '_>> for CreatePlayerSponsoredKeys {
+    fn from(accounts: CreatePlayerSponsoredAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            wallet_account: *accounts.wallet_account.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            item_registry: *accounts.item_registry.key,
+            referrer: *accounts.referrer.key,
+            player: *accounts.player.key,
+            payer: *accounts.payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<CreatePlayerSponsoredKeys>
+for [AccountMeta; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreatePlayerSponsoredKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.wallet_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.item_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.player,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN]>
+for CreatePlayerSponsoredKeys {
+    fn from(pubkeys: [Pubkey; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            wallet_account: pubkeys[1],
+            game_config: pubkeys[2],
+            authority_registry: pubkeys[3],
+            item_registry: pubkeys[4],
+            referrer: pubkeys[5],
+            player: pubkeys[6],
+            payer: pubkeys[7],
+            system_program: pubkeys[8],
+        }
+    }
+}
+impl<'info> From<CreatePlayerSponsoredAccounts<'_, 'info>>
+for [AccountInfo<'info>; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreatePlayerSponsoredAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.wallet_account.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.item_registry.clone(),
+            accounts.referrer.clone(),
+            accounts.player.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN]>
+for CreatePlayerSponsoredAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            player_account: &arr[0],
+            wallet_account: &arr[1],
+            game_config: &arr[2],
+            authority_registry: &arr[3],
+            item_registry: &arr[4],
+            referrer: &arr[5],
+            player: &arr[6],
+            payer: &arr[7],
+            system_program: &arr[8],
+        }
+    }
+}
+pub const CREATE_PLAYER_SPONSORED_IX_DISCM: [u8; 8] = [
+    222,
+    70,
+    77,
+    210,
+    11,
+    217,
+    117,
+    236,
+];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePlayerSponsoredIxArgs {
+    pub slot: u8,
+    pub active_class: u8,
+    pub active_weapon: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreatePlayerSponsoredIxData(pub CreatePlayerSponsoredIxArgs);
+impl From<CreatePlayerSponsoredIxArgs> for CreatePlayerSponsoredIxData {
+    fn from(args: CreatePlayerSponsoredIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreatePlayerSponsoredIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CREATE_PLAYER_SPONSORED_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_PLAYER_SPONSORED_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreatePlayerSponsoredIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CREATE_PLAYER_SPONSORED_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn create_player_sponsored_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreatePlayerSponsoredKeys,
+    args: CreatePlayerSponsoredIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_PLAYER_SPONSORED_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreatePlayerSponsoredIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_player_sponsored_ix(
+    keys: CreatePlayerSponsoredKeys,
+    args: CreatePlayerSponsoredIxArgs,
+) -> std::io::Result<Instruction> {
+    create_player_sponsored_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn create_player_sponsored_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreatePlayerSponsoredAccounts<'_, '_>,
+    args: CreatePlayerSponsoredIxArgs,
+) -> ProgramResult {
+    let keys: CreatePlayerSponsoredKeys = accounts.into();
+    let ix = create_player_sponsored_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn create_player_sponsored_invoke(
+    accounts: CreatePlayerSponsoredAccounts<'_, '_>,
+    args: CreatePlayerSponsoredIxArgs,
+) -> ProgramResult {
+    create_player_sponsored_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn create_player_sponsored_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreatePlayerSponsoredAccounts<'_, '_>,
+    args: CreatePlayerSponsoredIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CreatePlayerSponsoredKeys = accounts.into();
+    let ix = create_player_sponsored_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn create_player_sponsored_invoke_signed(
+    accounts: CreatePlayerSponsoredAccounts<'_, '_>,
+    args: CreatePlayerSponsoredIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    create_player_sponsored_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn create_player_sponsored_verify_account_keys(
+    accounts: CreatePlayerSponsoredAccounts<'_, '_>,
+    keys: CreatePlayerSponsoredKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.wallet_account.key, keys.wallet_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.item_registry.key, keys.item_registry),
+        (*accounts.referrer.key, keys.referrer),
+        (*accounts.player.key, keys.player),
+        (*accounts.payer.key, keys.payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn create_player_sponsored_verify_writable_privileges<'me, 'info>(
+    accounts: CreatePlayerSponsoredAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.player_account,
+        accounts.wallet_account,
+        accounts.game_config,
+        accounts.referrer,
+        accounts.payer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn create_player_sponsored_verify_signer_privileges<'me, 'info>(
+    accounts: CreatePlayerSponsoredAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.player, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn create_player_sponsored_verify_account_privileges<'me, 'info>(
+    accounts: CreatePlayerSponsoredAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    create_player_sponsored_verify_writable_privileges(accounts)?;
+    create_player_sponsored_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetReferralConfigAccounts<'me, 'info> {
+    pub referral_config: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetReferralConfigKeys {
+    pub referral_config: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<SetReferralConfigAccounts<'_, '_>> for SetReferralConfigKeys {
+    fn from(accounts: SetReferralConfigAccounts) -> Self {
+        Self {
+            referral_config: *accounts.referral_config.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetReferralConfigKeys> for [AccountMeta; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetReferralConfigKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.referral_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN]> for SetReferralConfigKeys {
+    fn from(pubkeys: [Pubkey; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            referral_config: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetReferralConfigAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetReferralConfigAccounts<'_, 'info>) -> Self {
+        [
+            accounts.referral_config.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN]>
+for SetReferralConfigAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            referral_config: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_REFERRAL_CONFIG_IX_DISCM: [u8; 8] = [114, 36, 32, 209, 207, 21, 21, 247];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetReferralConfigIxArgs {
+    pub max_rewarded_referrals: u16,
+    pub milestones: Vec<ReferralMilestone>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetReferralConfigIxData(pub SetReferralConfigIxArgs);
+impl From<SetReferralConfigIxArgs> for SetReferralConfigIxData {
+    fn from(args: SetReferralConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetReferralConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_REFERRAL_CONFIG_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_REFERRAL_CONFIG_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetReferralConfigIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_REFERRAL_CONFIG_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_referral_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetReferralConfigKeys,
+    args: SetReferralConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_REFERRAL_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetReferralConfigIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_referral_config_ix(
+    keys: SetReferralConfigKeys,
+    args: SetReferralConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    set_referral_config_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_referral_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetReferralConfigAccounts<'_, '_>,
+    args: SetReferralConfigIxArgs,
+) -> ProgramResult {
+    let keys: SetReferralConfigKeys = accounts.into();
+    let ix = set_referral_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_referral_config_invoke(
+    accounts: SetReferralConfigAccounts<'_, '_>,
+    args: SetReferralConfigIxArgs,
+) -> ProgramResult {
+    set_referral_config_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_referral_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetReferralConfigAccounts<'_, '_>,
+    args: SetReferralConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetReferralConfigKeys = accounts.into();
+    let ix = set_referral_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_referral_config_invoke_signed(
+    accounts: SetReferralConfigAccounts<'_, '_>,
+    args: SetReferralConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_referral_config_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_referral_config_verify_account_keys(
+    accounts: SetReferralConfigAccounts<'_, '_>,
+    keys: SetReferralConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.referral_config.key, keys.referral_config),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_referral_config_verify_writable_privileges<'me, 'info>(
+    accounts: SetReferralConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.referral_config, accounts.admin] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_referral_config_verify_signer_privileges<'me, 'info>(
+    accounts: SetReferralConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_referral_config_verify_account_privileges<'me, 'info>(
+    accounts: SetReferralConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_referral_config_verify_writable_privileges(accounts)?;
+    set_referral_config_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_GUILD_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct CreateGuildAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CreateGuildKeys {
+    pub guild: Pubkey,
+    pub player_account: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<CreateGuildAccounts<'_, '_>> for CreateGuildKeys {
+    fn from(accounts: CreateGuildAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            player_account: *accounts.player_account.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<CreateGuildKeys> for [AccountMeta; CREATE_GUILD_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateGuildKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_GUILD_IX_ACCOUNTS_LEN]> for CreateGuildKeys {
+    fn from(pubkeys: [Pubkey; CREATE_GUILD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            player_account: pubkeys[1],
+            signer: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<CreateGuildAccounts<'_, 'info>>
+for [AccountInfo<'info>; CREATE_GUILD_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateGuildAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.player_account.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_GUILD_IX_ACCOUNTS_LEN]>
+for CreateGuildAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; CREATE_GUILD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: &arr[0],
+            player_account: &arr[1],
+            signer: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const CREATE_GUILD_IX_DISCM: [u8; 8] = [163, 27, 97, 167, 132, 198, 53, 168];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateGuildIxArgs {
+    pub name: String,
+    pub join_policy: GuildJoinPolicy,
+    pub member_cap: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateGuildIxData(pub CreateGuildIxArgs);
+impl From<CreateGuildIxArgs> for CreateGuildIxData {
+    fn from(args: CreateGuildIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateGuildIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CREATE_GUILD_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_GUILD_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateGuildIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CREATE_GUILD_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn create_guild_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateGuildKeys,
+    args: CreateGuildIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_GUILD_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateGuildIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_guild_ix(
+    keys: CreateGuildKeys,
+    args: CreateGuildIxArgs,
+) -> std::io::Result<Instruction> {
+    create_guild_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn create_guild_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateGuildAccounts<'_, '_>,
+    args: CreateGuildIxArgs,
+) -> ProgramResult {
+    let keys: CreateGuildKeys = accounts.into();
+    let ix = create_guild_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn create_guild_invoke(
+    accounts: CreateGuildAccounts<'_, '_>,
+    args: CreateGuildIxArgs,
+) -> ProgramResult {
+    create_guild_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn create_guild_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateGuildAccounts<'_, '_>,
+    args: CreateGuildIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CreateGuildKeys = accounts.into();
+    let ix = create_guild_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn create_guild_invoke_signed(
+    accounts: CreateGuildAccounts<'_, '_>,
+    args: CreateGuildIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    create_guild_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn create_guild_verify_account_keys(
+    accounts: CreateGuildAccounts<'_, '_>,
+    keys: CreateGuildKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn create_guild_verify_writable_privileges<'me, 'info>(
+    accounts: CreateGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.guild,
+        accounts.player_account,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn create_guild_verify_signer_privileges<'me, 'info>(
+    accounts: CreateGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn create_guild_verify_account_privileges<'me, 'info>(
+    accounts: CreateGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    create_guild_verify_writable_privileges(accounts)?;
+    create_guild_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const INVITE_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct InviteAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub officer_account: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub guild_invite: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InviteKeys {
+    pub guild: Pubkey,
+    pub officer_account: Pubkey,
+    pub player_account: Pubkey,
+    pub guild_invite: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<InviteAccounts<'_, '_>> for InviteKeys {
+    fn from(accounts: InviteAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            officer_account: *accounts.officer_account.key,
+            player_account: *accounts.player_account.key,
+            guild_invite: *accounts.guild_invite.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InviteKeys> for [AccountMeta; INVITE_IX_ACCOUNTS_LEN] {
+    fn from(keys: InviteKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.officer_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.guild_invite,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INVITE_IX_ACCOUNTS_LEN]> for InviteKeys {
+    fn from(pubkeys: [Pubkey; INVITE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            officer_account: pubkeys[1],
+            player_account: pubkeys[2],
+            guild_invite: pubkeys[3],
+            signer: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<InviteAccounts<'_, 'info>>
+for [AccountInfo<'info>; INVITE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: InviteAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.officer_account.clone(),
+            accounts.player_account.clone(),
+            accounts.guild_invite.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INVITE_IX_ACCOUNTS_LEN]>
+for InviteAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; INVITE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: &arr[0],
+            officer_account: &arr[1],
+            player_account: &arr[2],
+            guild_invite: &arr[3],
+            signer: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const INVITE_IX_DISCM: [u8; 8] = [242, 24, 235, 225, 133, 211, 189, 250];
+#[derive(Clone, Debug, PartialEq)]
+pub struct InviteIxData;
+impl InviteIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INVITE_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INVITE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INVITE_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn invite_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InviteKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INVITE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: InviteIxData.try_to_vec()?,
+    })
+}
+pub fn invite_ix(keys: InviteKeys) -> std::io::Result<Instruction> {
+    invite_ix_with_program_id(crate::ID, keys)
+}
+pub fn invite_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InviteAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: InviteKeys = accounts.into();
+    let ix = invite_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn invite_invoke(accounts: InviteAccounts<'_, '_>) -> ProgramResult {
+    invite_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn invite_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InviteAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InviteKeys = accounts.into();
+    let ix = invite_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn invite_invoke_signed(
+    accounts: InviteAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invite_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn invite_verify_account_keys(
+    accounts: InviteAccounts<'_, '_>,
+    keys: InviteKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.officer_account.key, keys.officer_account),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.guild_invite.key, keys.guild_invite),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn invite_verify_writable_privileges<'me, 'info>(
+    accounts: InviteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.guild,
+        accounts.player_account,
+        accounts.guild_invite,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn invite_verify_signer_privileges<'me, 'info>(
+    accounts: InviteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn invite_verify_account_privileges<'me, 'info>(
+    accounts: InviteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    invite_verify_writable_privileges(accounts)?;
+    invite_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const JOIN_GUILD_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct JoinGuildAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub guild_invite: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JoinGuildKeys {
+    pub guild: Pubkey,
+    pub player_account: Pubkey,
+    pub guild_invite: Pubkey,
+    pub signer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<JoinGuildAccounts<'_, '_>> for JoinGuildKeys {
+    fn from(accounts: JoinGuildAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            player_account: *accounts.player_account.key,
+            guild_invite: *accounts.guild_invite.key,
+            signer: *accounts.signer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<JoinGuildKeys> for [AccountMeta; JOIN_GUILD_IX_ACCOUNTS_LEN] {
+    fn from(keys: JoinGuildKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.guild_invite,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; JOIN_GUILD_IX_ACCOUNTS_LEN]> for JoinGuildKeys {
+    fn from(pubkeys: [Pubkey; JOIN_GUILD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            player_account: pubkeys[1],
+            guild_invite: pubkeys[2],
+            signer: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<JoinGuildAccounts<'_, 'info>>
+for [AccountInfo<'info>; JOIN_GUILD_IX_ACCOUNTS_LEN] {
+    fn from(accounts: JoinGuildAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.player_account.clone(),
+            accounts.guild_invite.clone(),
+            accounts.signer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; JOIN_GUILD_IX_ACCOUNTS_LEN]>
+for JoinGuildAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; JOIN_GUILD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: &arr[0],
+            player_account: &arr[1],
+            guild_invite: &arr[2],
+            signer: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const JOIN_GUILD_IX_DISCM: [u8; 8] = [242, 159, 183, 21, 140, 65, 110, 73];
+#[derive(Clone, Debug, PartialEq)]
+pub struct JoinGuildIxData;
+impl JoinGuildIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != JOIN_GUILD_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        JOIN_GUILD_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&JOIN_GUILD_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn join_guild_ix_with_program_id(
+    program_id: Pubkey,
+    keys: JoinGuildKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; JOIN_GUILD_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: JoinGuildIxData.try_to_vec()?,
+    })
+}
+pub fn join_guild_ix(keys: JoinGuildKeys) -> std::io::Result<Instruction> {
+    join_guild_ix_with_program_id(crate::ID, keys)
+}
+pub fn join_guild_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: JoinGuildAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: JoinGuildKeys = accounts.into();
+    let ix = join_guild_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn join_guild_invoke(accounts: JoinGuildAccounts<'_, '_>) -> ProgramResult {
+    join_guild_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn join_guild_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: JoinGuildAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: JoinGuildKeys = accounts.into();
+    let ix = join_guild_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn join_guild_invoke_signed(
+    accounts: JoinGuildAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    join_guild_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn join_guild_verify_account_keys(
+    accounts: JoinGuildAccounts<'_, '_>,
+    keys: JoinGuildKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.guild_invite.key, keys.guild_invite),
+        (*accounts.signer.key, keys.signer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn join_guild_verify_writable_privileges<'me, 'info>(
+    accounts: JoinGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.guild,
+        accounts.player_account,
+        accounts.guild_invite,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn join_guild_verify_signer_privileges<'me, 'info>(
+    accounts: JoinGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn join_guild_verify_account_privileges<'me, 'info>(
+    accounts: JoinGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    join_guild_verify_writable_privileges(accounts)?;
+    join_guild_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const LEAVE_GUILD_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct LeaveGuildAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LeaveGuildKeys {
+    pub guild: Pubkey,
+    pub player_account: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<LeaveGuildAccounts<'_, '_>> for LeaveGuildKeys {
+    fn from(accounts: LeaveGuildAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            player_account: *accounts.player_account.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<LeaveGuildKeys> for [AccountMeta; LEAVE_GUILD_IX_ACCOUNTS_LEN] {
+    fn from(keys: LeaveGuildKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; LEAVE_GUILD_IX_ACCOUNTS_LEN]> for LeaveGuildKeys {
+    fn from(pubkeys: [Pubkey; LEAVE_GUILD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            player_account: pubkeys[1],
+            signer: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<LeaveGuildAccounts<'_, 'info>>
+for [AccountInfo<'info>; LEAVE_GUILD_IX_ACCOUNTS_LEN] {
+    fn from(accounts: LeaveGuildAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.player_account.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; LEAVE_GUILD_IX_ACCOUNTS_LEN]>
+for LeaveGuildAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; LEAVE_GUILD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: &arr[0],
+            player_account: &arr[1],
+            signer: &arr[2],
+        }
+    }
+}
+pub const LEAVE_GUILD_IX_DISCM: [u8; 8] = [32, 129, 72, 117, 154, 8, 115, 245];
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaveGuildIxData;
+impl LeaveGuildIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != LEAVE_GUILD_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        LEAVE_GUILD_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&LEAVE_GUILD_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn leave_guild_ix_with_program_id(
+    program_id: Pubkey,
+    keys: LeaveGuildKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; LEAVE_GUILD_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: LeaveGuildIxData.try_to_vec()?,
+    })
+}
+pub fn leave_guild_ix(keys: LeaveGuildKeys) -> std::io::Result<Instruction> {
+    leave_guild_ix_with_program_id(crate::ID, keys)
+}
+pub fn leave_guild_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: LeaveGuildAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: LeaveGuildKeys = accounts.into();
+    let ix = leave_guild_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn leave_guild_invoke(accounts: LeaveGuildAccounts<'_, '_>) -> ProgramResult {
+    leave_guild_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn leave_guild_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: LeaveGuildAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: LeaveGuildKeys = accounts.into();
+    let ix = leave_guild_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn leave_guild_invoke_signed(
+    accounts: LeaveGuildAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    leave_guild_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn leave_guild_verify_account_keys(
+    accounts: LeaveGuildAccounts<'_, '_>,
+    keys: LeaveGuildKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn leave_guild_verify_writable_privileges<'me, 'info>(
+    accounts: LeaveGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.guild,
+        accounts.player_account,
+        accounts.signer,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn leave_guild_verify_signer_privileges<'me, 'info>(
+    accounts: LeaveGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn leave_guild_verify_account_privileges<'me, 'info>(
+    accounts: LeaveGuildAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    leave_guild_verify_writable_privileges(accounts)?;
+    leave_guild_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const KICK_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct KickAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub officer_account: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KickKeys {
+    pub guild: Pubkey,
+    pub officer_account: Pubkey,
+    pub player_account: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<KickAccounts<'_, '_>> for KickKeys {
+    fn from(accounts: KickAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            officer_account: *accounts.officer_account.key,
+            player_account: *accounts.player_account.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<KickKeys> for [AccountMeta; KICK_IX_ACCOUNTS_LEN] {
+    fn from(keys: KickKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.officer_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; KICK_IX_ACCOUNTS_LEN]> for KickKeys {
+    fn from(pubkeys: [Pubkey; KICK_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            officer_account: pubkeys[1],
+            player_account: pubkeys[2],
+            signer: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<KickAccounts<'_, 'info>>
+for [AccountInfo<'info>; KICK_IX_ACCOUNTS_LEN] {
+    fn from(accounts: KickAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.officer_account.clone(),
+            accounts.player_account.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; KICK_IX_ACCOUNTS_LEN]>
+for KickAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; KICK_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: &arr[0],
+            officer_account: &arr[1],
+            player_account: &arr[2],
+            signer: &arr[3],
+        }
+    }
+}
+pub const KICK_IX_DISCM: [u8; 8] = [184, 92, 149, 185, 62, 150, 19, 210];
+#[derive(Clone, Debug, PartialEq)]
+pub struct KickIxData;
+impl KickIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != KICK_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        KICK_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&KICK_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn kick_ix_with_program_id(
+    program_id: Pubkey,
+    keys: KickKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; KICK_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: KickIxData.try_to_vec()?,
+    })
+}
+pub fn kick_ix(keys: KickKeys) -> std::io::Result<Instruction> {
+    kick_ix_with_program_id(crate::ID, keys)
+}
+pub fn kick_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: KickAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: KickKeys = accounts.into();
+    let ix = kick_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn kick_invoke(accounts: KickAccounts<'_, '_>) -> ProgramResult {
+    kick_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn kick_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: KickAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: KickKeys = accounts.into();
+    let ix = kick_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn kick_invoke_signed(
+    accounts: KickAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    kick_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn kick_verify_account_keys(
+    accounts: KickAccounts<'_, '_>,
+    keys: KickKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.officer_account.key, keys.officer_account),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn kick_verify_writable_privileges<'me, 'info>(
+    accounts: KickAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.guild, accounts.player_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn kick_verify_signer_privileges<'me, 'info>(
+    accounts: KickAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn kick_verify_account_privileges<'me, 'info>(
+    accounts: KickAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    kick_verify_writable_privileges(accounts)?;
+    kick_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct TransferLeadershipAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub leader_account: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransferLeadershipKeys {
+    pub guild: Pubkey,
+    pub leader_account: Pubkey,
+    pub player_account: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<TransferLeadershipAccounts<'_, '_>> for TransferLeadershipKeys {
+    fn from(accounts: TransferLeadershipAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            leader_account: *accounts.leader_account.key,
+            player_account: *accounts.player_account.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<TransferLeadershipKeys>
+for [AccountMeta; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN] {
+    fn from(keys: TransferLeadershipKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.leader_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN]> for TransferLeadershipKeys {
+    fn from(pubkeys: [Pubkey; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            leader_account: pubkeys[1],
+            player_account: pubkeys[2],
+            signer: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<TransferLeadershipAccounts<'_, 'info>>
+for [AccountInfo<'info>; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN] {
+    fn from(accounts: TransferLeadershipAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.leader_account.clone(),
+            accounts.player_account.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN]>
+for TransferLeadershipAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            guild: &arr[0],
+            leader_account: &arr[1],
+            player_account: &arr[2],
+            signer: &arr[3],
+        }
+    }
+}
+pub const TRANSFER_LEADERSHIP_IX_DISCM: [u8; 8] = [60, 182, 82, 49, 181, 30, 52, 237];
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferLeadershipIxData;
+impl TransferLeadershipIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != TRANSFER_LEADERSHIP_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        TRANSFER_LEADERSHIP_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&TRANSFER_LEADERSHIP_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn transfer_leadership_ix_with_program_id(
+    program_id: Pubkey,
+    keys: TransferLeadershipKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; TRANSFER_LEADERSHIP_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: TransferLeadershipIxData.try_to_vec()?,
+    })
+}
+pub fn transfer_leadership_ix(
+    keys: TransferLeadershipKeys,
+) -> std::io::Result<Instruction> {
+    transfer_leadership_ix_with_program_id(crate::ID, keys)
+}
+pub fn transfer_leadership_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferLeadershipAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: TransferLeadershipKeys = accounts.into();
+    let ix = transfer_leadership_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn transfer_leadership_invoke(
+    accounts: TransferLeadershipAccounts<'_, '_>,
+) -> ProgramResult {
+    transfer_leadership_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn transfer_leadership_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferLeadershipAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: TransferLeadershipKeys = accounts.into();
+    let ix = transfer_leadership_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn transfer_leadership_invoke_signed(
+    accounts: TransferLeadershipAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    transfer_leadership_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn transfer_leadership_verify_account_keys(
+    accounts: TransferLeadershipAccounts<'_, '_>,
+    keys: TransferLeadershipKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.leader_account.key, keys.leader_account),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_leadership_verify_writable_privileges<'me, 'info>(
+    accounts: TransferLeadershipAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.guild] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_leadership_verify_signer_privileges<'me, 'info>(
+    accounts: TransferLeadershipAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_leadership_verify_account_privileges<'me, 'info>(
+    accounts: TransferLeadershipAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    transfer_leadership_verify_writable_privileges(accounts)?;
+    transfer_leadership_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_GUILD_OFFICER_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetGuildOfficerAccounts<'me, 'info> {
+    pub guild: &'me AccountInfo<'info>,
+    pub leader_account: &'me AccountInfo<'info>,
+    pub player_account: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetGuildOfficerKeys {
+    pub guild: Pubkey,
+    pub leader_account: Pubkey,
+    pub player_account: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<SetGuildOfficerAccounts<'_, '_>> for SetGuildOfficerKeys {
+    fn from(accounts: SetGuildOfficerAccounts) -> Self {
+        Self {
+            guild: *accounts.guild.key,
+            leader_account: *accounts.leader_account.key,
+            player_account: *accounts.player_account.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<SetGuildOfficerKeys> for [AccountMeta; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetGuildOfficerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.leader_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN]> for SetGuildOfficerKeys {
+    fn from(pubkeys: [Pubkey; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: pubkeys[0],
+            leader_account: pubkeys[1],
+            player_account: pubkeys[2],
+            signer: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetGuildOfficerAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetGuildOfficerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.guild.clone(),
+            accounts.leader_account.clone(),
+            accounts.player_account.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN]>
+for SetGuildOfficerAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            guild: &arr[0],
+            leader_account: &arr[1],
+            player_account: &arr[2],
+            signer: &arr[3],
+        }
+    }
+}
+pub const SET_GUILD_OFFICER_IX_DISCM: [u8; 8] = [123, 132, 221, 168, 245, 118, 37, 218];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetGuildOfficerIxArgs {
+    pub promote: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetGuildOfficerIxData(pub SetGuildOfficerIxArgs);
+impl From<SetGuildOfficerIxArgs> for SetGuildOfficerIxData {
+    fn from(args: SetGuildOfficerIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetGuildOfficerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_GUILD_OFFICER_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_GUILD_OFFICER_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetGuildOfficerIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_GUILD_OFFICER_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_guild_officer_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetGuildOfficerKeys,
+    args: SetGuildOfficerIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_GUILD_OFFICER_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetGuildOfficerIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_guild_officer_ix(
+    keys: SetGuildOfficerKeys,
+    args: SetGuildOfficerIxArgs,
+) -> std::io::Result<Instruction> {
+    set_guild_officer_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_guild_officer_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetGuildOfficerAccounts<'_, '_>,
+    args: SetGuildOfficerIxArgs,
+) -> ProgramResult {
+    let keys: SetGuildOfficerKeys = accounts.into();
+    let ix = set_guild_officer_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_guild_officer_invoke(
+    accounts: SetGuildOfficerAccounts<'_, '_>,
+    args: SetGuildOfficerIxArgs,
+) -> ProgramResult {
+    set_guild_officer_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_guild_officer_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetGuildOfficerAccounts<'_, '_>,
+    args: SetGuildOfficerIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetGuildOfficerKeys = accounts.into();
+    let ix = set_guild_officer_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_guild_officer_invoke_signed(
+    accounts: SetGuildOfficerAccounts<'_, '_>,
+    args: SetGuildOfficerIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_guild_officer_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_guild_officer_verify_account_keys(
+    accounts: SetGuildOfficerAccounts<'_, '_>,
+    keys: SetGuildOfficerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.guild.key, keys.guild),
+        (*accounts.leader_account.key, keys.leader_account),
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_guild_officer_verify_writable_privileges<'me, 'info>(
+    accounts: SetGuildOfficerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.guild] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_guild_officer_verify_signer_privileges<'me, 'info>(
+    accounts: SetGuildOfficerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_guild_officer_verify_account_privileges<'me, 'info>(
+    accounts: SetGuildOfficerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_guild_officer_verify_writable_privileges(accounts)?;
+    set_guild_officer_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SETTLE_MATCH_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SettleMatchAccounts<'me, 'info> {
+    pub match_record: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SettleMatchKeys {
+    pub match_record: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<SettleMatchAccounts<'_, '_>> for SettleMatchKeys {
+    fn from(accounts: SettleMatchAccounts) -> Self {
+        Self {
+            match_record: *accounts.match_record.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SettleMatchKeys> for [AccountMeta; SETTLE_MATCH_IX_ACCOUNTS_LEN] {
+    fn from(keys: SettleMatchKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.match_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SETTLE_MATCH_IX_ACCOUNTS_LEN]> for SettleMatchKeys {
+    fn from(pubkeys: [Pubkey; SETTLE_MATCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            match_record: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            admin: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SettleMatchAccounts<'_, 'info>>
+for [AccountInfo<'info>; SETTLE_MATCH_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SettleMatchAccounts<'_, 'info>) -> Self {
+        [
+            accounts.match_record.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_MATCH_IX_ACCOUNTS_LEN]>
+for SettleMatchAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; SETTLE_MATCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            match_record: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            admin: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SETTLE_MATCH_IX_DISCM: [u8; 8] = [71, 124, 117, 96, 191, 217, 116, 24];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettleMatchIxArgs {
+    pub match_id: u64,
+    pub results: Vec<MatchResult>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettleMatchIxData(pub SettleMatchIxArgs);
+impl From<SettleMatchIxArgs> for SettleMatchIxData {
+    fn from(args: SettleMatchIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SettleMatchIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SETTLE_MATCH_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SETTLE_MATCH_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SettleMatchIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SETTLE_MATCH_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn settle_match_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SettleMatchKeys,
+    args: SettleMatchIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SETTLE_MATCH_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SettleMatchIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn settle_match_ix(
+    keys: SettleMatchKeys,
+    args: SettleMatchIxArgs,
+) -> std::io::Result<Instruction> {
+    settle_match_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn settle_match_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleMatchAccounts<'_, '_>,
+    args: SettleMatchIxArgs,
+) -> ProgramResult {
+    let keys: SettleMatchKeys = accounts.into();
+    let ix = settle_match_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn settle_match_invoke(
+    accounts: SettleMatchAccounts<'_, '_>,
+    args: SettleMatchIxArgs,
+) -> ProgramResult {
+    settle_match_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn settle_match_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleMatchAccounts<'_, '_>,
+    args: SettleMatchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SettleMatchKeys = accounts.into();
+    let ix = settle_match_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn settle_match_invoke_signed(
+    accounts: SettleMatchAccounts<'_, '_>,
+    args: SettleMatchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    settle_match_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn settle_match_verify_account_keys(
+    accounts: SettleMatchAccounts<'_, '_>,
+    keys: SettleMatchKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.match_record.key, keys.match_record),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn settle_match_verify_writable_privileges<'me, 'info>(
+    accounts: SettleMatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.match_record, accounts.admin] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn settle_match_verify_signer_privileges<'me, 'info>(
+    accounts: SettleMatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn settle_match_verify_account_privileges<'me, 'info>(
+    accounts: SettleMatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    settle_match_verify_writable_privileges(accounts)?;
+    settle_match_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct ModifyPlayerXpBatchAccounts<'me, 'info> {
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModifyPlayerXpBatchKeys {
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub admin: Pubkey,
+}
+impl From<ModifyPlayerXpBatchAccounts<'_, '_>> for ModifyPlayerXpBatchKeys {
+    fn from(accounts: ModifyPlayerXpBatchAccounts) -> Self {
+        Self {
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            admin: *accounts.admin.key,
+        }
+    }
+}
+impl From<ModifyPlayerXpBatchKeys>
+for [AccountMeta; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN] {
+    fn from(keys: ModifyPlayerXpBatchKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN]> for ModifyPlayerXpBatchKeys {
+    fn from(pubkeys: [Pubkey; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            game_config: pubkeys[0],
+            authority_registry: pubkeys[1],
+            admin: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<ModifyPlayerXpBatchAccounts<'_, 'info>>
+for [AccountInfo<'info>; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ModifyPlayerXpBatchAccounts<'_, 'info>) -> Self {
+        [
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.admin.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN]>
+for ModifyPlayerXpBatchAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            game_config: &arr[0],
+            authority_registry: &arr[1],
+            admin: &arr[2],
+        }
+    }
+}
+pub const MODIFY_PLAYER_XP_BATCH_IX_DISCM: [u8; 8] = [
+    96,
+    250,
+    107,
+    43,
+    16,
+    76,
+    238,
+    175,
+];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModifyPlayerXpBatchIxArgs {
+    pub xp_changes: Vec<i64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModifyPlayerXpBatchIxData(pub ModifyPlayerXpBatchIxArgs);
+impl From<ModifyPlayerXpBatchIxArgs> for ModifyPlayerXpBatchIxData {
+    fn from(args: ModifyPlayerXpBatchIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ModifyPlayerXpBatchIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != MODIFY_PLAYER_XP_BATCH_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MODIFY_PLAYER_XP_BATCH_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ModifyPlayerXpBatchIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&MODIFY_PLAYER_XP_BATCH_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn modify_player_xp_batch_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ModifyPlayerXpBatchKeys,
+    args: ModifyPlayerXpBatchIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ModifyPlayerXpBatchIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn modify_player_xp_batch_ix(
+    keys: ModifyPlayerXpBatchKeys,
+    args: ModifyPlayerXpBatchIxArgs,
+) -> std::io::Result<Instruction> {
+    modify_player_xp_batch_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn modify_player_xp_batch_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ModifyPlayerXpBatchAccounts<'_, '_>,
+    args: ModifyPlayerXpBatchIxArgs,
+) -> ProgramResult {
+    let keys: ModifyPlayerXpBatchKeys = accounts.into();
+    let ix = modify_player_xp_batch_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn modify_player_xp_batch_invoke(
+    accounts: ModifyPlayerXpBatchAccounts<'_, '_>,
+    args: ModifyPlayerXpBatchIxArgs,
+) -> ProgramResult {
+    modify_player_xp_batch_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn modify_player_xp_batch_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ModifyPlayerXpBatchAccounts<'_, '_>,
+    args: ModifyPlayerXpBatchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ModifyPlayerXpBatchKeys = accounts.into();
+    let ix = modify_player_xp_batch_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn modify_player_xp_batch_invoke_signed(
+    accounts: ModifyPlayerXpBatchAccounts<'_, '_>,
+    args: ModifyPlayerXpBatchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    modify_player_xp_batch_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn modify_player_xp_batch_verify_account_keys(
+    accounts: ModifyPlayerXpBatchAccounts<'_, '_>,
+    keys: ModifyPlayerXpBatchKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.admin.key, keys.admin),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn modify_player_xp_batch_verify_signer_privileges<'me, 'info>(
+    accounts: ModifyPlayerXpBatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn modify_player_xp_batch_verify_account_privileges<'me, 'info>(
+    accounts: ModifyPlayerXpBatchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    modify_player_xp_batch_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct RedeemXpVoucherAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub authority_registry: &'me AccountInfo<'info>,
+    pub guild: &'me AccountInfo<'info>,
+    pub instructions: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RedeemXpVoucherKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub authority_registry: Pubkey,
+    pub guild: Pubkey,
+    pub instructions: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<RedeemXpVoucherAccounts<'_, '_>> for RedeemXpVoucherKeys {
+    fn from(accounts: RedeemXpVoucherAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            authority_registry: *accounts.authority_registry.key,
+            guild: *accounts.guild.key,
+            instructions: *accounts.instructions.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<RedeemXpVoucherKeys> for [AccountMeta; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN] {
+    fn from(keys: RedeemXpVoucherKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority_registry,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.guild,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.instructions,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN]> for RedeemXpVoucherKeys {
+    fn from(pubkeys: [Pubkey; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            authority_registry: pubkeys[2],
+            guild: pubkeys[3],
+            instructions: pubkeys[4],
+            signer: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<RedeemXpVoucherAccounts<'_, 'info>>
+for [AccountInfo<'info>; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RedeemXpVoucherAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.authority_registry.clone(),
+            accounts.guild.clone(),
+            accounts.instructions.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN]>
+for RedeemXpVoucherAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            authority_registry: &arr[2],
+            guild: &arr[3],
+            instructions: &arr[4],
+            signer: &arr[5],
+        }
+    }
+}
+pub const REDEEM_XP_VOUCHER_IX_DISCM: [u8; 8] = [33, 15, 86, 60, 7, 247, 214, 151];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RedeemXpVoucherIxArgs {
+    pub voucher: XpVoucher,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RedeemXpVoucherIxData(pub RedeemXpVoucherIxArgs);
+impl From<RedeemXpVoucherIxArgs> for RedeemXpVoucherIxData {
+    fn from(args: RedeemXpVoucherIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RedeemXpVoucherIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REDEEM_XP_VOUCHER_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REDEEM_XP_VOUCHER_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(RedeemXpVoucherIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REDEEM_XP_VOUCHER_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn redeem_xp_voucher_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RedeemXpVoucherKeys,
+    args: RedeemXpVoucherIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REDEEM_XP_VOUCHER_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RedeemXpVoucherIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn redeem_xp_voucher_ix(
+    keys: RedeemXpVoucherKeys,
+    args: RedeemXpVoucherIxArgs,
+) -> std::io::Result<Instruction> {
+    redeem_xp_voucher_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn redeem_xp_voucher_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RedeemXpVoucherAccounts<'_, '_>,
+    args: RedeemXpVoucherIxArgs,
+) -> ProgramResult {
+    let keys: RedeemXpVoucherKeys = accounts.into();
+    let ix = redeem_xp_voucher_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn redeem_xp_voucher_invoke(
+    accounts: RedeemXpVoucherAccounts<'_, '_>,
+    args: RedeemXpVoucherIxArgs,
+) -> ProgramResult {
+    redeem_xp_voucher_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn redeem_xp_voucher_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RedeemXpVoucherAccounts<'_, '_>,
+    args: RedeemXpVoucherIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RedeemXpVoucherKeys = accounts.into();
+    let ix = redeem_xp_voucher_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn redeem_xp_voucher_invoke_signed(
+    accounts: RedeemXpVoucherAccounts<'_, '_>,
+    args: RedeemXpVoucherIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    redeem_xp_voucher_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn redeem_xp_voucher_verify_account_keys(
+    accounts: RedeemXpVoucherAccounts<'_, '_>,
+    keys: RedeemXpVoucherKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.authority_registry.key, keys.authority_registry),
+        (*accounts.guild.key, keys.guild),
+        (*accounts.instructions.key, keys.instructions),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn redeem_xp_voucher_verify_writable_privileges<'me, 'info>(
+    accounts: RedeemXpVoucherAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account, accounts.guild] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn redeem_xp_voucher_verify_signer_privileges<'me, 'info>(
+    accounts: RedeemXpVoucherAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn redeem_xp_voucher_verify_account_privileges<'me, 'info>(
+    accounts: RedeemXpVoucherAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    redeem_xp_voucher_verify_writable_privileges(accounts)?;
+    redeem_xp_voucher_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct ClaimReferralRewardAccounts<'me, 'info> {
+    pub player_account: &'me AccountInfo<'info>,
+    pub game_config: &'me AccountInfo<'info>,
+    pub referral_config: &'me AccountInfo<'info>,
+    pub referrer: &'me AccountInfo<'info>,
+    pub signer: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClaimReferralRewardKeys {
+    pub player_account: Pubkey,
+    pub game_config: Pubkey,
+    pub referral_config: Pubkey,
+    pub referrer: Pubkey,
+    pub signer: Pubkey,
+}
+impl From<ClaimReferralRewardAccounts<'_, '_>> for ClaimReferralRewardKeys {
+    fn from(accounts: ClaimReferralRewardAccounts) -> Self {
+        Self {
+            player_account: *accounts.player_account.key,
+            game_config: *accounts.game_config.key,
+            referral_config: *accounts.referral_config.key,
+            referrer: *accounts.referrer.key,
+            signer: *accounts.signer.key,
+        }
+    }
+}
+impl From<ClaimReferralRewardKeys>
+for [AccountMeta; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN] {
+    fn from(keys: ClaimReferralRewardKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.player_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.game_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referral_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN]> for ClaimReferralRewardKeys {
+    fn from(pubkeys: [Pubkey; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            player_account: pubkeys[0],
+            game_config: pubkeys[1],
+            referral_config: pubkeys[2],
+            referrer: pubkeys[3],
+            signer: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<ClaimReferralRewardAccounts<'_, 'info>>
+for [AccountInfo<'info>; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ClaimReferralRewardAccounts<'_, 'info>) -> Self {
+        [
+            accounts.player_account.clone(),
+            accounts.game_config.clone(),
+            accounts.referral_config.clone(),
+            accounts.referrer.clone(),
+            accounts.signer.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN]>
+for ClaimReferralRewardAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            player_account: &arr[0],
+            game_config: &arr[1],
+            referral_config: &arr[2],
+            referrer: &arr[3],
+            signer: &arr[4],
+        }
+    }
+}
+pub const CLAIM_REFERRAL_REWARD_IX_DISCM: [u8; 8] = [120, 43, 209, 240, 2, 41, 98, 212];
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimReferralRewardIxData;
+impl ClaimReferralRewardIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CLAIM_REFERRAL_REWARD_IX_DISCM {
+            return Err(
+                std::io::Error::other(
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CLAIM_REFERRAL_REWARD_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CLAIM_REFERRAL_REWARD_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn claim_referral_reward_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ClaimReferralRewardKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CLAIM_REFERRAL_REWARD_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ClaimReferralRewardIxData.try_to_vec()?,
+    })
+}
+pub fn claim_referral_reward_ix(
+    keys: ClaimReferralRewardKeys,
+) -> std::io::Result<Instruction> {
+    claim_referral_reward_ix_with_program_id(crate::ID, keys)
+}
+pub fn claim_referral_reward_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ClaimReferralRewardAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: ClaimReferralRewardKeys = accounts.into();
+    let ix = claim_referral_reward_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn claim_referral_reward_invoke(
+    accounts: ClaimReferralRewardAccounts<'_, '_>,
+) -> ProgramResult {
+    claim_referral_reward_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn claim_referral_reward_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ClaimReferralRewardAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ClaimReferralRewardKeys = accounts.into();
+    let ix = claim_referral_reward_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn claim_referral_reward_invoke_signed(
+    accounts: ClaimReferralRewardAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    claim_referral_reward_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn claim_referral_reward_verify_account_keys(
+    accounts: ClaimReferralRewardAccounts<'_, '_>,
+    keys: ClaimReferralRewardKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.player_account.key, keys.player_account),
+        (*accounts.game_config.key, keys.game_config),
+        (*accounts.referral_config.key, keys.referral_config),
+        (*accounts.referrer.key, keys.referrer),
+        (*accounts.signer.key, keys.signer),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn claim_referral_reward_verify_writable_privileges<'me, 'info>(
+    accounts: ClaimReferralRewardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.player_account, accounts.referrer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn claim_referral_reward_verify_signer_privileges<'me, 'info>(
+    accounts: ClaimReferralRewardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn claim_referral_reward_verify_account_privileges<'me, 'info>(
+    accounts: ClaimReferralRewardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    claim_referral_reward_verify_writable_privileges(accounts)?;
+    claim_referral_reward_verify_signer_privileges(accounts)?;
+    Ok(())
+}
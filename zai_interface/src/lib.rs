@@ -7,3 +7,25 @@ pub mod instructions;
 pub use instructions::*;
 pub mod errors;
 pub use errors::*;
+pub mod events;
+pub use events::*;
+pub mod xp;
+pub use xp::*;
+pub mod pda;
+pub use pda::*;
+pub mod username;
+pub use username::*;
+pub mod pause;
+pub use pause::*;
+pub mod session;
+pub use session::*;
+pub mod referral;
+pub use referral::*;
+pub mod guild;
+pub use guild::*;
+pub mod matches;
+pub use matches::*;
+pub mod xp_batch;
+pub use xp_batch::*;
+pub mod voucher;
+pub use voucher::*;
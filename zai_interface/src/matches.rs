@@ -0,0 +1,22 @@
+use solana_program::{instruction::{AccountMeta, Instruction}, pubkey::Pubkey};
+use crate::{settle_match_ix_with_program_id, MatchResult, SettleMatchIxArgs, SettleMatchKeys};
+pub const MATCH_RECORD_SEED: &[u8] = b"match";
+pub const MATCH_MAX_PLAYERS: usize = 16;
+pub fn find_match_record_address_with_program_id(program_id: Pubkey, match_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MATCH_RECORD_SEED, &match_id.to_le_bytes()], &program_id)
+}
+pub fn find_match_record_address(match_id: u64) -> (Pubkey, u8) {
+    find_match_record_address_with_program_id(crate::ID, match_id)
+}
+// settle_match with each player PDA appended as a writable remaining account, in the same order as its result,
+// followed by each guild PDA once. While guild XP sharing is on, guilds of players gaining XP must be included.
+pub fn settle_match_ix_with_players_with_program_id(program_id: Pubkey, keys: SettleMatchKeys, match_id: u64, results: &[(Pubkey, MatchResult)], guilds: &[Pubkey]) -> std::io::Result<Instruction> {
+    let args = SettleMatchIxArgs { match_id, results: results.iter().map(|(_, result)| result.clone()).collect() };
+    let mut ix = settle_match_ix_with_program_id(program_id, keys, args)?;
+    ix.accounts.extend(results.iter().map(|(player, _)| AccountMeta::new(*player, false)));
+    ix.accounts.extend(guilds.iter().map(|guild| AccountMeta::new(*guild, false)));
+    Ok(ix)
+}
+pub fn settle_match_ix_with_players(keys: SettleMatchKeys, match_id: u64, results: &[(Pubkey, MatchResult)], guilds: &[Pubkey]) -> std::io::Result<Instruction> {
+    settle_match_ix_with_players_with_program_id(crate::ID, keys, match_id, results, guilds)
+}
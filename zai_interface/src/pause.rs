@@ -0,0 +1,33 @@
+use crate::GameConfig;
+// Instruction groups behind GameConfig::paused, bit positions match the program.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PauseFlag {
+    Create,
+    ClassChange,
+    Xp,
+    LevelUp,
+    Equip,
+}
+impl PauseFlag {
+    pub const ALL: [PauseFlag; 5] = [PauseFlag::Create, PauseFlag::ClassChange, PauseFlag::Xp, PauseFlag::LevelUp, PauseFlag::Equip];
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+// Mask pausing every instruction group, for set_paused.
+pub fn pause_all_mask() -> u8 {
+    PauseFlag::ALL.iter().fold(0, |mask, flag| mask | flag.mask())
+}
+// Returns the current mask with one group paused or resumed, for set_paused.
+pub fn toggle_pause_flag(paused: u8, flag: PauseFlag, pause: bool) -> u8 {
+    if pause {
+        paused | flag.mask()
+    } else {
+        paused & !flag.mask()
+    }
+}
+impl GameConfig {
+    pub fn is_paused(&self, flag: PauseFlag) -> bool {
+        self.paused & flag.mask() != 0
+    }
+}
@@ -0,0 +1,32 @@
+use solana_program::pubkey::Pubkey;
+pub const PLAYER_SEED: &[u8] = b"player";
+pub const WALLET_ACCOUNT_SEED: &[u8] = b"account";
+// Slot 0 adds no seed so characters created before slots existed keep their address.
+pub fn player_slot_seed(slot: &u8) -> &[u8] {
+    if *slot == 0 {
+        &[]
+    } else {
+        std::slice::from_ref(slot)
+    }
+}
+// Player PDA for a wallet's character slot.
+pub fn find_player_address_with_program_id(program_id: Pubkey, owner: &Pubkey, slot: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLAYER_SEED, owner.as_ref(), player_slot_seed(&slot)], &program_id)
+}
+pub fn find_player_address(owner: &Pubkey, slot: u8) -> (Pubkey, u8) {
+    find_player_address_with_program_id(crate::ID, owner, slot)
+}
+// Player PDAs for slots 0..slots, e.g. WalletAccount::slots.
+pub fn find_player_addresses_with_program_id(program_id: Pubkey, owner: &Pubkey, slots: u8) -> Vec<(Pubkey, u8)> {
+    (0..slots).map(|slot| find_player_address_with_program_id(program_id, owner, slot)).collect()
+}
+pub fn find_player_addresses(owner: &Pubkey, slots: u8) -> Vec<(Pubkey, u8)> {
+    find_player_addresses_with_program_id(crate::ID, owner, slots)
+}
+// Per-wallet account tracking owned character slots.
+pub fn find_wallet_account_address_with_program_id(program_id: Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WALLET_ACCOUNT_SEED, owner.as_ref()], &program_id)
+}
+pub fn find_wallet_account_address(owner: &Pubkey) -> (Pubkey, u8) {
+    find_wallet_account_address_with_program_id(crate::ID, owner)
+}
@@ -0,0 +1,17 @@
+use solana_program::pubkey::Pubkey;
+use crate::Player;
+pub const REFERRAL_CONFIG_SEED: &[u8] = b"referrals";
+// Level-up instructions take the program id they are built for as referral_config until set_referral_config has run.
+pub fn find_referral_config_address_with_program_id(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_CONFIG_SEED], &program_id)
+}
+pub fn find_referral_config_address() -> (Pubkey, u8) {
+    find_referral_config_address_with_program_id(crate::ID)
+}
+impl Player {
+    // Referrer player PDA, None if the player was not referred.
+    // Level-up instructions take it as referrer to pay milestone rewards, otherwise the program id they are built for.
+    pub fn referrer(&self) -> Option<Pubkey> {
+        (self.referred_by != Pubkey::default()).then_some(self.referred_by)
+    }
+}
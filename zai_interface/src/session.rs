@@ -0,0 +1,34 @@
+use solana_program::pubkey::Pubkey;
+use crate::Session;
+pub const SESSION_SEED: &[u8] = b"session";
+pub const SESSION_MAX_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60;
+// Player-signed instruction groups a session key can sign for, bit positions match the program.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionScope {
+    ClassChange,
+    LevelUp,
+    Chests,
+    Equip,
+}
+impl SessionScope {
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+// Scope mask for create_session.
+pub fn session_scope_mask(scopes: &[SessionScope]) -> u8 {
+    scopes.iter().fold(0, |mask, scope| mask | scope.mask())
+}
+// Session PDA for a player PDA and session key.
+// Player-signed instructions take the program id they are built for as session when the owner wallet signs.
+pub fn find_session_address_with_program_id(program_id: Pubkey, player: &Pubkey, session_key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SESSION_SEED, player.as_ref(), session_key.as_ref()], &program_id)
+}
+pub fn find_session_address(player: &Pubkey, session_key: &Pubkey) -> (Pubkey, u8) {
+    find_session_address_with_program_id(crate::ID, player, session_key)
+}
+impl Session {
+    pub fn allows(&self, scope: SessionScope, now: i64) -> bool {
+        now < self.expires_at && self.scope & scope.mask() != 0
+    }
+}
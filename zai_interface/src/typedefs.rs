@@ -1,7 +1,133 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServerAuthority {
+    pub key: Pubkey,
+    pub roles: u8,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    XpGranter,
+    ItemGranter,
+    Operator,
+    Sponsor,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LootEntry {
+    pub weight: u16,
+    pub reward: LootReward,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LootReward {
+    XpBonus { amount: u32 },
+    PremiumClass { class_id: u8 },
+    PremiumWeapon { weapon_id: u8 },
+    Cosmetic { cosmetic_id: u8 },
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RandomnessSource {
+    SlotHashes,
+    Oracle,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OracleConfig {
+    pub program: Pubkey,
+    pub discriminator: [u8; 8],
+    pub fulfilled_offset: u16,
+    pub randomness_offset: u16,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OracleFulfiller {
+    pub key: Pubkey,
+    pub offset: u16,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomnessCommitment {
+    pub source: RandomnessSource,
+    pub account: Pubkey,
+    pub target_slot: u64,
+    pub commitment: [u8; 32],
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelUpSummary {
+    pub levels_gained: u8,
+    pub chest_rolls_queued: u8,
+    pub level: u8,
+    pub xp: i64,
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PremiumItemType {
     Class,
     Weapon,
+    Cosmetic,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigUpdate {
+    pub level_cap: Option<u8>,
+    pub classes: Option<Vec<u8>>,
+    pub key_overlap_slots: Option<u64>,
+    pub randomness_source: Option<RandomnessSource>,
+    pub oracle: Option<OracleConfig>,
+    pub auto_level: Option<bool>,
+    pub close_guards: Option<u8>,
+    pub username_cooldown: Option<i64>,
+    pub username_change_fee: Option<u64>,
+    pub suspended_xp_penalties: Option<bool>,
+    pub sponsorship_daily_budget: Option<u64>,
+    pub guild_xp_share_percent: Option<u8>,
+    pub oracle_fulfiller: Option<OracleFulfiller>,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloseGuard {
+    UnopenedChests,
+    InMatch,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemDefinition {
+    pub id: u8,
+    pub kind: PremiumItemType,
+    pub premium: bool,
+    pub min_level: u8,
+    pub allowed_classes: [u8; 8],
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GuildJoinPolicy {
+    Open,
+    InviteOnly,
+    Application,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XpVoucher {
+    pub player: Pubkey,
+    pub xp: u32,
+    pub nonce: u64,
+    pub expires_at: i64,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchResult {
+    pub xp_change: i64,
+    pub chests: u8,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferralMilestone {
+    pub level: u8,
+    pub xp: u32,
+    pub chests: u8,
 }
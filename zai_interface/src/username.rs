@@ -0,0 +1,27 @@
+use solana_program::pubkey::Pubkey;
+use crate::Player;
+pub const USERNAME_SEED: &[u8] = b"username";
+pub const USERNAME_MIN_LEN: usize = 3;
+pub const USERNAME_MAX_LEN: usize = 16;
+// Lowercases and validates a name the same way the program does, None if it would be rejected.
+pub fn normalize_username(name: &str) -> Option<String> {
+    let normalized = name.to_ascii_lowercase();
+    let valid_len = (USERNAME_MIN_LEN..=USERNAME_MAX_LEN).contains(&normalized.len());
+    let valid_chars = normalized.bytes().all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_');
+    (valid_len && valid_chars).then_some(normalized)
+}
+// Username PDA for a name, usable for lookups before normalizing.
+// close_player takes the program id it is built for as username_account when the player has no username.
+pub fn find_username_address_with_program_id(program_id: Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USERNAME_SEED, name.to_ascii_lowercase().as_bytes()], &program_id)
+}
+pub fn find_username_address(name: &str) -> (Pubkey, u8) {
+    find_username_address_with_program_id(crate::ID, name)
+}
+impl Player {
+    // Reverse lookup of the registered name, None if the player has not set one.
+    pub fn username(&self) -> Option<String> {
+        let len = self.username.iter().position(|byte| *byte == 0).unwrap_or(self.username.len());
+        (len > 0).then(|| String::from_utf8_lossy(&self.username[..len]).into_owned())
+    }
+}
@@ -0,0 +1,64 @@
+use base64::Engine;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{ed25519_program, instruction::Instruction, pubkey::Pubkey};
+use crate::{redeem_xp_voucher_ix_with_program_id, RedeemXpVoucherIxArgs, RedeemXpVoucherKeys, XpVoucher};
+pub const XP_VOUCHER_MESSAGE_PREFIX: &[u8] = b"zai_xp_voucher";
+// Offsets of the inline public key, signature and message in the Ed25519 instruction data.
+const ED25519_PUBLIC_KEY_OFFSET: u16 = 16;
+const ED25519_SIGNATURE_OFFSET: u16 = 48;
+const ED25519_MESSAGE_OFFSET: u16 = 112;
+// Message the server signs, matching the program: prefix, program id, then the borsh encoded voucher.
+// program_id must be the deployed zai program, the crate::ID placeholder would never verify.
+pub fn xp_voucher_message(program_id: Pubkey, voucher: &XpVoucher) -> std::io::Result<Vec<u8>> {
+    let mut message = [XP_VOUCHER_MESSAGE_PREFIX, program_id.as_ref()].concat();
+    voucher.serialize(&mut message)?;
+    Ok(message)
+}
+// A voucher with the signing server key and signature, as handed from the server to the player.
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub struct SignedXpVoucher {
+    pub voucher: XpVoucher,
+    pub signer: Pubkey,
+    pub signature: [u8; 64],
+}
+impl SignedXpVoucher {
+    // Signs with any ed25519 signer, e.g. |message| keypair.sign_message(message).into().
+    pub fn sign(program_id: Pubkey, voucher: XpVoucher, signer: Pubkey, sign: impl FnOnce(&[u8]) -> [u8; 64]) -> std::io::Result<Self> {
+        let signature = sign(&xp_voucher_message(program_id, &voucher)?);
+        Ok(Self { voucher, signer, signature })
+    }
+    pub fn to_base64(&self) -> std::io::Result<String> {
+        Ok(base64::engine::general_purpose::STANDARD.encode(self.try_to_vec()?))
+    }
+    pub fn from_base64(encoded: &str) -> std::io::Result<Self> {
+        let bytes = base64::engine::general_purpose::STANDARD.decode(encoded.trim()).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        Self::try_from_slice(&bytes)
+    }
+    // Ed25519 program instruction verifying the voucher signature with all data inline.
+    pub fn ed25519_ix(&self, program_id: Pubkey) -> std::io::Result<Instruction> {
+        let message = xp_voucher_message(program_id, &self.voucher)?;
+        let offsets = [
+            ED25519_SIGNATURE_OFFSET,
+            u16::MAX,
+            ED25519_PUBLIC_KEY_OFFSET,
+            u16::MAX,
+            ED25519_MESSAGE_OFFSET,
+            message.len() as u16,
+            u16::MAX,
+        ];
+        let mut data = vec![1, 0];
+        data.extend(offsets.iter().flat_map(|offset| offset.to_le_bytes()));
+        data.extend_from_slice(self.signer.as_ref());
+        data.extend_from_slice(&self.signature);
+        data.extend_from_slice(&message);
+        Ok(Instruction { program_id: ed25519_program::ID, accounts: Vec::new(), data })
+    }
+    // Ed25519 verification immediately followed by redeem_xp_voucher, as the program expects.
+    // keys.instructions is the Instructions sysvar, keys.guild as described on Player::guild.
+    pub fn redeem_ixs(&self, program_id: Pubkey, keys: RedeemXpVoucherKeys) -> std::io::Result<[Instruction; 2]> {
+        Ok([
+            self.ed25519_ix(program_id)?,
+            redeem_xp_voucher_ix_with_program_id(program_id, keys, RedeemXpVoucherIxArgs { voucher: self.voucher.clone() })?,
+        ])
+    }
+}
@@ -0,0 +1,42 @@
+use crate::Player;
+impl Player {
+    // XP needed to go from `level` to `level + 1`, identical to the on-chain curve.
+    pub fn required_xp(level: u8) -> i64 {
+        REQUIRED_XP[level as usize]
+    }
+}
+// round(4250 * e^(0.055 * level)) for every level, mirrored from the program.
+pub const REQUIRED_XP: [i64; 256] = [
+    4250, 4490, 4744, 5012, 5296, 5595, 5912, 6246,
+    6599, 6972, 7366, 7783, 8223, 8688, 9179, 9698,
+    10246, 10826, 11438, 12084, 12768, 13490, 14252, 15058,
+    15910, 16809, 17759, 18764, 19825, 20945, 22130, 23381,
+    24703, 26100, 27575, 29134, 30782, 32522, 34361, 36304,
+    38356, 40525, 42816, 45237, 47795, 50497, 53352, 56369,
+    59556, 62923, 66481, 70240, 74211, 78407, 82841, 87525,
+    92473, 97702, 103226, 109062, 115229, 121744, 128627, 135900,
+    143584, 151702, 160279, 169342, 178916, 189033, 199721, 211013,
+    222944, 235549, 248867, 262938, 277805, 293512, 310107, 327641,
+    346166, 365739, 386418, 408266, 431350, 455738, 481506, 508731,
+    537495, 567885, 599994, 633918, 669760, 707628, 747638, 789910,
+    834572, 881759, 931614, 984288, 1039941, 1098740, 1160863, 1226499,
+    1295846, 1369114, 1446524, 1528312, 1614723, 1706021, 1802480, 1904394,
+    2012069, 2125833, 2246029, 2373021, 2507193, 2648951, 2798724, 2956966,
+    3124155, 3300796, 3487425, 3684606, 3892936, 4113045, 4345599, 4591302,
+    4850897, 5125170, 5414950, 5721115, 6044590, 6386355, 6747444, 7128948,
+    7532023, 7957889, 8407832, 8883216, 9385479, 9916140, 10476804, 11069169,
+    11695027, 12356271, 13054902, 13793034, 14572901, 15396862, 16267410, 17187179,
+    18158953, 19185671, 20270441, 21416544, 22627448, 23906818, 25258525, 26686657,
+    28195537, 29789730, 31474060, 33253622, 35133803, 37120289, 39219093, 41436565,
+    43779414, 46254729, 48870000, 51633139, 54552509, 57636941, 60895769, 64338854,
+    67976612, 71820051, 75880801, 80171148, 84704074, 89493295, 94553301, 99899402,
+    105547776, 111515512, 117820668, 124482321, 131520628, 138956885, 146813593, 155114523,
+    163884794, 173150941, 182941001, 193284598, 204213028, 215759358, 227958525, 240847440,
+    254465102, 268852716, 284053813, 300114391, 317083043, 335011113, 353952847, 373965559,
+    395109801, 417449552, 441052406, 465989781, 492337129, 520174173, 549585141, 580659022,
+    613489840, 648176933, 684825255, 723545696, 764455415, 807678194, 853344815, 901593456,
+    952570104, 1006429003, 1063333117, 1123454625, 1186975440, 1254087761, 1324994654, 1399910667,
+    1479062476, 1562689578, 1651045007, 1744396106, 1843025335, 1947231120, 2057328764, 2173651397,
+    2296550983, 2426399388, 2563589500, 2708536426, 2861678741, 3023479816, 3194429223, 3375044215,
+    3565871289, 3767487844, 3980503922, 4205564061, 4443349238, 4694578935, 4960013314, 5240455515,
+];
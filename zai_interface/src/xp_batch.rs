@@ -0,0 +1,165 @@
+use solana_program::{instruction::{AccountMeta, Instruction}, message::Message, pubkey, pubkey::Pubkey};
+use crate::{modify_player_xp_batch_ix_with_program_id, ModifyPlayerXpBatchIxArgs, ModifyPlayerXpBatchKeys, MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN};
+// Serialized transaction size limit, matching solana_sdk::packet::PACKET_DATA_SIZE.
+pub const TRANSACTION_SIZE_LIMIT: usize = 1232;
+// Accounts a transaction may lock.
+pub const TRANSACTION_ACCOUNT_LIMIT: usize = 64;
+pub const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+// Estimates for modify_player_xp_batch including a possible auto level-up, not measured against the deployed program.
+// They only size batches and the requested limit, profile the deployment before relying on them to stay under the cap.
+pub const XP_BATCH_BASE_COMPUTE_UNITS: u32 = 20_000;
+pub const XP_BATCH_COMPUTE_UNITS_PER_PLAYER: u32 = 15_000;
+pub const XP_BATCH_COMPUTE_UNITS_PER_GUILD: u32 = 5_000;
+// Compute budget program, which solana-program does not export.
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+// ComputeBudgetInstruction::SetComputeUnitLimit, matching solana_sdk::compute_budget.
+pub fn set_compute_unit_limit_ix(units: u32) -> Instruction {
+    let mut data = vec![2];
+    data.extend_from_slice(&units.to_le_bytes());
+    Instruction { program_id: COMPUTE_BUDGET_PROGRAM_ID, accounts: vec![], data }
+}
+// Estimated compute units for a modify_player_xp_batch over this many players and guilds.
+pub fn xp_batch_compute_units(players: usize, guilds: usize) -> u32 {
+    XP_BATCH_BASE_COMPUTE_UNITS + players as u32 * XP_BATCH_COMPUTE_UNITS_PER_PLAYER + guilds as u32 * XP_BATCH_COMPUTE_UNITS_PER_GUILD
+}
+// One player's XP change. guild is the player's guild PDA from Player::guild, needed while guild XP sharing is on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct XpGrant {
+    pub player: Pubkey,
+    pub guild: Option<Pubkey>,
+    pub xp_change: i64,
+}
+// modify_player_xp_batch with each player PDA appended as a writable remaining account, in the same order as its delta,
+// followed by the guild of every player gaining XP, each once.
+pub fn modify_player_xp_batch_ix_with_players_with_program_id(program_id: Pubkey, keys: ModifyPlayerXpBatchKeys, grants: &[XpGrant]) -> std::io::Result<Instruction> {
+    let args = ModifyPlayerXpBatchIxArgs { xp_changes: grants.iter().map(|grant| grant.xp_change).collect() };
+    let mut ix = modify_player_xp_batch_ix_with_program_id(program_id, keys, args)?;
+    ix.accounts.extend(grants.iter().map(|grant| AccountMeta::new(grant.player, false)));
+    let mut guilds: Vec<Pubkey> = Vec::new();
+    for guild in grants.iter().filter(|grant| grant.xp_change > 0).filter_map(|grant| grant.guild) {
+        if !guilds.contains(&guild) {
+            guilds.push(guild);
+        }
+    }
+    ix.accounts.extend(guilds.into_iter().map(|guild| AccountMeta::new(guild, false)));
+    Ok(ix)
+}
+pub fn modify_player_xp_batch_ix_with_players(keys: ModifyPlayerXpBatchKeys, grants: &[XpGrant]) -> std::io::Result<Instruction> {
+    modify_player_xp_batch_ix_with_players_with_program_id(crate::ID, keys, grants)
+}
+// Serialized size of a transaction holding these instructions, paid for by `payer`.
+pub fn transaction_size(ixs: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(ixs, Some(payer));
+    1 + 64 * message.header.num_required_signatures as usize + message.serialize().len()
+}
+// Compute unit limit followed by the batch, sent together as one transaction. Also returns the unclamped estimate.
+fn xp_batch_transaction(program_id: Pubkey, keys: ModifyPlayerXpBatchKeys, grants: &[XpGrant]) -> std::io::Result<([Instruction; 2], u32)> {
+    let ix = modify_player_xp_batch_ix_with_players_with_program_id(program_id, keys, grants)?;
+    let guilds = ix.accounts.len() - MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN - grants.len();
+    let compute_units = xp_batch_compute_units(grants.len(), guilds);
+    Ok(([set_compute_unit_limit_ix(compute_units.min(MAX_COMPUTE_UNITS)), ix], compute_units))
+}
+// Splits grants into as few transactions as fit the size, account and `max_compute_units` limits.
+// Each transaction is a SetComputeUnitLimit for the batch's estimate followed by one modify_player_xp_batch
+// for program_id, the deployed zai program. Players must not repeat within the grants.
+pub fn pack_xp_batches(program_id: Pubkey, keys: ModifyPlayerXpBatchKeys, payer: &Pubkey, grants: &[XpGrant], max_compute_units: u32) -> std::io::Result<Vec<[Instruction; 2]>> {
+    let max_compute_units = max_compute_units.min(MAX_COMPUTE_UNITS);
+    let mut batches = Vec::new();
+    let mut start = 0;
+    while start < grants.len() {
+        // Always take at least one grant so oversized single grants still make progress
+        let (mut batch, _) = xp_batch_transaction(program_id, keys, &grants[start..start + 1])?;
+        let mut end = start + 1;
+        while end < grants.len() {
+            let (candidate, compute_units) = xp_batch_transaction(program_id, keys, &grants[start..end + 1])?;
+            let accounts = Message::new(&candidate, Some(payer)).account_keys.len();
+            if compute_units > max_compute_units || accounts > TRANSACTION_ACCOUNT_LIMIT || transaction_size(&candidate, payer) > TRANSACTION_SIZE_LIMIT {
+                break;
+            }
+            batch = candidate;
+            end += 1;
+        }
+        batches.push(batch);
+        start = end;
+    }
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> ModifyPlayerXpBatchKeys {
+        ModifyPlayerXpBatchKeys { game_config: Pubkey::new_unique(), authority_registry: Pubkey::new_unique(), admin: Pubkey::new_unique() }
+    }
+
+    fn grants(count: usize) -> Vec<XpGrant> {
+        (0..count).map(|index| XpGrant { player: Pubkey::new_unique(), guild: None, xp_change: index as i64 + 1 }).collect()
+    }
+
+    fn compute_unit_limit(ix: &Instruction) -> u32 {
+        assert_eq!(ix.program_id, COMPUTE_BUDGET_PROGRAM_ID);
+        assert_eq!(ix.data[0], 2);
+        u32::from_le_bytes(ix.data[1..5].try_into().unwrap())
+    }
+
+    #[test]
+    fn set_compute_unit_limit_encodes_units() {
+        let ix = set_compute_unit_limit_ix(300_000);
+        assert!(ix.accounts.is_empty());
+        assert_eq!(ix.data, [2, 0xe0, 0x93, 0x04, 0x00]);
+    }
+
+    #[test]
+    fn compute_estimate_is_15k_per_player() {
+        assert_eq!(xp_batch_compute_units(0, 0), XP_BATCH_BASE_COMPUTE_UNITS);
+        assert_eq!(xp_batch_compute_units(1, 0), 35_000);
+        assert_eq!(xp_batch_compute_units(10, 0), 170_000);
+        assert_eq!(xp_batch_compute_units(10, 2), 180_000);
+    }
+
+    #[test]
+    fn pack_splits_on_compute_units_and_requests_the_estimate() {
+        let (program_id, keys) = (Pubkey::new_unique(), keys());
+        let grants = grants(7);
+        let max_compute_units = xp_batch_compute_units(3, 0);
+        let batches = pack_xp_batches(program_id, keys, &keys.admin, &grants, max_compute_units).unwrap();
+        let players: Vec<usize> = batches.iter().map(|[_, ix]| ix.accounts.len() - MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN).collect();
+        assert_eq!(players, [3, 3, 1]);
+        for ([limit, ix], count) in batches.iter().zip(players) {
+            assert_eq!(compute_unit_limit(limit), xp_batch_compute_units(count, 0));
+            assert_eq!(ix.program_id, program_id);
+        }
+    }
+
+    #[test]
+    fn pack_counts_guilds_of_players_gaining_xp() {
+        let (program_id, keys) = (Pubkey::new_unique(), keys());
+        let guild = Pubkey::new_unique();
+        let mut grants = grants(3);
+        for grant in grants.iter_mut() {
+            grant.guild = Some(guild);
+        }
+        grants[2].xp_change = -5;
+        let batches = pack_xp_batches(program_id, keys, &keys.admin, &grants, MAX_COMPUTE_UNITS).unwrap();
+        assert_eq!(batches.len(), 1);
+        let [limit, ix] = &batches[0];
+        assert_eq!(ix.accounts.len(), MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN + 3 + 1);
+        assert_eq!(compute_unit_limit(limit), xp_batch_compute_units(3, 1));
+    }
+
+    #[test]
+    fn pack_respects_transaction_limits_and_keeps_order() {
+        let (program_id, keys) = (Pubkey::new_unique(), keys());
+        let grants = grants(100);
+        let batches = pack_xp_batches(program_id, keys, &keys.admin, &grants, MAX_COMPUTE_UNITS).unwrap();
+        assert!(batches.len() > 1);
+        let mut packed = Vec::new();
+        for batch in &batches {
+            assert!(transaction_size(batch, &keys.admin) <= TRANSACTION_SIZE_LIMIT);
+            assert!(Message::new(batch, Some(&keys.admin)).account_keys.len() <= TRANSACTION_ACCOUNT_LIMIT);
+            packed.extend(batch[1].accounts[MODIFY_PLAYER_XP_BATCH_IX_ACCOUNTS_LEN..].iter().map(|meta| meta.pubkey));
+        }
+        assert_eq!(packed, grants.iter().map(|grant| grant.player).collect::<Vec<_>>());
+    }
+}